@@ -147,6 +147,44 @@ pub struct WindowSize {
     pub pixels: Size,
 }
 
+impl WindowSize {
+    /// Returns the width / height proportion of a single cell, computed from the pixel size of
+    /// the window.
+    ///
+    /// Returns `None` when the terminal doesn't report its size in pixels (or the window has no
+    /// columns or rows), in which case callers should fall back to a sensible default such as
+    /// [`AspectRatio::DEFAULT_CELL_ASPECT`](crate::layout::AspectRatio::DEFAULT_CELL_ASPECT).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::backend::WindowSize;
+    /// use ratatui_core::layout::Size;
+    ///
+    /// let window_size = WindowSize {
+    ///     columns_rows: Size::new(80, 24),
+    ///     pixels: Size::new(640, 384),
+    /// };
+    /// assert_eq!(window_size.cell_aspect(), Some(0.5));
+    /// ```
+    pub fn cell_aspect(self) -> Option<f64> {
+        let Self {
+            columns_rows,
+            pixels,
+        } = self;
+        if columns_rows.width == 0
+            || columns_rows.height == 0
+            || pixels.width == 0
+            || pixels.height == 0
+        {
+            return None;
+        }
+        let cell_width = f64::from(pixels.width) / f64::from(columns_rows.width);
+        let cell_height = f64::from(pixels.height) / f64::from(columns_rows.height);
+        Some(cell_width / cell_height)
+    }
+}
+
 /// The `Backend` trait provides an abstraction over different terminal libraries. It defines the
 /// methods required to draw content, manipulate the cursor, and clear the terminal screen.
 ///
@@ -424,4 +462,16 @@ mod tests {
         );
         assert_eq!("".parse::<ClearType>(), Err(ParseError::VariantNotFound));
     }
+
+    #[test]
+    fn window_size_cell_aspect() {
+        let window_size = |columns, rows, width, height| WindowSize {
+            columns_rows: Size::new(columns, rows),
+            pixels: Size::new(width, height),
+        };
+        assert_eq!(window_size(80, 24, 640, 384).cell_aspect(), Some(0.5));
+        assert_eq!(window_size(10, 10, 100, 100).cell_aspect(), Some(1.0));
+        assert_eq!(window_size(80, 24, 0, 0).cell_aspect(), None);
+        assert_eq!(window_size(0, 0, 640, 384).cell_aspect(), None);
+    }
 }
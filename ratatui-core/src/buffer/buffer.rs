@@ -4,12 +4,11 @@ use core::ops::{Index, IndexMut};
 use core::{cmp, fmt};
 
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
 
 use crate::buffer::Cell;
 use crate::layout::{Position, Rect};
 use crate::style::Style;
-use crate::text::{Line, Span};
+use crate::text::{Line, Span, TabStops, WidthPolicy};
 
 /// A buffer that maps to the desired content of the terminal after the draw call
 ///
@@ -333,6 +332,9 @@ impl Buffer {
     /// Print at most the first n characters of a string if enough space is available
     /// until the end of the line. Skips zero-width graphemes and control characters.
     ///
+    /// Tab characters are expanded to spaces up to the next of the global [`TabStops`], counting
+    /// from `x`. The width of the other graphemes follows the global [`WidthPolicy`].
+    ///
     /// Use [`Buffer::set_string`] when the maximum amount of characters can be printed.
     pub fn set_stringn<T, S>(
         &mut self,
@@ -346,18 +348,37 @@ impl Buffer {
         T: AsRef<str>,
         S: Into<Style>,
     {
+        let start = x;
         let max_width = max_width.try_into().unwrap_or(u16::MAX);
         let mut remaining_width = self.area.right().saturating_sub(x).min(max_width);
-        let graphemes = UnicodeSegmentation::graphemes(string.as_ref(), true)
-            .filter(|symbol| !symbol.contains(char::is_control))
-            .map(|symbol| (symbol, symbol.width() as u16))
-            .filter(|(_symbol, width)| *width > 0)
-            .map_while(|(symbol, width)| {
-                remaining_width = remaining_width.checked_sub(width)?;
-                Some((symbol, width))
-            });
+        // the global tab stops are only needed once a tab is found
+        let mut tab_stops = None;
+        let width_policy = WidthPolicy::global();
         let style = style.into();
-        for (symbol, width) in graphemes {
+        for symbol in UnicodeSegmentation::graphemes(string.as_ref(), true) {
+            if symbol == "\t" {
+                let column = usize::from(x - start);
+                let tab_stops = tab_stops.get_or_insert_with(TabStops::global);
+                let tab_width = tab_stops.next_stop(column) - column;
+                let tab_width = u16::try_from(tab_width).unwrap_or(u16::MAX);
+                for _ in 0..tab_width.min(remaining_width) {
+                    self[(x, y)].set_symbol(" ").set_style(style);
+                    x += 1;
+                }
+                remaining_width = remaining_width.saturating_sub(tab_width);
+                continue;
+            }
+            if symbol.contains(char::is_control) {
+                continue;
+            }
+            let width = width_policy.str_width(symbol) as u16;
+            if width == 0 {
+                continue;
+            }
+            let Some(remaining) = remaining_width.checked_sub(width) else {
+                break;
+            };
+            remaining_width = remaining;
             self[(x, y)].set_symbol(symbol).set_style(style);
             let next_symbol = x + width;
             x += 1;
@@ -371,7 +392,13 @@ impl Buffer {
     }
 
     /// Print a line, starting at the position (x, y)
+    ///
+    /// Tab characters are expanded to spaces up to the next of the global [`TabStops`], counting
+    /// from the start of the line.
     pub fn set_line(&mut self, x: u16, y: u16, line: &Line<'_>, max_width: u16) -> (u16, u16) {
+        if line.has_tabs() {
+            return self.set_line(x, y, &line.expand_tabs(&TabStops::global()), max_width);
+        }
         let mut remaining_width = max_width;
         let mut x = x;
         for span in line {
@@ -465,7 +492,8 @@ impl Buffer {
     /// self to other.
     ///
     /// We're assuming that buffers are well-formed, that is no double-width cell is followed by
-    /// a non-blank cell.
+    /// a non-blank cell. The width of the symbols follows the global [`WidthPolicy`], which must
+    /// not change between rendering `self` and `other`.
     ///
     /// # Multi-width characters handling:
     ///
@@ -493,6 +521,7 @@ impl Buffer {
         let previous_buffer = &self.content;
         let next_buffer = &other.content;
 
+        let width_policy = WidthPolicy::global();
         let mut updates: Vec<(u16, u16, &Cell)> = vec![];
         // Cells invalidated by drawing/replacing preceding multi-width characters:
         let mut invalidated: usize = 0;
@@ -510,7 +539,7 @@ impl Buffer {
                 // result in visual artifacts (e.g., leftover characters). Emitting an explicit
                 // update for the trailing cells avoids this.
                 let symbol = current.symbol();
-                let cell_width = symbol.width();
+                let cell_width = width_policy.str_width(symbol);
                 // Work around terminals that fail to clear the trailing cell of certain
                 // emoji presentation sequences (those containing VS16 / U+FE0F).
                 // Only emit explicit clears for such sequences to avoid bloating diffs
@@ -537,9 +566,10 @@ impl Buffer {
                 }
             }
 
-            to_skip = current.symbol().width().saturating_sub(1);
+            let current_width = width_policy.str_width(current.symbol());
+            to_skip = current_width.saturating_sub(1);
 
-            let affected_width = cmp::max(current.symbol().width(), previous.symbol().width());
+            let affected_width = cmp::max(current_width, width_policy.str_width(previous.symbol()));
             invalidated = cmp::max(affected_width, invalidated).saturating_sub(1);
         }
         updates
@@ -620,6 +650,7 @@ impl fmt::Debug for Buffer {
         }
 
         f.write_str(",\n    content: [\n")?;
+        let width_policy = WidthPolicy::global();
         let mut last_style = None;
         let mut styles = vec![];
         for (y, line) in self.content.chunks(self.area.width as usize).enumerate() {
@@ -632,7 +663,7 @@ impl fmt::Debug for Buffer {
                 } else {
                     overwritten.push((x, c.symbol()));
                 }
-                skip = cmp::max(skip, c.symbol().width()).saturating_sub(1);
+                skip = cmp::max(skip, width_policy.str_width(c.symbol())).saturating_sub(1);
                 #[cfg(feature = "underline-color")]
                 {
                     let style = (c.fg, c.bg, c.underline_color, c.modifier);
@@ -685,6 +716,7 @@ mod tests {
 
     use itertools::Itertools;
     use rstest::{fixture, rstest};
+    use unicode_width::UnicodeWidthStr;
 
     use super::*;
     use crate::style::{Color, Modifier, Stylize};
@@ -946,6 +978,24 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["コン "]));
     }
 
+    #[test]
+    fn set_string_expands_tabs() {
+        let mut buffer = Buffer::filled(Rect::new(0, 0, 12, 1), Cell::new("x"));
+        let position = buffer.set_stringn(1, 0, "a\tb\t", 12, Style::new().red());
+        assert_eq!(position, (12, 0));
+        let mut expected = Buffer::with_lines(["xa       b  "]);
+        expected.set_style(Rect::new(1, 0, 11, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn set_line_expands_tabs_from_line_start() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        let line = Line::from(vec![Span::raw("abc"), Span::raw("\td")]);
+        buffer.set_line(2, 0, &line, 10);
+        assert_eq!(buffer, Buffer::with_lines(["  abc     d "]));
+    }
+
     #[fixture]
     fn small_one_line_buffer() -> Buffer {
         Buffer::empty(Rect::new(0, 0, 5, 1))
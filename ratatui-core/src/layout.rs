@@ -55,7 +55,11 @@
 //! - [`Rect`] - Represents a rectangular area with position and dimensions
 //! - [`Constraint`] - Defines how space should be allocated (length, percentage, ratio, etc.)
 //! - [`Direction`] - Specifies layout orientation (horizontal or vertical)
+//! - [`WritingDirection`] - Specifies whether content flows left to right or right to left
 //! - [`Flex`] - Controls space distribution when constraints are satisfied
+//! - [`LayoutExplanation`] - Reports how the constraints of a layout were resolved
+//! - [`Responsive`] - Selects between layouts based on the size of the area using [`Breakpoint`]s
+//! - [`Transition`] - Animates areas between two layouts over time using an [`Easing`] function
 //!
 //! ## Positioning and Sizing
 //!
@@ -64,6 +68,8 @@
 //! - [`Margin`] - Defines spacing around rectangular areas
 //! - [`Offset`] - Represents relative movement in the coordinate system
 //! - [`Spacing`] - Controls spacing or overlap between layout segments
+//! - [`AspectRatio`] - Sizes one axis from the other, accounting for the proportions of cells
+//! - [`Placement`] - Positions popups next to an anchor area, see [`Rect::anchored`]
 //!
 //! ## Alignment
 //!
@@ -286,6 +292,9 @@
 //! explicitly enabling the `layout-cache` feature when using `ratatui-core` directly. When
 //! enabled, layout results are cached based on the area and layout configuration.
 //!
+//! The default cache is thread-local. To share a cache between threads, clear it, or inspect its
+//! hit rate, create a `LayoutCache` and pass it to `Layout::split_with_cache`.
+//!
 //! # Related Documentation
 //!
 //! For more detailed information and practical examples:
@@ -311,23 +320,37 @@
 //!   examples
 
 mod alignment;
+mod aspect_ratio;
+#[cfg(feature = "layout-cache")]
+mod cache;
 mod constraint;
 mod direction;
+mod explain;
 mod flex;
 mod layout;
 mod margin;
 mod offset;
+mod placement;
 mod position;
 mod rect;
+mod responsive;
 mod size;
+mod transition;
 
 pub use alignment::{Alignment, HorizontalAlignment, VerticalAlignment};
+pub use aspect_ratio::AspectRatio;
+#[cfg(feature = "layout-cache")]
+pub use cache::{LayoutCache, LayoutCacheStats};
 pub use constraint::Constraint;
-pub use direction::Direction;
+pub use direction::{Direction, WritingDirection};
+pub use explain::{ConstraintExplanation, LayoutExplanation};
 pub use flex::Flex;
 pub use layout::{Layout, Spacing};
 pub use margin::Margin;
 pub use offset::Offset;
+pub use placement::{AnchorAlignment, Placement, Side};
 pub use position::Position;
 pub use rect::{Columns, Positions, Rect, Rows};
+pub use responsive::{Breakpoint, Responsive};
 pub use size::Size;
+pub use transition::{Easing, Transition};
@@ -1,5 +1,7 @@
 use strum::{Display, EnumString};
 
+use crate::layout::WritingDirection;
+
 /// A type alias for `HorizontalAlignment`.
 ///
 /// Prior to Ratatui 0.30.0, [`HorizontalAlignment`] was named `Alignment`. This alias is provided
@@ -25,6 +27,40 @@ pub enum HorizontalAlignment {
     Left,
     Center,
     Right,
+    /// Aligns content to the left, and distributes the remaining space between the words of
+    /// wrapped lines so that they fill the available width.
+    ///
+    /// The last line of a paragraph, lines that contain a single word, and content that is not
+    /// wrapped are aligned to the left instead.
+    Justify,
+}
+
+impl HorizontalAlignment {
+    /// Returns the alignment to use for content in the given writing direction.
+    ///
+    /// Alignments are expressed for left-to-right content. For right-to-left content, `Left` and
+    /// `Right` are swapped so that content aligned to the "start" ends up on the right. `Center`
+    /// is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{HorizontalAlignment, WritingDirection};
+    ///
+    /// let alignment = HorizontalAlignment::Left;
+    /// assert_eq!(
+    ///     alignment.for_writing_direction(WritingDirection::RightToLeft),
+    ///     HorizontalAlignment::Right
+    /// );
+    /// ```
+    #[must_use = "returns the alignment for the writing direction"]
+    pub const fn for_writing_direction(self, direction: WritingDirection) -> Self {
+        match (self, direction) {
+            (Self::Left, WritingDirection::RightToLeft) => Self::Right,
+            (Self::Right, WritingDirection::RightToLeft) => Self::Left,
+            (alignment, _) => alignment,
+        }
+    }
 }
 
 /// Vertical content alignment within a layout area.
@@ -50,11 +86,45 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn for_writing_direction() {
+        use WritingDirection::*;
+        assert_eq!(
+            Alignment::Left.for_writing_direction(LeftToRight),
+            Alignment::Left
+        );
+        assert_eq!(
+            Alignment::Right.for_writing_direction(LeftToRight),
+            Alignment::Right
+        );
+        assert_eq!(
+            Alignment::Center.for_writing_direction(LeftToRight),
+            Alignment::Center
+        );
+        assert_eq!(
+            Alignment::Left.for_writing_direction(RightToLeft),
+            Alignment::Right
+        );
+        assert_eq!(
+            Alignment::Right.for_writing_direction(RightToLeft),
+            Alignment::Left
+        );
+        assert_eq!(
+            Alignment::Center.for_writing_direction(RightToLeft),
+            Alignment::Center
+        );
+        assert_eq!(
+            Alignment::Justify.for_writing_direction(RightToLeft),
+            Alignment::Justify
+        );
+    }
+
     #[test]
     fn alignment_to_string() {
         assert_eq!(Alignment::Left.to_string(), "Left");
         assert_eq!(Alignment::Center.to_string(), "Center");
         assert_eq!(Alignment::Right.to_string(), "Right");
+        assert_eq!(Alignment::Justify.to_string(), "Justify");
     }
 
     #[test]
@@ -62,6 +132,7 @@ mod tests {
         assert_eq!("Left".parse::<Alignment>(), Ok(Alignment::Left));
         assert_eq!("Center".parse::<Alignment>(), Ok(Alignment::Center));
         assert_eq!("Right".parse::<Alignment>(), Ok(Alignment::Right));
+        assert_eq!("Justify".parse::<Alignment>(), Ok(Alignment::Justify));
         assert_eq!("".parse::<Alignment>(), Err(ParseError::VariantNotFound));
     }
 
@@ -0,0 +1,330 @@
+use crate::backend::WindowSize;
+use crate::layout::{Constraint, Direction, Rect};
+
+/// A target aspect ratio (width / height) that accounts for the proportions of terminal cells.
+///
+/// Terminal cells are not square: most fonts produce cells that are roughly twice as tall as they
+/// are wide. A widget that is 40 columns wide and 20 rows tall therefore looks about twice as tall
+/// as it is wide. `AspectRatio` converts between columns and rows so that an area looks like it
+/// has the target ratio on screen, which is useful for charts, canvases and maps that would
+/// otherwise appear stretched.
+///
+/// The cell aspect is the width of a cell divided by its height. It defaults to
+/// [`AspectRatio::DEFAULT_CELL_ASPECT`] (`0.5`), and can be set explicitly with
+/// [`cell_aspect`](Self::cell_aspect) or derived from the pixel size reported by
+/// [`Backend::window_size`](crate::backend::Backend::window_size) with
+/// [`window_size`](Self::window_size).
+///
+/// # Construction
+///
+/// - [`new`](Self::new) - Create an aspect ratio from a width and a height
+/// - [`square`](Self::square) - Create a 1:1 aspect ratio
+///
+/// # Configuration
+///
+/// - [`cell_aspect`](Self::cell_aspect) - Set the width / height proportion of a terminal cell
+/// - [`window_size`](Self::window_size) - Derive the cell aspect from a [`WindowSize`]
+///
+/// # Size Calculation
+///
+/// - [`height_for_width`](Self::height_for_width) - Rows needed for a given number of columns
+/// - [`width_for_height`](Self::width_for_height) - Columns needed for a given number of rows
+/// - [`constraint`](Self::constraint) - A [`Constraint`] that sizes one axis from the other
+/// - [`fit`](Self::fit) - The largest centered area with this aspect ratio
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::{AspectRatio, Constraint, Direction, Layout, Rect};
+///
+/// // the world map is twice as wide as it is tall
+/// let map = AspectRatio::new(2, 1);
+/// assert_eq!(map.height_for_width(80), 20);
+///
+/// // size the map from the height of the area, and leave the rest of the width to a sidebar
+/// let area = Rect::new(0, 0, 200, 30);
+/// let [map_area, sidebar] = Layout::horizontal([
+///     map.constraint(area, Direction::Horizontal),
+///     Constraint::Fill(1),
+/// ])
+/// .areas(area);
+/// assert_eq!(map_area, Rect::new(0, 0, 120, 30));
+/// assert_eq!(sidebar, Rect::new(120, 0, 80, 30));
+///
+/// // or center the largest area with the right proportions
+/// assert_eq!(map.fit(Rect::new(0, 0, 100, 30)), Rect::new(0, 2, 100, 25));
+/// ```
+///
+/// For comprehensive layout documentation and examples, see the [`layout`](crate::layout) module.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AspectRatio {
+    width: u32,
+    height: u32,
+    cell_aspect: f64,
+}
+
+impl AspectRatio {
+    /// The default width / height proportion of a terminal cell.
+    ///
+    /// Most terminal fonts produce cells that are about twice as tall as they are wide.
+    pub const DEFAULT_CELL_ASPECT: f64 = 0.5;
+
+    /// Creates a new aspect ratio of `width:height` using the default cell aspect.
+    ///
+    /// A `height` of 0 is treated as 1 to avoid division by zero.
+    pub const fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            cell_aspect: Self::DEFAULT_CELL_ASPECT,
+        }
+    }
+
+    /// Creates a new 1:1 aspect ratio using the default cell aspect.
+    pub const fn square() -> Self {
+        Self::new(1, 1)
+    }
+
+    /// Sets the width / height proportion of a terminal cell.
+    ///
+    /// Non-finite or non-positive values are ignored and the current cell aspect is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::AspectRatio;
+    ///
+    /// // a font with 8x16 pixel cells
+    /// let ratio = AspectRatio::square().cell_aspect(8.0 / 16.0);
+    /// assert_eq!(ratio.height_for_width(20), 10);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cell_aspect(mut self, cell_aspect: f64) -> Self {
+        if cell_aspect.is_finite() && cell_aspect > 0.0 {
+            self.cell_aspect = cell_aspect;
+        }
+        self
+    }
+
+    /// Sets the cell aspect from the pixel dimensions of a [`WindowSize`].
+    ///
+    /// Many terminals don't report their size in pixels, in which case the current cell aspect
+    /// is kept. See [`WindowSize::cell_aspect`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::backend::WindowSize;
+    /// use ratatui_core::layout::{AspectRatio, Size};
+    ///
+    /// let window_size = WindowSize {
+    ///     columns_rows: Size::new(100, 50),
+    ///     pixels: Size::new(1000, 1000),
+    /// };
+    /// let ratio = AspectRatio::square().window_size(window_size);
+    /// assert_eq!(ratio.height_for_width(20), 10);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn window_size(self, window_size: WindowSize) -> Self {
+        match window_size.cell_aspect() {
+            Some(cell_aspect) => self.cell_aspect(cell_aspect),
+            None => self,
+        }
+    }
+
+    /// The target ratio of the visible width to the visible height.
+    fn ratio(&self) -> f64 {
+        f64::from(self.width) / f64::from(self.height.max(1))
+    }
+
+    /// Returns the number of rows an area `width` columns wide needs to have this aspect ratio.
+    ///
+    /// The result is rounded to the nearest row and saturates at `u16::MAX`.
+    pub fn height_for_width(&self, width: u16) -> u16 {
+        let ratio = self.ratio();
+        if ratio == 0.0 {
+            return u16::MAX;
+        }
+        to_u16(f64::from(width) * self.cell_aspect / ratio)
+    }
+
+    /// Returns the number of columns an area `height` rows tall needs to have this aspect ratio.
+    ///
+    /// The result is rounded to the nearest column and saturates at `u16::MAX`.
+    pub fn width_for_height(&self, height: u16) -> u16 {
+        to_u16(f64::from(height) * self.ratio() / self.cell_aspect)
+    }
+
+    /// Returns a [`Constraint`] for a layout in the given direction that sizes a segment from the
+    /// other axis of `area`.
+    ///
+    /// For a [`Direction::Horizontal`] layout the width of the segment is computed from the height
+    /// of the area, and for a [`Direction::Vertical`] layout the height of the segment is computed
+    /// from the width of the area. The result is a [`Constraint::Length`], so the layout may
+    /// still shrink the segment if other constraints need the space.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{AspectRatio, Constraint, Direction, Layout, Rect};
+    ///
+    /// let area = Rect::new(0, 0, 40, 50);
+    /// let ratio = AspectRatio::square();
+    /// let [square, rest] = Layout::vertical([
+    ///     ratio.constraint(area, Direction::Vertical),
+    ///     Constraint::Fill(1),
+    /// ])
+    /// .areas(area);
+    /// assert_eq!(square, Rect::new(0, 0, 40, 20));
+    /// ```
+    pub fn constraint(&self, area: Rect, direction: Direction) -> Constraint {
+        match direction {
+            Direction::Horizontal => Constraint::Length(self.width_for_height(area.height)),
+            Direction::Vertical => Constraint::Length(self.height_for_width(area.width)),
+        }
+    }
+
+    /// Returns the largest area with this aspect ratio that fits inside `area`, centered on both
+    /// axes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{AspectRatio, Rect};
+    ///
+    /// let ratio = AspectRatio::square();
+    /// assert_eq!(
+    ///     ratio.fit(Rect::new(0, 0, 100, 20)),
+    ///     Rect::new(30, 0, 40, 20)
+    /// );
+    /// assert_eq!(ratio.fit(Rect::new(0, 0, 20, 20)), Rect::new(0, 5, 20, 10));
+    /// ```
+    pub fn fit(&self, area: Rect) -> Rect {
+        let height = self.height_for_width(area.width);
+        if height <= area.height {
+            let y = area.y + (area.height - height) / 2;
+            Rect::new(area.x, y, area.width, height)
+        } else {
+            let width = self.width_for_height(area.height).min(area.width);
+            let x = area.x + (area.width - width) / 2;
+            Rect::new(x, area.y, width, area.height)
+        }
+    }
+}
+
+impl Default for AspectRatio {
+    fn default() -> Self {
+        Self::square()
+    }
+}
+
+/// Converts a non-negative value to `u16`, rounding to the nearest integer.
+///
+/// Float to int `as` casts saturate, so values larger than `u16::MAX` become `u16::MAX`.
+fn to_u16(value: f64) -> u16 {
+    (value + 0.5) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::layout::Size;
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            AspectRatio::new(16, 9),
+            AspectRatio {
+                width: 16,
+                height: 9,
+                cell_aspect: AspectRatio::DEFAULT_CELL_ASPECT,
+            }
+        );
+        assert_eq!(AspectRatio::default(), AspectRatio::new(1, 1));
+    }
+
+    #[rstest]
+    #[case::default(AspectRatio::DEFAULT_CELL_ASPECT, 0.5)]
+    #[case::custom(0.25, 0.25)]
+    #[case::zero(0.0, 0.5)]
+    #[case::negative(-1.0, 0.5)]
+    #[case::nan(f64::NAN, 0.5)]
+    #[case::infinite(f64::INFINITY, 0.5)]
+    fn cell_aspect(#[case] value: f64, #[case] expected: f64) {
+        assert_eq!(
+            AspectRatio::square().cell_aspect(value),
+            AspectRatio {
+                cell_aspect: expected,
+                ..AspectRatio::square()
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::square_cells(Size::new(100, 50), Size::new(1000, 500), 1.0)]
+    #[case::tall_cells(Size::new(100, 50), Size::new(800, 800), 0.5)]
+    #[case::no_pixels(Size::new(100, 50), Size::new(0, 0), 0.5)]
+    #[case::no_cells(Size::new(0, 0), Size::new(800, 800), 0.5)]
+    fn window_size(#[case] columns_rows: Size, #[case] pixels: Size, #[case] expected: f64) {
+        let window_size = WindowSize {
+            columns_rows,
+            pixels,
+        };
+        assert_eq!(
+            AspectRatio::square().window_size(window_size),
+            AspectRatio {
+                cell_aspect: expected,
+                ..AspectRatio::square()
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::square(AspectRatio::new(1, 1), 40, 20)]
+    #[case::wide(AspectRatio::new(2, 1), 40, 10)]
+    #[case::tall(AspectRatio::new(1, 2), 40, 40)]
+    #[case::rounding(AspectRatio::new(1, 1), 5, 3)]
+    #[case::zero_height(AspectRatio::new(1, 0), 40, 20)]
+    #[case::zero_width(AspectRatio::new(0, 1), 40, u16::MAX)]
+    #[case::saturates(AspectRatio::new(1, 100), u16::MAX, u16::MAX)]
+    fn height_for_width(#[case] ratio: AspectRatio, #[case] width: u16, #[case] expected: u16) {
+        assert_eq!(ratio.height_for_width(width), expected);
+    }
+
+    #[rstest]
+    #[case::square(AspectRatio::new(1, 1), 20, 40)]
+    #[case::wide(AspectRatio::new(2, 1), 20, 80)]
+    #[case::tall(AspectRatio::new(1, 2), 20, 20)]
+    #[case::zero_width(AspectRatio::new(0, 1), 20, 0)]
+    #[case::saturates(AspectRatio::new(100, 1), u16::MAX, u16::MAX)]
+    fn width_for_height(#[case] ratio: AspectRatio, #[case] height: u16, #[case] expected: u16) {
+        assert_eq!(ratio.width_for_height(height), expected);
+    }
+
+    #[test]
+    fn constraint() {
+        let ratio = AspectRatio::new(2, 1);
+        let area = Rect::new(0, 0, 100, 20);
+        assert_eq!(
+            ratio.constraint(area, Direction::Horizontal),
+            Constraint::Length(80)
+        );
+        assert_eq!(
+            ratio.constraint(area, Direction::Vertical),
+            Constraint::Length(25)
+        );
+    }
+
+    #[rstest]
+    #[case::wide_area(Rect::new(0, 0, 100, 20), Rect::new(30, 0, 40, 20))]
+    #[case::tall_area(Rect::new(0, 0, 20, 20), Rect::new(0, 5, 20, 10))]
+    #[case::exact(Rect::new(0, 0, 40, 20), Rect::new(0, 0, 40, 20))]
+    #[case::offset(Rect::new(10, 10, 20, 20), Rect::new(10, 15, 20, 10))]
+    #[case::empty(Rect::ZERO, Rect::ZERO)]
+    fn fit(#[case] area: Rect, #[case] expected: Rect) {
+        assert_eq!(AspectRatio::square().fit(area), expected);
+    }
+}
@@ -0,0 +1,282 @@
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use core::num::NonZeroUsize;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use lru::LruCache;
+
+use crate::layout::{Layout, Rect};
+
+type Entry = (Arc<[Rect]>, Arc<[Rect]>);
+
+/// A cache of [`Layout`] results that can be passed explicitly to the layout methods.
+///
+/// By default, [`Layout::split`] stores its results in an implicit thread-local cache. This works
+/// well for most applications, but each thread gets its own copy of the cache, and there is no
+/// way to inspect or clear it. A `LayoutCache` is an explicit alternative that:
+///
+/// - can be shared between threads (it is [`Send`] and [`Sync`], so wrap it in an [`Arc`] or use a
+///   `static`),
+/// - can be [cleared](Self::clear), e.g. between tests,
+/// - keeps track of hits and misses, available from [`stats`](Self::stats).
+///
+/// Pass the cache to [`Layout::split_with_cache`] or [`Layout::split_with_spacers_and_cache`] to
+/// use it. The cache is a `LruCache` keyed on the layout and area, and evicts the least recently
+/// used entries once it reaches its capacity.
+///
+/// This type is only available with the `layout-cache` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// use ratatui_core::layout::{Constraint, Layout, LayoutCache, Rect};
+///
+/// let cache = Arc::new(LayoutCache::default());
+/// let layout = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]);
+/// let area = Rect::new(0, 0, 10, 10);
+///
+/// let handle = thread::spawn({
+///     let (cache, layout) = (Arc::clone(&cache), layout.clone());
+///     move || layout.split_with_cache(area, &cache).len()
+/// });
+/// assert_eq!(handle.join().unwrap(), 2);
+///
+/// // the result computed on the other thread is reused here
+/// layout.split_with_cache(area, &cache);
+/// let stats = cache.stats();
+/// assert_eq!((stats.hits, stats.misses), (1, 1));
+///
+/// cache.clear();
+/// assert!(cache.is_empty());
+/// ```
+#[derive(Debug)]
+pub struct LayoutCache {
+    entries: Mutex<LruCache<(Rect, Layout), Entry>>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+/// Hit and miss statistics of a [`LayoutCache`].
+///
+/// Returned by [`LayoutCache::stats`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct LayoutCacheStats {
+    /// The number of lookups that found a cached result.
+    pub hits: usize,
+    /// The number of lookups that had to compute the layout.
+    pub misses: usize,
+    /// The number of entries currently in the cache.
+    pub len: usize,
+    /// The maximum number of entries the cache can hold.
+    pub capacity: usize,
+}
+
+impl LayoutCacheStats {
+    /// The fraction of lookups that found a cached result, between `0.0` and `1.0`.
+    ///
+    /// Returns `0.0` when there have been no lookups.
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+impl LayoutCache {
+    /// Creates a new empty cache that holds up to `capacity` layout results.
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        }
+    }
+
+    /// Removes all the entries from the cache.
+    ///
+    /// The hit and miss statistics are kept. Use [`reset_stats`](Self::reset_stats) to reset them.
+    pub fn clear(&self) {
+        self.entries().clear();
+    }
+
+    /// Resets the hit and miss statistics to zero.
+    pub fn reset_stats(&self) {
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+
+    /// Changes the maximum number of entries the cache can hold, evicting the least recently used
+    /// entries if needed.
+    pub fn resize(&self, capacity: NonZeroUsize) {
+        self.entries().resize(capacity);
+    }
+
+    /// Returns the number of entries currently in the cache.
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    /// Returns true if the cache contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries().is_empty()
+    }
+
+    /// Returns the maximum number of entries the cache can hold.
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.entries().cap()
+    }
+
+    /// Returns the hit and miss statistics of the cache along with its current size.
+    pub fn stats(&self) -> LayoutCacheStats {
+        let entries = self.entries();
+        LayoutCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            len: entries.len(),
+            capacity: entries.cap().get(),
+        }
+    }
+
+    /// Returns the cached result for the layout and area, or computes and stores it with `split`.
+    ///
+    /// The lock is not held while computing the layout, so two threads missing on the same key at
+    /// the same time may both compute it.
+    pub(crate) fn get_or_insert_with<F>(
+        &self,
+        area: Rect,
+        layout: &Layout,
+        split: F,
+    ) -> (Rc<[Rect]>, Rc<[Rect]>)
+    where
+        F: FnOnce() -> (Rc<[Rect]>, Rc<[Rect]>),
+    {
+        let key = (area, layout.clone());
+        if let Some((segments, spacers)) = self.entries().get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return (Rc::from(&**segments), Rc::from(&**spacers));
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let (segments, spacers) = split();
+        let entry = (Arc::from(&*segments), Arc::from(&*spacers));
+        self.entries().put(key, entry);
+        (segments, spacers)
+    }
+
+    fn entries(&self) -> MutexGuard<'_, LruCache<(Rect, Layout), Entry>> {
+        // the cache is always left in a valid state, so it's fine to keep using it after a panic
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for LayoutCache {
+    /// Creates a new empty cache that holds up to [`Layout::DEFAULT_CACHE_SIZE`] layout results.
+    fn default() -> Self {
+        Self::new(NonZeroUsize::new(Layout::DEFAULT_CACHE_SIZE).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Constraint;
+
+    #[test]
+    fn default() {
+        let cache = LayoutCache::default();
+        assert!(cache.is_empty());
+        assert_eq!(cache.capacity().get(), Layout::DEFAULT_CACHE_SIZE);
+        assert_eq!(
+            cache.stats(),
+            LayoutCacheStats {
+                hits: 0,
+                misses: 0,
+                len: 0,
+                capacity: Layout::DEFAULT_CACHE_SIZE,
+            }
+        );
+    }
+
+    #[test]
+    fn hits_and_misses() {
+        let cache = LayoutCache::default();
+        let layout = Layout::horizontal([Constraint::Length(2), Constraint::Fill(1)]);
+        let area = Rect::new(0, 0, 10, 1);
+
+        let first = layout.split_with_cache(area, &cache);
+        let second = layout.split_with_cache(area, &cache);
+        assert_eq!(first, second);
+        assert_eq!(first, layout.split(area));
+        layout.split_with_cache(Rect::new(0, 0, 20, 1), &cache);
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.len), (1, 2, 2));
+        assert!((stats.hit_rate() - 1.0 / 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn split_with_spacers_and_cache() {
+        let cache = LayoutCache::default();
+        let layout = Layout::horizontal([Constraint::Length(2); 2]).spacing(1);
+        let area = Rect::new(0, 0, 10, 1);
+        assert_eq!(
+            layout.split_with_spacers_and_cache(area, &cache),
+            layout.split_with_spacers(area)
+        );
+        // split shares the cached entry with split_with_spacers
+        layout.split_with_cache(area, &cache);
+        assert_eq!(cache.stats().hits, 1);
+    }
+
+    #[test]
+    fn clear_and_reset_stats() {
+        let cache = LayoutCache::default();
+        let layout = Layout::vertical([Constraint::Fill(1)]);
+        layout.split_with_cache(Rect::new(0, 0, 1, 1), &cache);
+        assert_eq!(cache.len(), 1);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.stats().misses, 1);
+
+        cache.reset_stats();
+        assert_eq!(
+            cache.stats(),
+            LayoutCacheStats {
+                capacity: Layout::DEFAULT_CACHE_SIZE,
+                ..LayoutCacheStats::default()
+            }
+        );
+    }
+
+    #[test]
+    fn resize_evicts_entries() {
+        let cache = LayoutCache::new(NonZeroUsize::new(2).unwrap());
+        let layout = Layout::vertical([Constraint::Fill(1)]);
+        for width in 1..=3 {
+            layout.split_with_cache(Rect::new(0, 0, width, 1), &cache);
+        }
+        assert_eq!(cache.len(), 2);
+
+        cache.resize(NonZeroUsize::new(1).unwrap());
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.capacity().get(), 1);
+    }
+
+    #[test]
+    fn hit_rate_without_lookups() {
+        assert!(LayoutCacheStats::default().hit_rate().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<LayoutCache>();
+    }
+}
@@ -33,6 +33,42 @@ impl Direction {
     }
 }
 
+/// Defines the writing direction of content, i.e. whether it flows from left to right or from
+/// right to left.
+///
+/// Scripts such as Hebrew and Arabic are written from right to left. Setting the writing
+/// direction of a [`Layout`](crate::layout::Layout) to `RightToLeft` mirrors the segments of
+/// horizontal layouts so that the first segment is placed on the right, and
+/// [`HorizontalAlignment::for_writing_direction`](crate::layout::HorizontalAlignment::for_writing_direction)
+/// maps alignments to their mirrored equivalent. Text can be reordered for display with the Unicode
+/// Bidirectional Algorithm with `Line::to_visual_order` and `Text::to_visual_order` (requires the
+/// `unicode-bidi` feature). The `Paragraph` widget does both when given a writing direction.
+///
+/// For comprehensive layout documentation and examples, see the [`layout`](crate::layout) module.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WritingDirection {
+    /// Content flows from left to right (default).
+    #[default]
+    LeftToRight,
+    /// Content flows from right to left.
+    RightToLeft,
+}
+
+impl WritingDirection {
+    /// The opposite writing direction.
+    ///
+    /// `LeftToRight` returns `RightToLeft`, and `RightToLeft` returns `LeftToRight`.
+    #[inline]
+    #[must_use = "returns the opposite writing direction"]
+    pub const fn reversed(self) -> Self {
+        match self {
+            Self::LeftToRight => Self::RightToLeft,
+            Self::RightToLeft => Self::LeftToRight,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
@@ -54,6 +90,23 @@ mod tests {
         assert_eq!("".parse::<Direction>(), Err(ParseError::VariantNotFound));
     }
 
+    #[test]
+    fn writing_direction_from_str() {
+        assert_eq!(
+            "RightToLeft".parse::<WritingDirection>(),
+            Ok(WritingDirection::RightToLeft)
+        );
+        assert_eq!(WritingDirection::LeftToRight.to_string(), "LeftToRight");
+        assert_eq!(WritingDirection::default(), WritingDirection::LeftToRight);
+    }
+
+    #[test]
+    fn writing_direction_reversed() {
+        use WritingDirection::*;
+        assert_eq!(LeftToRight.reversed(), RightToLeft);
+        assert_eq!(RightToLeft.reversed(), LeftToRight);
+    }
+
     #[test]
     fn other() {
         use Direction::*;
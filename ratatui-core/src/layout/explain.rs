@@ -0,0 +1,311 @@
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::layout::layout::strengths::{
+    FILL_GROW, LENGTH_SIZE_EQ, MAX_SIZE_LE, MIN_SIZE_GE, PERCENTAGE_SIZE_EQ, RATIO_SIZE_EQ,
+};
+use crate::layout::{Constraint, Direction, Flex, Rect};
+
+/// A report of how a [`Layout`] resolved its constraints for a given area.
+///
+/// The layout solver tries to satisfy all the constraints of a layout, but when they conflict
+/// (e.g. the sum of several [`Constraint::Length`]s is larger than the area), the constraints
+/// with a lower strength are given up first. A `LayoutExplanation` is returned by
+/// [`Layout::explain`] and describes, for each constraint, the size it asked for, the size it
+/// received, the strength it was added to the solver with, and whether it was satisfied. This
+/// makes it possible to find out why a segment collapsed to zero from a test or a debug overlay.
+///
+/// The [`Display`](fmt::Display) implementation renders the explanation with one line per
+/// constraint, which is handy for printing while debugging.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::{Constraint, Layout, Rect};
+///
+/// let layout = Layout::horizontal([
+///     Constraint::Length(8),
+///     Constraint::Length(8),
+///     Constraint::Min(2),
+/// ]);
+/// let explanation = layout.explain(Rect::new(0, 0, 10, 1));
+///
+/// let unsatisfied: Vec<_> = explanation.unsatisfied().map(|c| c.constraint).collect();
+/// assert_eq!(unsatisfied, [Constraint::Length(8), Constraint::Length(8)]);
+/// assert_eq!(explanation.constraints[2].size, 2);
+/// ```
+///
+/// [`Layout`]: crate::layout::Layout
+/// [`Layout::explain`]: crate::layout::Layout::explain
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutExplanation {
+    /// The direction of the layout.
+    pub direction: Direction,
+    /// The flex mode of the layout.
+    pub flex: Flex,
+    /// The area that was split, after the layout margin was applied.
+    pub area: Rect,
+    /// The explanation for each constraint of the layout, in the same order as the constraints.
+    pub constraints: Vec<ConstraintExplanation>,
+    /// The spacers between the segments, as returned by
+    /// [`Layout::split_with_spacers`](crate::layout::Layout::split_with_spacers).
+    pub spacers: Rc<[Rect]>,
+}
+
+/// How a single [`Constraint`] of a [`Layout`](crate::layout::Layout) was resolved.
+///
+/// See [`LayoutExplanation`] for more details.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConstraintExplanation {
+    /// The constraint as it was given to the layout.
+    pub constraint: Constraint,
+    /// The strength the constraint was added to the solver with. Constraints with a higher
+    /// strength take precedence when constraints conflict.
+    pub strength: f64,
+    /// The size in cells the constraint asked for, or `None` for [`Constraint::Fill`] which has
+    /// no specific size.
+    ///
+    /// This is a lower bound for [`Constraint::Min`] and an upper bound for [`Constraint::Max`].
+    /// Percentages and ratios are computed from the size of the area and may be fractional.
+    pub requested: Option<f64>,
+    /// The size in cells the segment received.
+    pub size: u16,
+    /// The area the segment received.
+    pub area: Rect,
+    /// Whether the size of the segment satisfies the constraint.
+    pub satisfied: bool,
+}
+
+impl LayoutExplanation {
+    pub(crate) fn new(
+        direction: Direction,
+        flex: Flex,
+        area: Rect,
+        constraints: &[Constraint],
+        segments: &[Rect],
+        spacers: Rc<[Rect]>,
+    ) -> Self {
+        let area_size = match direction {
+            Direction::Horizontal => area.width,
+            Direction::Vertical => area.height,
+        };
+        let constraints = constraints
+            .iter()
+            .zip(segments)
+            .map(|(&constraint, &segment)| {
+                ConstraintExplanation::new(constraint, segment, direction, area_size)
+            })
+            .collect();
+        Self {
+            direction,
+            flex,
+            area,
+            constraints,
+            spacers,
+        }
+    }
+
+    /// Returns an iterator over the constraints that the layout could not satisfy.
+    pub fn unsatisfied(&self) -> impl Iterator<Item = &ConstraintExplanation> {
+        self.constraints.iter().filter(|c| !c.satisfied)
+    }
+
+    /// Returns true if every constraint of the layout was satisfied.
+    pub fn is_satisfied(&self) -> bool {
+        self.constraints.iter().all(|c| c.satisfied)
+    }
+
+    /// Returns the areas of the segments, in the same order as the constraints.
+    pub fn segments(&self) -> impl Iterator<Item = Rect> + '_ {
+        self.constraints.iter().map(|c| c.area)
+    }
+}
+
+impl ConstraintExplanation {
+    fn new(constraint: Constraint, area: Rect, direction: Direction, area_size: u16) -> Self {
+        let size = match direction {
+            Direction::Horizontal => area.width,
+            Direction::Vertical => area.height,
+        };
+        let area_size = f64::from(area_size);
+        let (strength, requested) = match constraint {
+            Constraint::Min(min) => (MIN_SIZE_GE, Some(f64::from(min))),
+            Constraint::Max(max) => (MAX_SIZE_LE, Some(f64::from(max))),
+            Constraint::Length(length) => (LENGTH_SIZE_EQ, Some(f64::from(length))),
+            Constraint::Percentage(p) => {
+                (PERCENTAGE_SIZE_EQ, Some(area_size * f64::from(p) / 100.0))
+            }
+            Constraint::Ratio(num, den) => (
+                RATIO_SIZE_EQ,
+                // avoid division by zero by using 1 when denominator is 0
+                Some(area_size * f64::from(num) / f64::from(den.max(1))),
+            ),
+            Constraint::Fill(_) => (FILL_GROW, None),
+        };
+        let actual = f64::from(size);
+        // segment edges are rounded to whole cells, so allow for less than a cell of difference
+        let satisfied = match (constraint, requested) {
+            (Constraint::Min(_), Some(requested)) => actual >= requested,
+            (Constraint::Max(_), Some(requested)) => actual <= requested,
+            (_, Some(requested)) => (actual - requested).abs() < 1.0,
+            (_, None) => true,
+        };
+        Self {
+            constraint,
+            strength: strength.value(),
+            requested,
+            size,
+            area,
+            satisfied,
+        }
+    }
+}
+
+impl fmt::Display for LayoutExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} layout ({:?}) in {}x{} at ({}, {})",
+            self.direction, self.flex, self.area.width, self.area.height, self.area.x, self.area.y
+        )?;
+        for (index, constraint) in self.constraints.iter().enumerate() {
+            writeln!(f, "{index}: {constraint}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ConstraintExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.satisfied {
+            "satisfied"
+        } else {
+            "unsatisfied"
+        };
+        write!(f, "{} -> {} ", self.constraint, self.size)?;
+        match self.requested {
+            Some(requested) => write!(f, "(requested {requested:.1}")?,
+            None => write!(f, "(requested any")?,
+        }
+        write!(f, ", strength {:.0}) {status}", self.strength)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::layout::Layout;
+
+    #[test]
+    fn satisfied() {
+        let layout = Layout::vertical([Constraint::Length(2), Constraint::Fill(1)]);
+        let explanation = layout.explain(Rect::new(0, 0, 10, 10));
+        assert!(explanation.is_satisfied());
+        assert_eq!(explanation.unsatisfied().count(), 0);
+        assert_eq!(
+            explanation.constraints,
+            [
+                ConstraintExplanation {
+                    constraint: Constraint::Length(2),
+                    strength: LENGTH_SIZE_EQ.value(),
+                    requested: Some(2.0),
+                    size: 2,
+                    area: Rect::new(0, 0, 10, 2),
+                    satisfied: true,
+                },
+                ConstraintExplanation {
+                    constraint: Constraint::Fill(1),
+                    strength: FILL_GROW.value(),
+                    requested: None,
+                    size: 8,
+                    area: Rect::new(0, 2, 10, 8),
+                    satisfied: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn conflicting_lengths() {
+        let layout = Layout::horizontal([
+            Constraint::Length(8),
+            Constraint::Min(5),
+            Constraint::Length(8),
+        ]);
+        let explanation = layout.explain(Rect::new(0, 0, 10, 1));
+        assert!(!explanation.is_satisfied());
+        let unsatisfied = explanation
+            .unsatisfied()
+            .map(|c| (c.constraint, c.size))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            unsatisfied,
+            [(Constraint::Length(8), 5), (Constraint::Length(8), 0)]
+        );
+        // Min has a higher strength than Length, so it is satisfied first
+        assert!(explanation.constraints[1].satisfied);
+        assert!(explanation.constraints[1].strength > explanation.constraints[0].strength);
+    }
+
+    #[test]
+    fn min_and_max() {
+        let layout = Layout::horizontal([Constraint::Min(20), Constraint::Max(2)]);
+        let explanation = layout.explain(Rect::new(0, 0, 10, 1));
+        let satisfied = explanation
+            .constraints
+            .iter()
+            .map(|c| (c.size, c.satisfied))
+            .collect::<Vec<_>>();
+        assert_eq!(satisfied, [(10, false), (0, true)]);
+    }
+
+    #[test]
+    fn percentage_and_ratio() {
+        let layout = Layout::horizontal([Constraint::Percentage(33), Constraint::Ratio(2, 3)]);
+        let explanation = layout.explain(Rect::new(0, 0, 10, 1));
+        assert!(explanation.is_satisfied());
+        assert_eq!(explanation.constraints[0].requested, Some(3.3));
+        assert_eq!(explanation.constraints[0].size, 3);
+        assert_eq!(explanation.constraints[1].size, 7);
+    }
+
+    #[test]
+    fn margin_and_spacers() {
+        let layout = Layout::horizontal([Constraint::Length(2), Constraint::Length(2)])
+            .margin(1)
+            .spacing(1);
+        let explanation = layout.explain(Rect::new(0, 0, 10, 4));
+        assert_eq!(explanation.area, Rect::new(1, 1, 8, 2));
+        assert_eq!(
+            explanation.segments().collect::<Vec<_>>(),
+            [Rect::new(1, 1, 2, 2), Rect::new(4, 1, 2, 2)]
+        );
+        assert_eq!(explanation.spacers.len(), 3);
+        assert_eq!(explanation.spacers[1], Rect::new(3, 1, 1, 2));
+    }
+
+    #[test]
+    fn display() {
+        let layout = Layout::horizontal([
+            Constraint::Length(8),
+            Constraint::Min(5),
+            Constraint::Fill(1),
+        ]);
+        let explanation = layout.explain(Rect::new(0, 0, 10, 1));
+        assert_eq!(
+            explanation.to_string(),
+            indoc! {"
+                Horizontal layout (Start) in 10x1 at (0, 0)
+                0: Length(8) -> 5 (requested 8.0, strength 10000000) unsatisfied
+                1: Min(5) -> 5 (requested 5.0, strength 100000000) satisfied
+                2: Fill(1) -> 0 (requested any, strength 1000) satisfied
+            "}
+        );
+    }
+}
@@ -16,7 +16,11 @@ use self::strengths::{
     ALL_SEGMENT_GROW, FILL_GROW, GROW, LENGTH_SIZE_EQ, MAX_SIZE_EQ, MAX_SIZE_LE, MIN_SIZE_EQ,
     MIN_SIZE_GE, PERCENTAGE_SIZE_EQ, RATIO_SIZE_EQ, SPACE_GROW, SPACER_SIZE_EQ,
 };
-use crate::layout::{Constraint, Direction, Flex, Margin, Rect};
+#[cfg(feature = "layout-cache")]
+use crate::layout::LayoutCache;
+use crate::layout::{
+    Constraint, Direction, Flex, LayoutExplanation, Margin, Rect, WritingDirection,
+};
 
 type Rects = Rc<[Rect]>;
 type Segments = Rects;
@@ -149,6 +153,8 @@ impl From<i16> for Spacing {
 /// - [`vertical_margin`](Self::vertical_margin) - Set the vertical margin of the layout
 /// - [`flex`](Self::flex) - Set the way space is distributed when constraints are satisfied
 /// - [`spacing`](Self::spacing) - Set the gap between the constraints of the layout
+/// - [`writing_direction`](Self::writing_direction) - Mirror horizontal layouts for right-to-left
+///   content
 ///
 /// # Layout Operations
 ///
@@ -157,10 +163,14 @@ impl From<i16> for Spacing {
 /// - [`split`](Self::split) - Split area into rectangles (runtime determined count)
 /// - [`split_with_spacers`](Self::split_with_spacers) - Split area and return both areas and
 ///   spacers
+/// - [`explain`](Self::explain) - Split area and report how each constraint was resolved
 ///
 /// # Cache Management
 ///
 /// - [`init_cache`](Self::init_cache) - Initialize layout cache with custom size
+/// - [`split_with_cache`](Self::split_with_cache) - Split area using an explicit [`LayoutCache`]
+/// - [`split_with_spacers_and_cache`](Self::split_with_spacers_and_cache) - Split area and return
+///   both areas and spacers using an explicit [`LayoutCache`]
 ///
 /// # Example
 ///
@@ -196,6 +206,8 @@ pub struct Layout {
     margin: Margin,
     flex: Flex,
     spacing: Spacing,
+    #[cfg_attr(feature = "serde", serde(default))]
+    writing_direction: WritingDirection,
 }
 
 impl Layout {
@@ -526,6 +538,32 @@ impl Layout {
         self
     }
 
+    /// Sets the writing direction of the layout.
+    ///
+    /// With [`WritingDirection::RightToLeft`], the segments of a horizontal layout are mirrored
+    /// within the area: the first constraint is placed on the right and the last one on the left.
+    /// [`Flex`] is mirrored as well, so [`Flex::Start`] packs the segments against the right edge.
+    /// The order of the returned areas and spacers still matches the order of the constraints.
+    ///
+    /// Vertical layouts are not affected by the writing direction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint, Layout, Rect, WritingDirection};
+    ///
+    /// let layout = Layout::horizontal([Constraint::Length(2), Constraint::Fill(1)])
+    ///     .writing_direction(WritingDirection::RightToLeft);
+    /// let [first, second] = layout.areas(Rect::new(0, 0, 10, 1));
+    /// assert_eq!(first, Rect::new(8, 0, 2, 1));
+    /// assert_eq!(second, Rect::new(0, 0, 8, 1));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn writing_direction(mut self, writing_direction: WritingDirection) -> Self {
+        self.writing_direction = writing_direction;
+        self
+    }
+
     /// Split the rect into a number of sub-rects according to the given [`Layout`].
     ///
     /// An ergonomic wrapper around [`Layout::split`] that returns an array of `Rect`s instead of
@@ -725,6 +763,79 @@ impl Layout {
         split()
     }
 
+    /// Split the rect into a number of sub-rects according to the given [`Layout`], using an
+    /// explicit [`LayoutCache`] instead of the implicit thread-local cache.
+    ///
+    /// This is the same as [`Layout::split`], except that the result is looked up in and stored
+    /// into `cache`. The cache can be shared between threads, cleared and queried for hit and miss
+    /// statistics. See [`LayoutCache`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::{Constraint, Layout, LayoutCache, Rect};
+    ///
+    /// let cache = LayoutCache::default();
+    /// let layout = Layout::vertical([Constraint::Length(5), Constraint::Fill(1)]);
+    /// let areas = layout.split_with_cache(Rect::new(0, 0, 10, 10), &cache);
+    /// assert_eq!(areas[..], [Rect::new(0, 0, 10, 5), Rect::new(0, 5, 10, 5)]);
+    /// assert_eq!(cache.stats().misses, 1);
+    /// ```
+    #[cfg(feature = "layout-cache")]
+    pub fn split_with_cache(&self, area: Rect, cache: &LayoutCache) -> Rects {
+        self.split_with_spacers_and_cache(area, cache).0
+    }
+
+    /// Split the rect into a number of sub-rects and spacers according to the given [`Layout`],
+    /// using an explicit [`LayoutCache`] instead of the implicit thread-local cache.
+    ///
+    /// This is the same as [`Layout::split_with_spacers`], except that the result is looked up in
+    /// and stored into `cache`. See [`LayoutCache`] for more details.
+    #[cfg(feature = "layout-cache")]
+    pub fn split_with_spacers_and_cache(
+        &self,
+        area: Rect,
+        cache: &LayoutCache,
+    ) -> (Segments, Spacers) {
+        cache.get_or_insert_with(area, self, || {
+            self.try_split(area).expect("failed to split")
+        })
+    }
+
+    /// Splits the area like [`Layout::split_with_spacers`] and reports how each constraint was
+    /// resolved.
+    ///
+    /// When constraints conflict, the solver silently gives up the weaker ones, which can make a
+    /// segment shrink or collapse to zero. The returned [`LayoutExplanation`] lists, for each
+    /// constraint, the requested size, the resulting size, the strength it was solved with and
+    /// whether it was satisfied. It also implements [`Display`](core::fmt::Display) for easy
+    /// printing while debugging.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::{Constraint, Layout, Rect};
+    ///
+    /// let layout = Layout::horizontal([Constraint::Length(20), Constraint::Min(5)]);
+    /// let explanation = layout.explain(Rect::new(0, 0, 10, 1));
+    ///
+    /// assert!(!explanation.is_satisfied());
+    /// let unsatisfied = explanation.unsatisfied().next().unwrap();
+    /// assert_eq!(unsatisfied.constraint, Constraint::Length(20));
+    /// assert_eq!(unsatisfied.size, 5);
+    /// ```
+    pub fn explain(&self, area: Rect) -> LayoutExplanation {
+        let (segments, spacers) = self.split_with_spacers(area);
+        LayoutExplanation::new(
+            self.direction,
+            self.flex,
+            area.inner(self.margin),
+            &self.constraints,
+            &segments,
+            spacers,
+        )
+    }
+
     fn try_split(&self, area: Rect) -> Result<(Segments, Spacers), AddConstraintError> {
         // To take advantage of all of [`kasuari`] features, we would want to store the `Solver` in
         // one of the fields of the Layout struct. And we would want to set it up such that we could
@@ -823,8 +934,15 @@ impl Layout {
         // debug_elements(&segments, &changes);
         // debug_elements(&spacers, &changes);
 
-        let segment_rects = changes_to_rects(&changes, &segments, inner_area, self.direction);
-        let spacer_rects = changes_to_rects(&changes, &spacers, inner_area, self.direction);
+        let mut segment_rects = changes_to_rects(&changes, &segments, inner_area, self.direction);
+        let mut spacer_rects = changes_to_rects(&changes, &spacers, inner_area, self.direction);
+
+        if self.direction == Direction::Horizontal
+            && self.writing_direction == WritingDirection::RightToLeft
+        {
+            segment_rects = mirror_rects(&segment_rects, inner_area);
+            spacer_rects = mirror_rects(&spacer_rects, inner_area);
+        }
 
         Ok((segment_rects, spacer_rects))
     }
@@ -1127,6 +1245,21 @@ fn changes_to_rects(
         .collect::<Rects>()
 }
 
+/// Mirrors the rects horizontally within the area, for right-to-left layouts.
+fn mirror_rects(rects: &[Rect], area: Rect) -> Rects {
+    rects
+        .iter()
+        .map(|rect| {
+            // the sum of the edges can overflow u16, but the result always fits inside the area
+            let x = u32::from(area.left()) + u32::from(area.right()) - u32::from(rect.right());
+            Rect {
+                x: u16::try_from(x).unwrap_or(u16::MAX),
+                ..*rect
+            }
+        })
+        .collect()
+}
+
 /// please leave this here as it's useful for debugging unit tests when we make any changes to
 /// layout code - we should replace this with tracing in the future.
 #[expect(dead_code)]
@@ -1214,7 +1347,7 @@ impl From<&Element> for Expression {
     }
 }
 
-mod strengths {
+pub(super) mod strengths {
     use kasuari::Strength;
 
     /// The strength to apply to Spacers to ensure that their sizes are equal.
@@ -1351,6 +1484,7 @@ mod tests {
                 constraints: vec![],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                writing_direction: WritingDirection::LeftToRight,
             }
         );
     }
@@ -1396,6 +1530,7 @@ mod tests {
                 constraints: vec![Constraint::Min(0)],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                writing_direction: WritingDirection::LeftToRight,
             }
         );
     }
@@ -1410,6 +1545,7 @@ mod tests {
                 constraints: vec![Constraint::Min(0)],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                writing_direction: WritingDirection::LeftToRight,
             }
         );
     }
@@ -1509,6 +1645,56 @@ mod tests {
         assert_eq!(Layout::default().flex(Flex::Center).flex, Flex::Center);
     }
 
+    #[test]
+    fn writing_direction() {
+        assert_eq!(
+            Layout::default().writing_direction,
+            WritingDirection::LeftToRight
+        );
+        assert_eq!(
+            Layout::default()
+                .writing_direction(WritingDirection::RightToLeft)
+                .writing_direction,
+            WritingDirection::RightToLeft
+        );
+    }
+
+    #[test]
+    fn split_right_to_left() {
+        let layout = Layout::horizontal([Constraint::Length(2), Constraint::Length(3)])
+            .spacing(1)
+            .writing_direction(WritingDirection::RightToLeft);
+        let (areas, spacers) = layout.split_with_spacers(Rect::new(2, 1, 10, 1));
+        assert_eq!(areas[..], [Rect::new(10, 1, 2, 1), Rect::new(6, 1, 3, 1)]);
+        assert_eq!(
+            spacers[..],
+            [
+                Rect::new(12, 1, 0, 1),
+                Rect::new(9, 1, 1, 1),
+                Rect::new(2, 1, 4, 1)
+            ]
+        );
+
+        // vertical layouts are not mirrored
+        let layout = Layout::vertical([Constraint::Length(2), Constraint::Length(3)])
+            .writing_direction(WritingDirection::RightToLeft);
+        assert_eq!(
+            layout.split(Rect::new(0, 0, 1, 10))[..],
+            [Rect::new(0, 0, 1, 2), Rect::new(0, 2, 1, 3)]
+        );
+
+        // mirroring at the far edge of the coordinate space doesn't overflow
+        let layout = Layout::horizontal([Constraint::Length(1), Constraint::Fill(1)])
+            .writing_direction(WritingDirection::RightToLeft);
+        assert_eq!(
+            layout.split(Rect::new(u16::MAX - 10, 0, 10, 1))[..],
+            [
+                Rect::new(u16::MAX - 1, 0, 1, 1),
+                Rect::new(u16::MAX - 10, 0, 9, 1)
+            ]
+        );
+    }
+
     #[test]
     fn spacing() {
         assert_eq!(Layout::default().spacing(10).spacing, Spacing::Space(10));
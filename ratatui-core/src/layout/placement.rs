@@ -0,0 +1,445 @@
+use strum::{Display, EnumString};
+
+use crate::layout::{Rect, Size};
+
+/// The side of an anchor [`Rect`] on which a popup is placed.
+///
+/// See [`Placement`] for more details.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Side {
+    /// Above the anchor.
+    Top,
+    /// Below the anchor (default).
+    #[default]
+    Bottom,
+    /// To the left of the anchor.
+    Left,
+    /// To the right of the anchor.
+    Right,
+}
+
+impl Side {
+    /// The opposite side.
+    ///
+    /// `Top` returns `Bottom`, `Left` returns `Right`, and vice versa.
+    #[inline]
+    #[must_use = "returns the opposite side"]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// How a popup is aligned with its anchor along the side it is placed on.
+///
+/// For popups placed on the [`Top`](Side::Top) or [`Bottom`](Side::Bottom) side, `Start` aligns the
+/// left edges and `End` aligns the right edges. For popups placed on the [`Left`](Side::Left) or
+/// [`Right`](Side::Right) side, `Start` aligns the top edges and `End` aligns the bottom edges.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnchorAlignment {
+    /// Align the start edges of the popup and the anchor (default).
+    #[default]
+    Start,
+    /// Center the popup on the anchor.
+    Center,
+    /// Align the end edges of the popup and the anchor.
+    End,
+}
+
+/// Describes where to place a popup, such as a dropdown or a tooltip, relative to an anchor.
+///
+/// A placement is used with [`Rect::anchored`] to compute the area of a popup of a given [`Size`]
+/// next to an anchor [`Rect`] (e.g. the input field a dropdown belongs to), within a bounding
+/// [`Rect`] (usually the whole frame). It is made of:
+///
+/// - the preferred [`Side`] of the anchor on which the popup is placed,
+/// - the [`AnchorAlignment`] of the popup along that side,
+/// - a `gap` between the anchor and the popup, and an `offset` along the side,
+/// - whether the popup may `flip` to the opposite side when it doesn't fit on the preferred side,
+/// - whether the popup may `shift` along the side to stay within the bounds.
+///
+/// By default, popups are placed below the anchor, aligned with its left edge, and both flipping
+/// and shifting are enabled.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::{AnchorAlignment, Placement, Rect, Side, Size};
+///
+/// let screen = Rect::new(0, 0, 80, 24);
+/// let placement = Placement::new(Side::Bottom).alignment(AnchorAlignment::End);
+///
+/// // there is room below the field, so the dropdown is placed there
+/// let field = Rect::new(10, 2, 20, 1);
+/// let dropdown = field.anchored(Size::new(10, 5), placement, screen);
+/// assert_eq!(dropdown, Rect::new(20, 3, 10, 5));
+///
+/// // there is no room below the field, so the dropdown flips above it
+/// let field = Rect::new(10, 21, 20, 1);
+/// let dropdown = field.anchored(Size::new(10, 5), placement, screen);
+/// assert_eq!(dropdown, Rect::new(20, 16, 10, 5));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Placement {
+    /// The preferred side of the anchor on which the popup is placed.
+    pub side: Side,
+    /// The alignment of the popup along the side of the anchor.
+    pub alignment: AnchorAlignment,
+    /// The distance between the anchor and the popup, in cells.
+    pub gap: u16,
+    /// How far the popup is moved along the side of the anchor, in cells. Positive values move the
+    /// popup to the right or down.
+    pub offset: i32,
+    /// Whether the popup is placed on the opposite side when it doesn't fit on the preferred side.
+    pub flip: bool,
+    /// Whether the popup is moved to stay within the bounds. When disabled, the parts of the popup
+    /// outside of the bounds are cut off instead.
+    pub shift: bool,
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Self::new(Side::default())
+    }
+}
+
+impl Placement {
+    /// Creates a new placement on the given side of the anchor.
+    ///
+    /// The popup is aligned with the start of the anchor, and flipping and shifting are enabled.
+    pub const fn new(side: Side) -> Self {
+        Self {
+            side,
+            alignment: AnchorAlignment::Start,
+            gap: 0,
+            offset: 0,
+            flip: true,
+            shift: true,
+        }
+    }
+
+    /// Sets the alignment of the popup along the side of the anchor.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn alignment(mut self, alignment: AnchorAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets the distance between the anchor and the popup, in cells.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets how far the popup is moved along the side of the anchor, in cells.
+    ///
+    /// Positive values move the popup to the right (when placed above or below the anchor) or
+    /// down (when placed to the left or right of the anchor).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn offset(mut self, offset: i32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets whether the popup is placed on the opposite side when it doesn't fit on the preferred
+    /// side.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn flip(mut self, flip: bool) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    /// Sets whether the popup is moved to stay within the bounds.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn shift(mut self, shift: bool) -> Self {
+        self.shift = shift;
+        self
+    }
+
+    /// Returns the side the popup is placed on, after flipping if needed.
+    ///
+    /// The popup flips to the opposite side only when it doesn't fit on the preferred side and it
+    /// fits on the opposite side.
+    pub fn resolve_side(self, anchor: Rect, size: Size, bounds: Rect) -> Side {
+        let size = clamp_size(size, bounds);
+        if !self.flip || self.fits(self.side, anchor, size, bounds) {
+            return self.side;
+        }
+        let opposite = self.side.opposite();
+        if self.fits(opposite, anchor, size, bounds) {
+            opposite
+        } else {
+            self.side
+        }
+    }
+
+    /// Computes the area of a popup of the given size. See [`Rect::anchored`].
+    pub(crate) fn place(self, anchor: Rect, popup_size: Size, bounds: Rect) -> Rect {
+        let popup_size = clamp_size(popup_size, bounds);
+        let side = self.resolve_side(anchor, popup_size, bounds);
+        let mut area = self.area(side, anchor, popup_size);
+        if self.shift {
+            area.x = area.x.clamp(
+                i64::from(bounds.left()),
+                i64::from(bounds.right()) - area.width,
+            );
+            area.y = area.y.clamp(
+                i64::from(bounds.top()),
+                i64::from(bounds.bottom()) - area.height,
+            );
+        }
+        area.intersection(bounds)
+    }
+
+    fn fits(self, side: Side, anchor: Rect, popup_size: Size, bounds: Rect) -> bool {
+        let area = self.area(side, anchor, popup_size);
+        match side {
+            Side::Top | Side::Bottom => {
+                area.y >= i64::from(bounds.top())
+                    && area.y + area.height <= i64::from(bounds.bottom())
+            }
+            Side::Left | Side::Right => {
+                area.x >= i64::from(bounds.left())
+                    && area.x + area.width <= i64::from(bounds.right())
+            }
+        }
+    }
+
+    /// The unclamped area of the popup on the given side of the anchor.
+    fn area(self, side: Side, anchor: Rect, popup_size: Size) -> SignedRect {
+        let (width, height) = (i64::from(popup_size.width), i64::from(popup_size.height));
+        let (left, top) = (i64::from(anchor.left()), i64::from(anchor.top()));
+        let (right, bottom) = (i64::from(anchor.right()), i64::from(anchor.bottom()));
+        let gap = i64::from(self.gap);
+        let align = |start: i64, end: i64, length: i64| {
+            let position = match self.alignment {
+                AnchorAlignment::Start => start,
+                AnchorAlignment::Center => start + (end - start - length) / 2,
+                AnchorAlignment::End => end - length,
+            };
+            position + i64::from(self.offset)
+        };
+        let (x, y) = match side {
+            Side::Top => (align(left, right, width), top - gap - height),
+            Side::Bottom => (align(left, right, width), bottom + gap),
+            Side::Left => (left - gap - width, align(top, bottom, height)),
+            Side::Right => (right + gap, align(top, bottom, height)),
+        };
+        SignedRect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+}
+
+/// The popup can't be larger than the bounds.
+fn clamp_size(size: Size, bounds: Rect) -> Size {
+    Size::new(size.width.min(bounds.width), size.height.min(bounds.height))
+}
+
+/// A rect that may extend past the edges of the coordinate space while it is being placed.
+#[derive(Debug, Clone, Copy)]
+struct SignedRect {
+    x: i64,
+    y: i64,
+    width: i64,
+    height: i64,
+}
+
+impl SignedRect {
+    fn intersection(self, bounds: Rect) -> Rect {
+        let left = self.x.max(i64::from(bounds.left()));
+        let top = self.y.max(i64::from(bounds.top()));
+        let right = (self.x + self.width).min(i64::from(bounds.right()));
+        let bottom = (self.y + self.height).min(i64::from(bounds.bottom()));
+        if left >= right || top >= bottom {
+            // the popup is entirely outside of the bounds
+            return Rect::new(bounds.x, bounds.y, 0, 0);
+        }
+        // all the values are within the bounds, so they fit in a u16
+        Rect::new(
+            left as u16,
+            top as u16,
+            (right - left) as u16,
+            (bottom - top) as u16,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const BOUNDS: Rect = Rect::new(0, 0, 40, 20);
+    const ANCHOR: Rect = Rect::new(10, 8, 10, 2);
+
+    #[test]
+    fn side_opposite() {
+        assert_eq!(Side::Top.opposite(), Side::Bottom);
+        assert_eq!(Side::Bottom.opposite(), Side::Top);
+        assert_eq!(Side::Left.opposite(), Side::Right);
+        assert_eq!(Side::Right.opposite(), Side::Left);
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(
+            Placement::default(),
+            Placement {
+                side: Side::Bottom,
+                alignment: AnchorAlignment::Start,
+                gap: 0,
+                offset: 0,
+                flip: true,
+                shift: true,
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::top(Side::Top, Rect::new(10, 4, 6, 4))]
+    #[case::bottom(Side::Bottom, Rect::new(10, 10, 6, 4))]
+    #[case::left(Side::Left, Rect::new(4, 8, 6, 4))]
+    #[case::right(Side::Right, Rect::new(20, 8, 6, 4))]
+    fn sides(#[case] side: Side, #[case] expected: Rect) {
+        let placement = Placement::new(side);
+        assert_eq!(
+            ANCHOR.anchored(Size::new(6, 4), placement, BOUNDS),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::bottom_start(Side::Bottom, AnchorAlignment::Start, Rect::new(10, 10, 6, 4))]
+    #[case::bottom_center(Side::Bottom, AnchorAlignment::Center, Rect::new(12, 10, 6, 4))]
+    #[case::bottom_end(Side::Bottom, AnchorAlignment::End, Rect::new(14, 10, 6, 4))]
+    #[case::right_start(Side::Right, AnchorAlignment::Start, Rect::new(20, 8, 6, 4))]
+    #[case::right_center(Side::Right, AnchorAlignment::Center, Rect::new(20, 7, 6, 4))]
+    #[case::right_end(Side::Right, AnchorAlignment::End, Rect::new(20, 6, 6, 4))]
+    fn alignments(#[case] side: Side, #[case] alignment: AnchorAlignment, #[case] expected: Rect) {
+        let placement = Placement::new(side).alignment(alignment);
+        assert_eq!(
+            ANCHOR.anchored(Size::new(6, 4), placement, BOUNDS),
+            expected
+        );
+    }
+
+    #[test]
+    fn gap_and_offset() {
+        let placement = Placement::new(Side::Bottom).gap(1).offset(-2);
+        assert_eq!(
+            ANCHOR.anchored(Size::new(6, 4), placement, BOUNDS),
+            Rect::new(8, 11, 6, 4)
+        );
+        let placement = Placement::new(Side::Left).gap(2).offset(1);
+        assert_eq!(
+            ANCHOR.anchored(Size::new(6, 4), placement, BOUNDS),
+            Rect::new(2, 9, 6, 4)
+        );
+        let placement = Placement::new(Side::Bottom).offset(i32::MAX);
+        assert_eq!(
+            ANCHOR.anchored(Size::new(6, 4), placement, BOUNDS),
+            Rect::new(34, 10, 6, 4)
+        );
+    }
+
+    #[rstest]
+    #[case::bottom_to_top(Side::Bottom, Rect::new(10, 13, 6, 2), Rect::new(10, 7, 6, 6))]
+    #[case::top_to_bottom(Side::Top, Rect::new(10, 2, 6, 2), Rect::new(10, 4, 6, 6))]
+    #[case::right_to_left(Side::Right, Rect::new(30, 8, 6, 2), Rect::new(24, 8, 6, 6))]
+    #[case::left_to_right(Side::Left, Rect::new(4, 8, 6, 2), Rect::new(10, 8, 6, 6))]
+    fn flip(#[case] side: Side, #[case] anchor: Rect, #[case] expected: Rect) {
+        let placement = Placement::new(side);
+        assert_eq!(
+            anchor.anchored(Size::new(6, 6), placement, BOUNDS),
+            expected
+        );
+        assert_eq!(
+            placement.resolve_side(anchor, Size::new(6, 6), BOUNDS),
+            side.opposite()
+        );
+    }
+
+    #[test]
+    fn no_flip_shifts_into_bounds() {
+        let anchor = Rect::new(10, 16, 6, 2);
+        let placement = Placement::new(Side::Bottom).flip(false);
+        assert_eq!(
+            anchor.anchored(Size::new(6, 6), placement, BOUNDS),
+            Rect::new(10, 14, 6, 6)
+        );
+    }
+
+    #[test]
+    fn keeps_side_when_neither_side_fits() {
+        let anchor = Rect::new(10, 8, 6, 2);
+        let placement = Placement::new(Side::Bottom);
+        assert_eq!(
+            placement.resolve_side(anchor, Size::new(6, 12), BOUNDS),
+            Side::Bottom
+        );
+        // the popup is shifted up to fit within the bounds
+        assert_eq!(
+            anchor.anchored(Size::new(6, 12), placement, BOUNDS),
+            Rect::new(10, 8, 6, 12)
+        );
+    }
+
+    #[test]
+    fn shift_along_side() {
+        let anchor = Rect::new(36, 2, 4, 1);
+        let placement = Placement::new(Side::Bottom);
+        assert_eq!(
+            anchor.anchored(Size::new(10, 3), placement, BOUNDS),
+            Rect::new(30, 3, 10, 3)
+        );
+    }
+
+    #[test]
+    fn no_shift_cuts_off() {
+        let anchor = Rect::new(36, 2, 4, 1);
+        let placement = Placement::new(Side::Bottom).shift(false);
+        assert_eq!(
+            anchor.anchored(Size::new(10, 3), placement, BOUNDS),
+            Rect::new(36, 3, 4, 3)
+        );
+        let anchor = Rect::new(0, 2, 4, 1);
+        let placement = Placement::new(Side::Left).flip(false).shift(false);
+        assert_eq!(
+            anchor.anchored(Size::new(10, 3), placement, BOUNDS),
+            Rect::ZERO
+        );
+    }
+
+    #[test]
+    fn larger_than_bounds() {
+        let placement = Placement::new(Side::Bottom);
+        assert_eq!(
+            ANCHOR.anchored(Size::new(100, 100), placement, BOUNDS),
+            BOUNDS
+        );
+    }
+
+    #[test]
+    fn bounds_offset_from_origin() {
+        let bounds = Rect::new(5, 5, 20, 10);
+        let anchor = Rect::new(20, 12, 5, 1);
+        let placement = Placement::new(Side::Bottom).alignment(AnchorAlignment::Center);
+        assert_eq!(
+            anchor.anchored(Size::new(9, 4), placement, bounds),
+            Rect::new(16, 8, 9, 4)
+        );
+    }
+}
@@ -9,7 +9,7 @@ use crate::layout::{Margin, Offset, Position, Size};
 mod iter;
 mod ops;
 
-use super::{Constraint, Flex, Layout};
+use super::{Constraint, Flex, Layout, Placement};
 
 /// A rectangular area in the terminal.
 ///
@@ -50,6 +50,7 @@ use super::{Constraint, Flex, Layout};
 /// - [`union`](Self::union) - Combine with another rectangle to create a bounding box
 /// - [`intersection`](Self::intersection) - Find the overlapping area with another rectangle
 /// - [`clamp`](Self::clamp) - Constrain the rectangle to fit within another
+/// - [`lerp`](Self::lerp) - Interpolate between two rectangles
 ///
 /// # Positioning and Centering
 ///
@@ -57,6 +58,7 @@ use super::{Constraint, Flex, Layout};
 ///   constraint
 /// - [`centered_vertically`](Self::centered_vertically) - Center vertically within a constraint
 /// - [`centered`](Self::centered) - Center both horizontally and vertically
+/// - [`anchored`](Self::anchored) - Place a popup next to the rectangle
 ///
 /// # Testing and Iteration
 ///
@@ -392,6 +394,42 @@ impl Rect {
         Self::new(x, y, width, height)
     }
 
+    /// Linearly interpolates between this `Rect` and the other `Rect`.
+    ///
+    /// `t` is the fraction of the way from this `Rect` (`0.0`) to the other `Rect` (`1.0`), and is
+    /// clamped to that range. The edges of the `Rect` are interpolated and rounded to the nearest
+    /// cell, so that adjacent areas stay adjacent while being interpolated. This is the building
+    /// block of [`Transition`](crate::layout::Transition).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let from = Rect::new(0, 0, 10, 10);
+    /// let to = Rect::new(10, 0, 20, 10);
+    /// assert_eq!(from.lerp(to, 0.5), Rect::new(5, 0, 15, 10));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        let t = if t.is_nan() { 1.0 } else { t.clamp(0.0, 1.0) };
+        // the values are always between the two edges, so the result fits in a u16
+        let lerp = |a: u16, b: u16| {
+            let (a, b) = (f64::from(a), f64::from(b));
+            (a + (b - a) * t + 0.5) as u16
+        };
+        let left = lerp(self.left(), other.left());
+        let top = lerp(self.top(), other.top());
+        let right = lerp(self.right(), other.right());
+        let bottom = lerp(self.bottom(), other.bottom());
+        Self::new(
+            left,
+            top,
+            right.saturating_sub(left),
+            bottom.saturating_sub(top),
+        )
+    }
+
     /// An iterator over rows within the `Rect`.
     ///
     /// Each row is a full `Rect` region with height 1 that can be used for rendering widgets
@@ -557,6 +595,32 @@ impl Rect {
             .centered_vertically(vertical_constraint)
     }
 
+    /// Returns the area of a popup of the given size placed next to this `Rect`.
+    ///
+    /// This `Rect` is the anchor the popup is attached to, e.g. the input field of a dropdown or
+    /// the item a tooltip describes. The popup is placed on the side of the anchor described by
+    /// the [`Placement`], and kept within `bounds` (usually the area of the whole frame): it is
+    /// flipped to the opposite side when it doesn't fit on the preferred side, and shifted
+    /// along the side when it would overflow. The popup is never larger than `bounds`.
+    ///
+    /// See [`Placement`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::{Placement, Rect, Side, Size};
+    /// use ratatui_core::terminal::Frame;
+    ///
+    /// fn render(frame: &mut Frame, field: Rect) {
+    ///     let placement = Placement::new(Side::Bottom).gap(1);
+    ///     let dropdown = field.anchored(Size::new(20, 8), placement, frame.area());
+    /// }
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub fn anchored(self, size: Size, placement: Placement, bounds: Self) -> Self {
+        placement.place(self, size, bounds)
+    }
+
     /// Split the rect into a number of sub-rects according to the given [`Layout`].
     ///
     /// An ergonomic wrapper around [`Layout::split`] that returns an array of `Rect`s instead of
@@ -957,6 +1021,31 @@ mod tests {
         assert_eq!(rect.clamp(other), expected);
     }
 
+    #[rstest]
+    #[case::start(0.0, Rect::new(0, 0, 10, 4))]
+    #[case::middle(0.5, Rect::new(5, 2, 10, 3))]
+    #[case::end(1.0, Rect::new(10, 4, 10, 2))]
+    #[case::before_start(-1.0, Rect::new(0, 0, 10, 4))]
+    #[case::after_end(2.0, Rect::new(10, 4, 10, 2))]
+    #[case::rounds_edges(0.25, Rect::new(3, 1, 10, 4))]
+    fn lerp(#[case] t: f64, #[case] expected: Rect) {
+        let from = Rect::new(0, 0, 10, 4);
+        let to = Rect::new(10, 4, 10, 2);
+        assert_eq!(from.lerp(to, t), expected);
+    }
+
+    #[test]
+    fn lerp_keeps_adjacent_areas_adjacent() {
+        let [left, right] = [Rect::new(0, 0, 3, 1), Rect::new(3, 0, 7, 1)];
+        let [left_to, right_to] = [Rect::new(0, 0, 8, 1), Rect::new(8, 0, 2, 1)];
+        for t in [0.1, 0.3, 0.5, 0.7, 0.9] {
+            assert_eq!(
+                left.lerp(left_to, t).right(),
+                right.lerp(right_to, t).left()
+            );
+        }
+    }
+
     #[test]
     fn rows() {
         let area = Rect::new(0, 0, 3, 2);
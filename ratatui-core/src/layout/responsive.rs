@@ -0,0 +1,369 @@
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+
+use crate::layout::{Layout, Rect, Size};
+
+/// A minimum size that an area must reach for a [`Responsive`] layout to use a given [`Layout`].
+///
+/// A breakpoint matches an area when the area's width is at least [`min_width`] and its height is
+/// at least [`min_height`]. A breakpoint created with [`Breakpoint::width`] only considers the
+/// width of the area, and one created with [`Breakpoint::height`] only considers the height.
+///
+/// [`min_width`]: Self::min_width
+/// [`min_height`]: Self::min_height
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::{Breakpoint, Rect};
+///
+/// let wide = Breakpoint::width(100);
+/// assert!(wide.matches(Rect::new(0, 0, 120, 10)));
+/// assert!(!wide.matches(Rect::new(0, 0, 80, 10)));
+///
+/// let large = Breakpoint::new(100, 30);
+/// assert!(large.matches(Rect::new(0, 0, 100, 30)));
+/// assert!(!large.matches(Rect::new(0, 0, 100, 20)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Breakpoint {
+    /// The minimum width (in columns) an area must have to match this breakpoint.
+    pub min_width: u16,
+    /// The minimum height (in rows) an area must have to match this breakpoint.
+    pub min_height: u16,
+}
+
+impl Breakpoint {
+    /// Creates a new breakpoint that matches areas at least `min_width` wide and `min_height`
+    /// tall.
+    pub const fn new(min_width: u16, min_height: u16) -> Self {
+        Self {
+            min_width,
+            min_height,
+        }
+    }
+
+    /// Creates a new breakpoint that matches areas at least `min_width` wide, regardless of their
+    /// height.
+    pub const fn width(min_width: u16) -> Self {
+        Self::new(min_width, 0)
+    }
+
+    /// Creates a new breakpoint that matches areas at least `min_height` tall, regardless of their
+    /// width.
+    pub const fn height(min_height: u16) -> Self {
+        Self::new(0, min_height)
+    }
+
+    /// Returns true if the given area is large enough to match this breakpoint.
+    ///
+    /// Anything that converts into a [`Size`] can be passed, e.g. a [`Rect`] or a `(u16, u16)`
+    /// tuple of `(width, height)`.
+    pub fn matches<S: Into<Size>>(self, size: S) -> bool {
+        let size = size.into();
+        size.width >= self.min_width && size.height >= self.min_height
+    }
+}
+
+impl From<Size> for Breakpoint {
+    fn from(size: Size) -> Self {
+        Self::new(size.width, size.height)
+    }
+}
+
+/// Selects one of several [`Layout`]s depending on the size of the area being split.
+///
+/// A `Responsive` layout is made of a fallback layout and a list of [`Breakpoint`]s, each with
+/// its own layout. When an area is split, the breakpoints are checked in the order they were
+/// added and the layout of the first one that [matches](Breakpoint::matches) the area is used.
+/// When no breakpoint matches, the fallback layout is used. Add breakpoints from the largest to
+/// the smallest so that the most specific layout wins.
+///
+/// Since each layout can have a different number of constraints, the result of a split has a
+/// variable length, so it is returned as a slice of [`Rect`]s along with the index of the
+/// breakpoint that matched (`None` when the fallback layout was used).
+///
+/// # Construction
+///
+/// - [`new`](Self::new) - Create a responsive layout with a fallback layout
+/// - [`default`](Default::default) - Create a responsive layout with a default fallback layout
+///
+/// # Configuration
+///
+/// - [`breakpoint`](Self::breakpoint) - Add a layout to use when the area matches a breakpoint
+///
+/// # Layout Operations
+///
+/// - [`select`](Self::select) - Get the layout that will be used for a given area
+/// - [`split`](Self::split) - Split an area with the layout that matches it
+/// - [`split_with_spacers`](Self::split_with_spacers) - Split an area and return both areas and
+///   spacers
+///
+/// # Examples
+///
+/// Stack three panes vertically unless the area is at least 100 columns wide:
+///
+/// ```rust
+/// use ratatui_core::layout::{Breakpoint, Constraint, Layout, Rect, Responsive};
+///
+/// let responsive = Responsive::new(Layout::vertical([Constraint::Fill(1); 3])).breakpoint(
+///     Breakpoint::width(100),
+///     Layout::horizontal([Constraint::Fill(1); 3]),
+/// );
+///
+/// let (breakpoint, areas) = responsive.split(Rect::new(0, 0, 120, 30));
+/// assert_eq!(breakpoint, Some(0));
+/// assert_eq!(areas[0], Rect::new(0, 0, 40, 30));
+///
+/// let (breakpoint, areas) = responsive.split(Rect::new(0, 0, 60, 30));
+/// assert_eq!(breakpoint, None);
+/// assert_eq!(areas[0], Rect::new(0, 0, 60, 10));
+/// ```
+///
+/// For comprehensive layout documentation and examples, see the [`layout`](crate::layout) module.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Responsive {
+    fallback: Layout,
+    breakpoints: Vec<(Breakpoint, Layout)>,
+}
+
+impl Responsive {
+    /// Creates a new responsive layout that uses `fallback` when no breakpoint matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint, Layout, Responsive};
+    ///
+    /// let responsive = Responsive::new(Layout::vertical([Constraint::Fill(1); 2]));
+    /// ```
+    pub const fn new(fallback: Layout) -> Self {
+        Self {
+            fallback,
+            breakpoints: Vec::new(),
+        }
+    }
+
+    /// Adds a layout to use when the area being split matches the given breakpoint.
+    ///
+    /// Breakpoints are checked in the order they are added, so larger breakpoints should be added
+    /// before smaller ones. The index of the breakpoint (starting at 0) is returned from
+    /// [`Responsive::split`] when it matches.
+    ///
+    /// The breakpoint can be anything that converts into a [`Breakpoint`], such as a [`Size`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Breakpoint, Constraint, Layout, Responsive, Size};
+    ///
+    /// let responsive = Responsive::new(Layout::vertical([Constraint::Fill(1); 3]))
+    ///     .breakpoint(
+    ///         Size::new(160, 40),
+    ///         Layout::horizontal([Constraint::Fill(1); 3]),
+    ///     )
+    ///     .breakpoint(
+    ///         Breakpoint::width(100),
+    ///         Layout::horizontal([Constraint::Fill(1); 2]),
+    ///     );
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn breakpoint<B: Into<Breakpoint>>(mut self, breakpoint: B, layout: Layout) -> Self {
+        self.breakpoints.push((breakpoint.into(), layout));
+        self
+    }
+
+    /// Returns the index of the matching breakpoint and the layout that will be used to split the
+    /// given area.
+    ///
+    /// The index is `None` when no breakpoint matches and the fallback layout is used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Breakpoint, Constraint, Layout, Rect, Responsive};
+    ///
+    /// let wide = Layout::horizontal([Constraint::Fill(1); 3]);
+    /// let responsive = Responsive::new(Layout::vertical([Constraint::Fill(1); 3]))
+    ///     .breakpoint(Breakpoint::width(100), wide.clone());
+    ///
+    /// assert_eq!(
+    ///     responsive.select(Rect::new(0, 0, 100, 10)),
+    ///     (Some(0), &wide)
+    /// );
+    /// ```
+    pub fn select(&self, area: Rect) -> (Option<usize>, &Layout) {
+        self.breakpoints
+            .iter()
+            .position(|(breakpoint, _)| breakpoint.matches(area))
+            .map_or((None, &self.fallback), |index| {
+                (Some(index), &self.breakpoints[index].1)
+            })
+    }
+
+    /// Splits the area with the layout of the first breakpoint that matches it, or the fallback
+    /// layout if none match.
+    ///
+    /// Returns the index of the breakpoint that matched (`None` for the fallback layout) and the
+    /// split areas. See [`Layout::split`] for more details on how the areas are computed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Breakpoint, Constraint, Layout, Rect, Responsive};
+    ///
+    /// let responsive = Responsive::new(Layout::vertical([Constraint::Fill(1); 2])).breakpoint(
+    ///     Breakpoint::width(100),
+    ///     Layout::horizontal([Constraint::Fill(1); 3]),
+    /// );
+    ///
+    /// let (breakpoint, areas) = responsive.split(Rect::new(0, 0, 90, 10));
+    /// assert_eq!(breakpoint, None);
+    /// assert_eq!(areas.len(), 2);
+    /// ```
+    pub fn split(&self, area: Rect) -> (Option<usize>, Rc<[Rect]>) {
+        let (breakpoint, layout) = self.select(area);
+        (breakpoint, layout.split(area))
+    }
+
+    /// Splits the area with the layout of the first breakpoint that matches it, or the fallback
+    /// layout if none match, returning both the areas and the spacers between them.
+    ///
+    /// See [`Layout::split_with_spacers`] for more details on how the areas and spacers are
+    /// computed.
+    pub fn split_with_spacers(&self, area: Rect) -> (Option<usize>, Rc<[Rect]>, Rc<[Rect]>) {
+        let (breakpoint, layout) = self.select(area);
+        let (areas, spacers) = layout.split_with_spacers(area);
+        (breakpoint, areas, spacers)
+    }
+}
+
+impl From<Layout> for Responsive {
+    fn from(fallback: Layout) -> Self {
+        Self::new(fallback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::layout::Constraint;
+
+    fn responsive() -> Responsive {
+        Responsive::new(Layout::vertical([Constraint::Fill(1); 3]))
+            .breakpoint(
+                Breakpoint::new(150, 30),
+                Layout::horizontal([Constraint::Fill(1); 3]),
+            )
+            .breakpoint(
+                Breakpoint::width(100),
+                Layout::horizontal([Constraint::Fill(1); 2]),
+            )
+    }
+
+    #[test]
+    fn breakpoint_constructors() {
+        assert_eq!(
+            Breakpoint::new(10, 20),
+            Breakpoint {
+                min_width: 10,
+                min_height: 20
+            }
+        );
+        assert_eq!(Breakpoint::width(10), Breakpoint::new(10, 0));
+        assert_eq!(Breakpoint::height(20), Breakpoint::new(0, 20));
+        assert_eq!(Breakpoint::from(Size::new(10, 20)), Breakpoint::new(10, 20));
+    }
+
+    #[test]
+    fn breakpoint_matches() {
+        let breakpoint = Breakpoint::new(10, 5);
+        assert!(breakpoint.matches(Rect::new(0, 0, 10, 5)));
+        assert!(breakpoint.matches(Rect::new(3, 3, 20, 10)));
+        assert!(!breakpoint.matches(Rect::new(0, 0, 9, 5)));
+        assert!(!breakpoint.matches(Rect::new(0, 0, 10, 4)));
+        assert!(breakpoint.matches((10, 5)));
+        assert!(Breakpoint::default().matches(Rect::ZERO));
+    }
+
+    #[test]
+    fn select() {
+        let responsive = responsive();
+        let (index, layout) = responsive.select(Rect::new(0, 0, 160, 40));
+        assert_eq!(index, Some(0));
+        assert_eq!(layout, &Layout::horizontal([Constraint::Fill(1); 3]));
+
+        // wide enough for the first breakpoint but not tall enough
+        let (index, layout) = responsive.select(Rect::new(0, 0, 160, 20));
+        assert_eq!(index, Some(1));
+        assert_eq!(layout, &Layout::horizontal([Constraint::Fill(1); 2]));
+
+        let (index, layout) = responsive.select(Rect::new(0, 0, 99, 40));
+        assert_eq!(index, None);
+        assert_eq!(layout, &Layout::vertical([Constraint::Fill(1); 3]));
+    }
+
+    #[test]
+    fn split() {
+        let responsive = responsive();
+        let (index, areas) = responsive.split(Rect::new(0, 0, 150, 30));
+        assert_eq!(index, Some(0));
+        assert_eq!(
+            areas[..],
+            [
+                Rect::new(0, 0, 50, 30),
+                Rect::new(50, 0, 50, 30),
+                Rect::new(100, 0, 50, 30),
+            ]
+        );
+
+        let (index, areas) = responsive.split(Rect::new(0, 0, 100, 10));
+        assert_eq!(index, Some(1));
+        assert_eq!(
+            areas[..],
+            [Rect::new(0, 0, 50, 10), Rect::new(50, 0, 50, 10)]
+        );
+
+        let (index, areas) = responsive.split(Rect::new(0, 0, 40, 9));
+        assert_eq!(index, None);
+        assert_eq!(
+            areas[..],
+            [
+                Rect::new(0, 0, 40, 3),
+                Rect::new(0, 3, 40, 3),
+                Rect::new(0, 6, 40, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_with_spacers() {
+        let responsive = Responsive::new(Layout::vertical([Constraint::Length(2)])).breakpoint(
+            Breakpoint::width(10),
+            Layout::horizontal([Constraint::Length(4); 2]).spacing(1),
+        );
+        let (index, areas, spacers) = responsive.split_with_spacers(Rect::new(0, 0, 10, 1));
+        assert_eq!(index, Some(0));
+        assert_eq!(areas[..], [Rect::new(0, 0, 4, 1), Rect::new(5, 0, 4, 1)]);
+        assert_eq!(
+            spacers[..],
+            [
+                Rect::new(0, 0, 0, 1),
+                Rect::new(4, 0, 1, 1),
+                Rect::new(9, 0, 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_breakpoints_uses_fallback() {
+        let layout = Layout::horizontal([Constraint::Length(5), Constraint::Fill(1)]);
+        let responsive = Responsive::from(layout.clone());
+        let area = Rect::new(0, 0, 20, 5);
+        assert_eq!(responsive.split(area), (None, layout.split(area)));
+    }
+}
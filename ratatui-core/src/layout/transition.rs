@@ -0,0 +1,278 @@
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::layout::{Layout, Rect};
+
+/// An easing function that controls the rate of change of a [`Transition`] over time.
+///
+/// Easing functions map the linear progress of a transition (from `0.0` to `1.0`) to the eased
+/// progress that is used to interpolate the areas. The `EaseIn`, `EaseOut` and `EaseInOut`
+/// functions are cubic curves.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::Easing;
+///
+/// assert_eq!(Easing::Linear.apply(0.5), 0.5);
+/// assert_eq!(Easing::EaseIn.apply(0.5), 0.125);
+/// assert_eq!(Easing::EaseOut.apply(0.5), 0.875);
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    /// Progresses at a constant rate (default).
+    #[default]
+    Linear,
+    /// Starts slowly and accelerates towards the end.
+    EaseIn,
+    /// Starts quickly and decelerates towards the end.
+    EaseOut,
+    /// Starts slowly, accelerates through the middle and decelerates towards the end.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Returns the eased progress for the linear progress `t`.
+    ///
+    /// `t` is clamped to the range `0.0..=1.0`, and the result is always in the same range.
+    #[must_use = "returns the eased progress"]
+    pub fn apply(self, t: f64) -> f64 {
+        let t = if t.is_nan() { 1.0 } else { t.clamp(0.0, 1.0) };
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => {
+                let inv = 1.0 - t;
+                1.0 - inv * inv * inv
+            }
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    let inv = -2.0 * t + 2.0;
+                    1.0 - inv * inv * inv / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// An animated transition between two sets of areas, e.g. the results of two [`Layout`]s.
+///
+/// When the layout of an application changes (e.g. a sidebar is toggled), the areas snap to their
+/// new positions instantly. A `Transition` interpolates each area from its old position and size
+/// to its new one over a [`Duration`], so that panels can slide open and closed.
+///
+/// A transition does not keep track of time itself. Instead, the time elapsed since the start of
+/// the transition is passed explicitly to [`Transition::areas_at`], which makes the result
+/// deterministic and easy to test. Applications typically store an [`Instant`] when the transition
+/// starts and pass `start.elapsed()` each frame.
+///
+/// Areas are matched by index. If the target has more areas than the source, the extra areas grow
+/// from an empty area at their target position. Extra areas in the source are dropped, so the
+/// result always has as many areas as the target.
+///
+/// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui_core::layout::{Constraint, Easing, Layout, Rect, Transition};
+///
+/// let area = Rect::new(0, 0, 100, 10);
+/// let closed = Layout::horizontal([Constraint::Length(0), Constraint::Fill(1)]);
+/// let open = Layout::horizontal([Constraint::Length(20), Constraint::Fill(1)]);
+///
+/// let transition = Transition::between(&closed, &open, area, Duration::from_millis(200))
+///     .easing(Easing::EaseOut);
+///
+/// let areas = transition.areas_at(Duration::from_millis(100));
+/// assert_eq!(areas[0], Rect::new(0, 0, 18, 10));
+/// assert_eq!(areas[1], Rect::new(18, 0, 82, 10));
+///
+/// assert!(transition.is_finished(Duration::from_millis(200)));
+/// assert_eq!(
+///     transition.areas_at(Duration::from_secs(1)),
+///     open.split(area)
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Transition {
+    from: Vec<Rect>,
+    to: Vec<Rect>,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl Transition {
+    /// Creates a new transition from one set of areas to another over the given duration.
+    ///
+    /// The transition uses [`Easing::Linear`] by default.
+    pub fn new<F, T>(from: F, to: T, duration: Duration) -> Self
+    where
+        F: IntoIterator<Item = Rect>,
+        T: IntoIterator<Item = Rect>,
+    {
+        Self {
+            from: from.into_iter().collect(),
+            to: to.into_iter().collect(),
+            duration,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Creates a new transition between the results of splitting `area` with two layouts.
+    pub fn between(from: &Layout, to: &Layout, area: Rect, duration: Duration) -> Self {
+        Self::new(
+            from.split(area).iter().copied(),
+            to.split(area).iter().copied(),
+            duration,
+        )
+    }
+
+    /// Sets the easing function of the transition.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the duration of the transition.
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the eased progress of the transition after `elapsed` time, between `0.0` and `1.0`.
+    ///
+    /// A transition with a zero duration is always complete.
+    pub fn progress(&self, elapsed: Duration) -> f64 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        self.easing
+            .apply(elapsed.as_secs_f64() / self.duration.as_secs_f64())
+    }
+
+    /// Returns true if the transition is complete after `elapsed` time.
+    pub fn is_finished(&self, elapsed: Duration) -> bool {
+        elapsed >= self.duration
+    }
+
+    /// Returns the interpolated areas after `elapsed` time since the start of the transition.
+    ///
+    /// Once the transition is finished, this returns the target areas.
+    pub fn areas_at(&self, elapsed: Duration) -> Rc<[Rect]> {
+        let t = self.progress(elapsed);
+        self.to
+            .iter()
+            .enumerate()
+            .map(|(index, &to)| {
+                let from = self.from.get(index).copied().unwrap_or(Rect {
+                    width: 0,
+                    height: 0,
+                    ..to
+                });
+                from.lerp(to, t)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::layout::Constraint;
+
+    #[rstest]
+    #[case::linear(Easing::Linear, [0.0, 0.25, 0.5, 0.75, 1.0])]
+    #[case::ease_in(Easing::EaseIn, [0.0, 0.015_625, 0.125, 0.421_875, 1.0])]
+    #[case::ease_out(Easing::EaseOut, [0.0, 0.578_125, 0.875, 0.984_375, 1.0])]
+    #[case::ease_in_out(Easing::EaseInOut, [0.0, 0.0625, 0.5, 0.9375, 1.0])]
+    fn apply(#[case] easing: Easing, #[case] expected: [f64; 5]) {
+        for (t, expected) in [0.0, 0.25, 0.5, 0.75, 1.0].into_iter().zip(expected) {
+            assert!(
+                (easing.apply(t) - expected).abs() < f64::EPSILON,
+                "{easing:?} at {t}"
+            );
+        }
+    }
+
+    #[rstest]
+    #[case::before_start(-1.0, 0.0)]
+    #[case::after_end(2.0, 1.0)]
+    #[case::nan(f64::NAN, 1.0)]
+    fn easing_clamps(#[case] t: f64, #[case] expected: f64) {
+        assert!((Easing::Linear.apply(t) - expected).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn areas_at() {
+        let from = [Rect::new(0, 0, 10, 10)];
+        let to = [Rect::new(10, 20, 30, 0)];
+        let transition = Transition::new(from, to, Duration::from_secs(2));
+        assert_eq!(transition.areas_at(Duration::ZERO)[..], from);
+        assert_eq!(
+            transition.areas_at(Duration::from_secs(1))[..],
+            [Rect::new(5, 10, 20, 5)]
+        );
+        assert_eq!(transition.areas_at(Duration::from_secs(2))[..], to);
+        assert_eq!(transition.areas_at(Duration::from_secs(3))[..], to);
+    }
+
+    #[test]
+    fn is_finished() {
+        let transition = Transition::new([], [], Duration::from_millis(100));
+        assert!(!transition.is_finished(Duration::from_millis(99)));
+        assert!(transition.is_finished(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn zero_duration() {
+        let to = [Rect::new(1, 2, 3, 4)];
+        let transition = Transition::new([Rect::ZERO], to, Duration::ZERO);
+        assert_eq!(transition.areas_at(Duration::ZERO)[..], to);
+    }
+
+    #[test]
+    fn mismatched_lengths() {
+        let transition = Transition::new(
+            [Rect::new(0, 0, 4, 4), Rect::new(4, 0, 4, 4)],
+            [Rect::new(0, 0, 2, 4)],
+            Duration::from_secs(1),
+        );
+        assert_eq!(
+            transition.areas_at(Duration::from_millis(500))[..],
+            [Rect::new(0, 0, 3, 4)]
+        );
+
+        let transition = Transition::new(
+            [Rect::new(0, 0, 4, 4)],
+            [Rect::new(0, 0, 2, 4), Rect::new(2, 0, 2, 4)],
+            Duration::from_secs(1),
+        );
+        assert_eq!(
+            transition.areas_at(Duration::from_millis(500))[..],
+            [Rect::new(0, 0, 3, 4), Rect::new(2, 0, 1, 2)]
+        );
+    }
+
+    #[test]
+    fn between_layouts() {
+        let area = Rect::new(0, 0, 40, 1);
+        let from = Layout::horizontal([Constraint::Length(0), Constraint::Fill(1)]);
+        let to = Layout::horizontal([Constraint::Length(10), Constraint::Fill(1)]);
+        let transition =
+            Transition::between(&from, &to, area, Duration::from_secs(1)).easing(Easing::EaseIn);
+        assert_eq!(
+            transition.areas_at(Duration::from_millis(500))[..],
+            [Rect::new(0, 0, 1, 1), Rect::new(1, 0, 39, 1)]
+        );
+        assert_eq!(transition.areas_at(Duration::from_secs(1)), to.split(area));
+    }
+}
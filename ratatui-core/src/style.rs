@@ -72,17 +72,23 @@ use core::fmt;
 
 use bitflags::bitflags;
 pub use color::{Color, ParseColorError};
+pub use gradient::{Gradient, GradientDirection, Interpolation};
+pub use parse::{ParseStyleError, ParseStyleErrorKind};
 use stylize::ColorDebugKind;
 pub use stylize::{Styled, Stylize};
+pub use theme::{Theme, roles};
 
 #[cfg(feature = "anstyle")]
 mod anstyle;
 mod color;
+mod gradient;
 pub mod palette;
 #[cfg(feature = "palette")]
 mod palette_conversion;
+mod parse;
 #[macro_use]
 mod stylize;
+mod theme;
 
 bitflags! {
     /// Modifier changes the way a piece of text is displayed.
@@ -235,7 +241,7 @@ impl fmt::Debug for Modifier {
 /// );
 /// ```
 #[derive(Default, Clone, Copy, Eq, PartialEq, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Style {
     /// The foreground color.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
@@ -248,43 +254,13 @@ pub struct Style {
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub underline_color: Option<Color>,
     /// The modifiers to add.
-    #[cfg_attr(
-        feature = "serde",
-        serde(
-            default,
-            skip_serializing_if = "Modifier::is_empty",
-            deserialize_with = "deserialize_modifier"
-        )
-    )]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Modifier::is_empty"))]
     pub add_modifier: Modifier,
     /// The modifiers to remove.
-    #[cfg_attr(
-        feature = "serde",
-        serde(
-            default,
-            skip_serializing_if = "Modifier::is_empty",
-            deserialize_with = "deserialize_modifier"
-        )
-    )]
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Modifier::is_empty"))]
     pub sub_modifier: Modifier,
 }
 
-#[cfg(feature = "serde")]
-/// Deserialize a [`Modifier`] while treating missing or `null` values as empty.
-///
-/// This helper is used with serde to coerce absent or `null` modifier fields to
-/// [`Modifier::empty`], allowing configuration files to omit these fields
-/// without triggering deserialization errors.
-fn deserialize_modifier<'de, D>(deserializer: D) -> Result<Modifier, D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    use serde::Deserialize;
-
-    Option::<Modifier>::deserialize(deserializer)
-        .map(|modifier| modifier.unwrap_or_else(Modifier::empty))
-}
-
 /// A custom debug implementation that prints only the fields that are not the default, and unwraps
 /// the `Option`s.
 impl fmt::Debug for Style {
@@ -3,6 +3,7 @@
 use core::fmt;
 use core::str::FromStr;
 
+use crate::style::palette::xterm;
 use crate::style::stylize::{ColorDebug, ColorDebugKind};
 
 /// ANSI Color
@@ -480,6 +481,295 @@ impl Color {
     }
 }
 
+/// Color manipulation
+///
+/// These methods work on the RGB values of colors. The RGB values of the named colors and of the
+/// [`Color::Indexed`] colors are taken from the default xterm palette (see [`palette::xterm`]), as
+/// the actual colors that terminals display for them are not known. The results are
+/// [`Color::Rgb`] colors, except for [`Color::Reset`], which is returned unchanged as it has no RGB
+/// value.
+///
+/// [`palette::xterm`]: crate::style::palette::xterm
+impl Color {
+    /// Returns the RGB values of the color, or `None` for [`Color::Reset`].
+    ///
+    /// The named colors and the [`Color::Indexed`] colors are converted using the default xterm
+    /// palette (see [`palette::xterm`](crate::style::palette::xterm)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(1, 2, 3).to_rgb(), Some((1, 2, 3)));
+    /// assert_eq!(Color::Red.to_rgb(), Some((205, 0, 0)));
+    /// assert_eq!(Color::Indexed(244).to_rgb(), Some((128, 128, 128)));
+    /// assert_eq!(Color::Reset.to_rgb(), None);
+    /// ```
+    pub const fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        let index = match self {
+            Self::Reset => return None,
+            Self::Rgb(r, g, b) => return Some((r, g, b)),
+            Self::Indexed(index) => index,
+            Self::Black => 0,
+            Self::Red => 1,
+            Self::Green => 2,
+            Self::Yellow => 3,
+            Self::Blue => 4,
+            Self::Magenta => 5,
+            Self::Cyan => 6,
+            Self::Gray => 7,
+            Self::DarkGray => 8,
+            Self::LightRed => 9,
+            Self::LightGreen => 10,
+            Self::LightYellow => 11,
+            Self::LightBlue => 12,
+            Self::LightMagenta => 13,
+            Self::LightCyan => 14,
+            Self::White => 15,
+        };
+        match xterm::COLORS[index as usize] {
+            Self::Rgb(r, g, b) => Some((r, g, b)),
+            _ => None,
+        }
+    }
+
+    /// Returns a lighter color, increasing the HSL lightness of the color by `amount`.
+    ///
+    /// The `amount` is a fraction of the full lightness range, e.g. `0.1` for 10%, and the
+    /// resulting lightness is clamped to the range [0.0..1.0].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(0, 0, 128).lighten(0.25), Color::Rgb(0, 0, 255));
+    /// assert_eq!(
+    ///     Color::Rgb(128, 128, 128).lighten(1.0),
+    ///     Color::Rgb(255, 255, 255)
+    /// );
+    /// ```
+    #[must_use = "method returns a new color and does not modify the original"]
+    pub fn lighten(self, amount: f32) -> Self {
+        self.map_hsl(|hue, saturation, lightness| (hue, saturation, lightness + amount))
+    }
+
+    /// Returns a darker color, decreasing the HSL lightness of the color by `amount`.
+    ///
+    /// The `amount` is a fraction of the full lightness range, e.g. `0.1` for 10%, and the
+    /// resulting lightness is clamped to the range [0.0..1.0].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(0, 0, 255).darken(0.25), Color::Rgb(0, 0, 128));
+    /// assert_eq!(Color::White.darken(1.0), Color::Rgb(0, 0, 0));
+    /// ```
+    #[must_use = "method returns a new color and does not modify the original"]
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Returns a more saturated color, increasing the HSL saturation of the color by `amount`.
+    ///
+    /// The `amount` is a fraction of the full saturation range, e.g. `0.1` for 10%, and the
+    /// resulting saturation is clamped to the range [0.0..1.0].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(
+    ///     Color::Rgb(96, 128, 160).saturate(0.5),
+    ///     Color::Rgb(33, 128, 224)
+    /// );
+    /// ```
+    #[must_use = "method returns a new color and does not modify the original"]
+    pub fn saturate(self, amount: f32) -> Self {
+        self.map_hsl(|hue, saturation, lightness| (hue, saturation + amount, lightness))
+    }
+
+    /// Returns a less saturated color, decreasing the HSL saturation of the color by `amount`.
+    ///
+    /// The `amount` is a fraction of the full saturation range, e.g. `0.1` for 10%, and the
+    /// resulting saturation is clamped to the range [0.0..1.0]. Desaturating a color by `1.0`
+    /// returns a gray with the same lightness.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(
+    ///     Color::Rgb(32, 128, 224).desaturate(1.0),
+    ///     Color::Rgb(128, 128, 128)
+    /// );
+    /// ```
+    #[must_use = "method returns a new color and does not modify the original"]
+    pub fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// Mixes this color with another color.
+    ///
+    /// The `ratio` is the proportion of `other` in the result and is clamped to the range
+    /// [0.0..1.0]: `0.0` returns this color, `0.5` the average of the colors and `1.0` the other
+    /// color. The RGB components are interpolated linearly.
+    ///
+    /// As [`Color::Reset`] has no RGB value, mixing it with another color returns the color with
+    /// the larger proportion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// let color = Color::Rgb(255, 0, 0).mix(Color::Rgb(0, 0, 255), 0.25);
+    /// assert_eq!(color, Color::Rgb(191, 0, 64));
+    /// assert_eq!(
+    ///     Color::Black.mix(Color::White, 0.5),
+    ///     Color::Rgb(128, 128, 128)
+    /// );
+    /// ```
+    #[must_use = "method returns a new color and does not modify the original"]
+    pub fn mix(self, other: Self, ratio: f32) -> Self {
+        let ratio = ratio.clamp(0.0, 1.0);
+        let (Some((r1, g1, b1)), Some((r2, g2, b2))) = (self.to_rgb(), other.to_rgb()) else {
+            return if ratio < 0.5 { self } else { other };
+        };
+        let mix = |a: u8, b: u8| {
+            to_u8(f32::from(a) / 255.0 * (1.0 - ratio) + f32::from(b) / 255.0 * ratio)
+        };
+        Self::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+    }
+
+    /// Returns the relative luminance of the color, or `None` for [`Color::Reset`].
+    ///
+    /// The relative luminance is the brightness of the color as defined by [WCAG 2], from `0.0`
+    /// for black to `1.0` for white.
+    ///
+    /// [WCAG 2]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(0, 0, 0).luminance(), Some(0.0));
+    /// assert_eq!(Color::Rgb(255, 255, 255).luminance(), Some(1.0));
+    /// assert!(Color::Rgb(255, 255, 0).luminance() > Color::Rgb(0, 0, 255).luminance());
+    /// ```
+    pub fn luminance(self) -> Option<f32> {
+        let (r, g, b) = self.to_rgb()?;
+        let linear = |component: u8| SRGB_TO_LINEAR[usize::from(component)];
+        Some(0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b))
+    }
+
+    /// Returns the contrast ratio between this color and another color, or `None` if either color
+    /// is [`Color::Reset`].
+    ///
+    /// The contrast ratio is defined by [WCAG 2] and ranges from `1.0` for colors with the same
+    /// luminance to `21.0` for black and white. WCAG recommends a contrast ratio of at least `4.5`
+    /// for text, and `3.0` for large text.
+    ///
+    /// [WCAG 2]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// let ratio = Color::Black.contrast_ratio(Color::White).unwrap();
+    /// assert!((ratio - 21.0).abs() < 0.001);
+    /// assert_eq!(Color::Blue.contrast_ratio(Color::Blue), Some(1.0));
+    /// ```
+    pub fn contrast_ratio(self, other: Self) -> Option<f32> {
+        let (a, b) = (self.luminance()?, other.luminance()?);
+        Some((a.max(b) + 0.05) / (a.min(b) + 0.05))
+    }
+
+    /// Returns the most readable foreground color for text on this background color, which is
+    /// black or white, whichever has the higher [contrast ratio](Self::contrast_ratio).
+    ///
+    /// The colors are returned as [`Color::Rgb`] colors, so that they are not affected by the
+    /// terminal theme. [`Color::Reset`] is returned for [`Color::Reset`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Yellow.readable_foreground(), Color::Rgb(0, 0, 0));
+    /// assert_eq!(Color::Blue.readable_foreground(), Color::Rgb(255, 255, 255));
+    /// ```
+    #[must_use = "method returns a new color and does not modify the original"]
+    pub fn readable_foreground(self) -> Self {
+        const BLACK: Color = Color::Rgb(0, 0, 0);
+        const WHITE: Color = Color::Rgb(255, 255, 255);
+        match (self.contrast_ratio(BLACK), self.contrast_ratio(WHITE)) {
+            (Some(black), Some(white)) if black >= white => BLACK,
+            (Some(_), Some(_)) => WHITE,
+            _ => Self::Reset,
+        }
+    }
+
+    /// Converts the color to HSL, applies `f` to the hue, saturation and lightness, and converts
+    /// the result back to RGB.
+    fn map_hsl<F: FnOnce(f32, f32, f32) -> (f32, f32, f32)>(self, f: F) -> Self {
+        let Some((r, g, b)) = self.to_rgb() else {
+            return self;
+        };
+        let (hue, saturation, lightness) = rgb_to_hsl(r, g, b);
+        let (hue, saturation, lightness) = f(hue, saturation, lightness);
+        let (r, g, b) = hsl_to_rgb(hue, saturation.clamp(0.0, 1.0), lightness.clamp(0.0, 1.0));
+        Self::Rgb(r, g, b)
+    }
+}
+
+/// Converts a component in the range [0.0..1.0] to a `u8`, rounding to the nearest value.
+fn to_u8(component: f32) -> u8 {
+    // the value is clamped to the range of u8, so the cast doesn't truncate
+    (component.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+}
+
+/// Converts RGB values to hue (in degrees, in the range [0.0..360.0)), saturation and lightness.
+pub(super) fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let lightness = (f32::from(max) + f32::from(min)) / 510.0;
+    if max == min {
+        return (0.0, 0.0, lightness);
+    }
+    let delta = f32::from(max - min);
+    let saturation = delta / 255.0 / (1.0 - (2.0 * lightness - 1.0).abs());
+    let (fr, fg, fb) = (f32::from(r), f32::from(g), f32::from(b));
+    let hue = if max == r {
+        (fg - fb) / delta + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        (fb - fr) / delta + 2.0
+    } else {
+        (fr - fg) / delta + 4.0
+    };
+    (hue * 60.0, saturation, lightness)
+}
+
+/// Converts hue (in degrees), saturation and lightness to RGB values.
+pub(super) fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> (u8, u8, u8) {
+    let a = saturation * lightness.min(1.0 - lightness);
+    let component = |n: f32| {
+        let mut k = (n + hue / 30.0) % 12.0;
+        if k < 0.0 {
+            k += 12.0;
+        }
+        to_u8(lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0))
+    };
+    (component(0.0), component(8.0), component(4.0))
+}
+
 impl From<[u8; 3]> for Color {
     /// Converts an array of 3 u8 values to a `Color::Rgb` instance.
     fn from([r, g, b]: [u8; 3]) -> Self {
@@ -508,6 +798,266 @@ impl From<(u8, u8, u8, u8)> for Color {
     }
 }
 
+/// The linear values of the sRGB components, used to compute the relative luminance of colors.
+const SRGB_TO_LINEAR: [f32; 256] = [
+    0.0,
+    0.000303527,
+    0.000607054,
+    0.000910581,
+    0.001214108,
+    0.001517635,
+    0.001821162,
+    0.002124689,
+    0.002428216,
+    0.002731743,
+    0.00303527,
+    0.003346536,
+    0.003676507,
+    0.004024717,
+    0.004391442,
+    0.004776953,
+    0.005181517,
+    0.005605392,
+    0.006048833,
+    0.006512091,
+    0.00699541,
+    0.007499032,
+    0.008023193,
+    0.008568126,
+    0.009134059,
+    0.009721217,
+    0.01032982,
+    0.01096009,
+    0.01161225,
+    0.01228649,
+    0.01298303,
+    0.01370208,
+    0.01444384,
+    0.01520851,
+    0.01599629,
+    0.01680738,
+    0.01764195,
+    0.01850022,
+    0.01938236,
+    0.02028856,
+    0.02121901,
+    0.02217388,
+    0.02315337,
+    0.02415763,
+    0.02518686,
+    0.02624122,
+    0.02732089,
+    0.02842604,
+    0.02955683,
+    0.03071344,
+    0.03189603,
+    0.03310477,
+    0.03433981,
+    0.03560131,
+    0.03688945,
+    0.03820437,
+    0.03954624,
+    0.0409152,
+    0.04231141,
+    0.04373503,
+    0.0451862,
+    0.04666509,
+    0.04817182,
+    0.04970657,
+    0.05126946,
+    0.05286065,
+    0.05448028,
+    0.05612849,
+    0.05780543,
+    0.05951124,
+    0.06124605,
+    0.06301002,
+    0.06480327,
+    0.06662594,
+    0.06847817,
+    0.0703601,
+    0.07227185,
+    0.07421357,
+    0.07618538,
+    0.07818742,
+    0.08021982,
+    0.08228271,
+    0.08437621,
+    0.08650046,
+    0.08865559,
+    0.09084171,
+    0.09305896,
+    0.09530747,
+    0.09758735,
+    0.09989873,
+    0.1022417,
+    0.1046165,
+    0.1070231,
+    0.1094617,
+    0.1119324,
+    0.1144354,
+    0.1169707,
+    0.1195384,
+    0.1221388,
+    0.1247718,
+    0.1274377,
+    0.1301365,
+    0.1328683,
+    0.1356333,
+    0.1384316,
+    0.1412633,
+    0.1441285,
+    0.1470273,
+    0.1499598,
+    0.1529262,
+    0.1559265,
+    0.1589608,
+    0.1620294,
+    0.1651322,
+    0.1682694,
+    0.1714411,
+    0.1746474,
+    0.1778884,
+    0.1811642,
+    0.184475,
+    0.1878208,
+    0.1912017,
+    0.1946178,
+    0.1980693,
+    0.2015563,
+    0.2050787,
+    0.2086369,
+    0.2122308,
+    0.2158605,
+    0.2195262,
+    0.223228,
+    0.2269659,
+    0.23074,
+    0.2345506,
+    0.2383976,
+    0.2422811,
+    0.2462013,
+    0.2501583,
+    0.2541521,
+    0.2581829,
+    0.2622507,
+    0.2663556,
+    0.2704978,
+    0.2746773,
+    0.2788943,
+    0.2831487,
+    0.2874408,
+    0.2917706,
+    0.2961383,
+    0.3005438,
+    0.3049873,
+    0.3094689,
+    0.3139887,
+    0.3185468,
+    0.3231432,
+    0.3277781,
+    0.3324515,
+    0.3371636,
+    0.3419144,
+    0.3467041,
+    0.3515326,
+    0.3564001,
+    0.3613068,
+    0.3662526,
+    0.3712377,
+    0.3762621,
+    0.381326,
+    0.3864294,
+    0.3915725,
+    0.3967552,
+    0.4019778,
+    0.4072402,
+    0.4125426,
+    0.4178851,
+    0.4232677,
+    0.4286905,
+    0.4341536,
+    0.4396572,
+    0.4452012,
+    0.4507858,
+    0.456411,
+    0.462077,
+    0.4677838,
+    0.4735315,
+    0.4793202,
+    0.4851499,
+    0.4910208,
+    0.496933,
+    0.5028865,
+    0.5088813,
+    0.5149177,
+    0.5209956,
+    0.5271151,
+    0.5332764,
+    0.5394795,
+    0.5457245,
+    0.5520114,
+    0.5583404,
+    0.5647115,
+    0.5711248,
+    0.5775804,
+    0.5840784,
+    0.5906188,
+    0.5972018,
+    0.6038273,
+    0.6104956,
+    0.6172066,
+    0.6239604,
+    0.6307571,
+    0.6375969,
+    0.6444797,
+    0.6514056,
+    0.6583748,
+    0.6653873,
+    0.6724432,
+    0.6795425,
+    0.6866853,
+    0.6938718,
+    0.7011019,
+    0.7083758,
+    0.7156935,
+    0.7230551,
+    0.7304607,
+    0.7379104,
+    0.7454042,
+    0.7529422,
+    0.7605245,
+    0.7681511,
+    0.7758222,
+    0.7835378,
+    0.7912979,
+    0.7991027,
+    0.8069523,
+    0.8148466,
+    0.8227858,
+    0.8307699,
+    0.838799,
+    0.8468732,
+    0.8549926,
+    0.8631572,
+    0.8713671,
+    0.8796224,
+    0.8879231,
+    0.8962694,
+    0.9046612,
+    0.9130987,
+    0.9215819,
+    0.9301109,
+    0.9386857,
+    0.9473065,
+    0.9559734,
+    0.9646862,
+    0.9734453,
+    0.9822506,
+    0.9911021,
+    1.0,
+];
+
 #[cfg(test)]
 mod tests {
     use alloc::boxed::Box;
@@ -516,7 +1066,6 @@ mod tests {
 
     #[cfg(feature = "palette")]
     use palette::{Hsl, Hsluv};
-    #[cfg(feature = "palette")]
     use rstest::rstest;
     #[cfg(feature = "serde")]
     use serde::de::{Deserialize, IntoDeserializer};
@@ -785,4 +1334,96 @@ mod tests {
         let from_tuple4 = Color::from((200, 150, 100, 0));
         assert_eq!(from_tuple4, Color::Rgb(200, 150, 100));
     }
+
+    #[rstest]
+    #[case::reset(Color::Reset, None)]
+    #[case::named(Color::Blue, Some((0, 0, 238)))]
+    #[case::bright(Color::LightBlue, Some((92, 92, 255)))]
+    #[case::cube(Color::Indexed(67), Some((95, 135, 175)))]
+    #[case::rgb(Color::Rgb(1, 2, 3), Some((1, 2, 3)))]
+    fn to_rgb(#[case] color: Color, #[case] expected: Option<(u8, u8, u8)>) {
+        assert_eq!(color.to_rgb(), expected);
+    }
+
+    #[rstest]
+    #[case::red((255, 0, 0))]
+    #[case::orange((255, 128, 0))]
+    #[case::dark_green((0, 100, 0))]
+    #[case::purple((128, 0, 255))]
+    #[case::pink((255, 0, 128))]
+    #[case::gray((120, 120, 120))]
+    #[case::muted((96, 128, 160))]
+    fn hsl_round_trip(#[case] (r, g, b): (u8, u8, u8)) {
+        let (hue, saturation, lightness) = rgb_to_hsl(r, g, b);
+        assert_eq!(hsl_to_rgb(hue, saturation, lightness), (r, g, b));
+    }
+
+    #[rstest]
+    #[case::lighten(Color::Rgb(255, 0, 0).lighten(0.25), Color::Rgb(255, 128, 128))]
+    #[case::lighten_named(Color::Red.lighten(0.1), Color::Rgb(255, 1, 1))]
+    #[case::lighten_clamped(Color::Red.lighten(2.0), Color::Rgb(255, 255, 255))]
+    #[case::darken(Color::Rgb(255, 128, 128).darken(0.25), Color::Rgb(255, 1, 1))]
+    #[case::darken_clamped(Color::Red.darken(2.0), Color::Rgb(0, 0, 0))]
+    #[case::saturate(Color::Rgb(96, 128, 160).saturate(0.25), Color::Rgb(64, 128, 192))]
+    #[case::desaturate(Color::Rgb(255, 0, 0).desaturate(0.5), Color::Rgb(191, 64, 64))]
+    #[case::reset(Color::Reset.lighten(0.5), Color::Reset)]
+    fn lighten_darken_saturate(#[case] color: Color, #[case] expected: Color) {
+        assert_eq!(color, expected);
+    }
+
+    #[rstest]
+    #[case::start(Color::Red, Color::Blue, 0.0, Color::Rgb(205, 0, 0))]
+    #[case::end(Color::Red, Color::Blue, 1.0, Color::Rgb(0, 0, 238))]
+    #[case::clamped(Color::Red, Color::Blue, 2.0, Color::Rgb(0, 0, 238))]
+    #[case::middle(
+        Color::Rgb(0, 100, 200),
+        Color::Rgb(100, 200, 0),
+        0.5,
+        Color::Rgb(50, 150, 100)
+    )]
+    #[case::reset_start(Color::Reset, Color::Blue, 0.25, Color::Reset)]
+    #[case::reset_end(Color::Reset, Color::Blue, 0.75, Color::Blue)]
+    fn mix(
+        #[case] color: Color,
+        #[case] other: Color,
+        #[case] ratio: f32,
+        #[case] expected: Color,
+    ) {
+        assert_eq!(color.mix(other, ratio), expected);
+    }
+
+    #[test]
+    fn luminance() {
+        assert_eq!(Color::Reset.luminance(), None);
+        assert_eq!(Color::Indexed(16).luminance(), Some(0.0));
+        // values from the WCAG definition
+        let luminance = Color::Rgb(128, 128, 128).luminance().unwrap();
+        assert!((luminance - 0.2158605).abs() < 1e-6, "{luminance}");
+        let luminance = Color::Rgb(255, 0, 0).luminance().unwrap();
+        assert!((luminance - 0.2126).abs() < 1e-6, "{luminance}");
+    }
+
+    #[test]
+    fn contrast_ratio() {
+        assert_eq!(Color::Reset.contrast_ratio(Color::White), None);
+        assert_eq!(Color::White.contrast_ratio(Color::Reset), None);
+        let ratio = Color::Rgb(255, 0, 0).contrast_ratio(Color::Rgb(255, 255, 255));
+        assert!((ratio.unwrap() - 3.998).abs() < 0.001, "{ratio:?}");
+        assert_eq!(
+            Color::Rgb(255, 0, 0).contrast_ratio(Color::Rgb(0, 0, 0)),
+            Color::Rgb(0, 0, 0).contrast_ratio(Color::Rgb(255, 0, 0)),
+        );
+    }
+
+    #[rstest]
+    #[case::black(Color::Black, Color::Rgb(255, 255, 255))]
+    #[case::white(Color::White, Color::Rgb(0, 0, 0))]
+    #[case::blue(Color::Rgb(0, 0, 255), Color::Rgb(255, 255, 255))]
+    #[case::yellow(Color::Rgb(255, 255, 0), Color::Rgb(0, 0, 0))]
+    #[case::mid_gray(Color::Indexed(244), Color::Rgb(0, 0, 0))]
+    #[case::dark_gray(Color::Indexed(238), Color::Rgb(255, 255, 255))]
+    #[case::reset(Color::Reset, Color::Reset)]
+    fn readable_foreground(#[case] background: Color, #[case] expected: Color) {
+        assert_eq!(background.readable_foreground(), expected);
+    }
 }
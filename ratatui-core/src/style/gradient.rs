@@ -0,0 +1,558 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::buffer::Buffer;
+use crate::layout::{Position, Rect};
+use crate::style::color::{hsl_to_rgb, rgb_to_hsl};
+use crate::style::{Color, Style};
+use crate::text::{Line, Span};
+
+/// A color gradient, made of colors at positions (stops) between `0.0` and `1.0`.
+///
+/// The colors between the stops are interpolated in the [color space](Interpolation) of the
+/// gradient. Positions before the first stop have the color of the first stop, and positions after
+/// the last stop have the color of the last stop.
+///
+/// A gradient can be used to:
+///
+/// - color each grapheme of a [`Line`] with [`Line::fg_gradient`] and [`Line::bg_gradient`], e.g.
+///   for gradient titles
+/// - fill the background of an area of a [`Buffer`] with [`Gradient::fill_bg`], following the
+///   [direction](GradientDirection) of the gradient
+/// - color the bars of the `Gauge` and `LineGauge` widgets and the borders of the `Block` widget in
+///   `ratatui-widgets`
+///
+/// Colors are interpolated using their RGB values. The named colors and the [`Color::Indexed`]
+/// colors use the RGB values of the default xterm palette (see [`Color::to_rgb`]).
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_core::style::{Color, Gradient, Interpolation};
+///
+/// let gradient = Gradient::new([Color::Rgb(255, 0, 0), Color::Rgb(0, 0, 255)]);
+/// assert_eq!(gradient.color_at(0.0), Color::Rgb(255, 0, 0));
+/// assert_eq!(gradient.color_at(0.5), Color::Rgb(128, 0, 128));
+/// assert_eq!(gradient.color_at(1.0), Color::Rgb(0, 0, 255));
+///
+/// // interpolating the hue goes through the colors between red and blue
+/// let gradient = gradient.interpolation(Interpolation::Hsl);
+/// assert_eq!(gradient.color_at(0.5), Color::Rgb(255, 0, 255));
+///
+/// // stops can be placed at any position
+/// let gradient = Gradient::from_stops([(0.0, Color::Black), (0.8, Color::White)]);
+/// assert_eq!(gradient.color_at(0.4), Color::Rgb(128, 128, 128));
+/// assert_eq!(gradient.color_at(0.9), Color::White);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Gradient {
+    /// The stops, sorted by position
+    stops: Vec<(f32, Color)>,
+    interpolation: Interpolation,
+    direction: GradientDirection,
+}
+
+/// The color space in which the colors of a [`Gradient`] are interpolated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// Interpolates the red, green and blue components of the colors.
+    #[default]
+    Rgb,
+    /// Interpolates the hue, saturation and lightness of the colors.
+    ///
+    /// The hue is interpolated along the shortest path around the color wheel, so the colors in
+    /// between are more saturated than with [`Interpolation::Rgb`].
+    Hsl,
+}
+
+/// The direction in which a [`Gradient`] goes when it fills an area.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GradientDirection {
+    /// From the left edge to the right edge of the area.
+    #[default]
+    Horizontal,
+    /// From the top edge to the bottom edge of the area.
+    Vertical,
+    /// From the top left corner to the bottom right corner of the area.
+    Diagonal,
+}
+
+impl Gradient {
+    /// Creates a gradient from colors that are evenly spaced between `0.0` and `1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::{Color, Gradient};
+    ///
+    /// let gradient = Gradient::new([Color::Red, Color::Yellow, Color::Green]);
+    /// assert_eq!(gradient.color_at(0.0), Color::Red);
+    /// assert_eq!(gradient.color_at(0.25), Color::Rgb(205, 103, 0));
+    /// assert_eq!(gradient.color_at(0.5), Color::Yellow);
+    /// ```
+    pub fn new<I, C>(colors: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Color>,
+    {
+        let colors: Vec<Color> = colors.into_iter().map(Into::into).collect();
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        let stops = colors
+            .into_iter()
+            .enumerate()
+            .map(|(index, color)| (index as f32 / last, color))
+            .collect();
+        Self {
+            stops,
+            ..Self::default()
+        }
+    }
+
+    /// Creates a gradient from colors at positions between `0.0` and `1.0`.
+    ///
+    /// Positions outside this range are clamped, and the stops don't need to be sorted by
+    /// position. Stops at the same position create a hard transition between two colors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::{Color, Gradient};
+    ///
+    /// // a gradient from black to white, with a red band in the middle
+    /// let gradient = Gradient::from_stops([
+    ///     (0.0, Color::Black),
+    ///     (0.4, Color::Red),
+    ///     (0.6, Color::Red),
+    ///     (1.0, Color::White),
+    /// ]);
+    /// assert_eq!(gradient.color_at(0.5), Color::Red);
+    /// ```
+    pub fn from_stops<I, C>(stops: I) -> Self
+    where
+        I: IntoIterator<Item = (f32, C)>,
+        C: Into<Color>,
+    {
+        let mut stops: Vec<(f32, Color)> = stops
+            .into_iter()
+            .map(|(position, color)| (normalize(position), color.into()))
+            .collect();
+        stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        Self {
+            stops,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the color space in which the colors are interpolated.
+    ///
+    /// The default is [`Interpolation::Rgb`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Sets the direction of the gradient when it fills an area.
+    ///
+    /// The default is [`GradientDirection::Horizontal`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: GradientDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Returns the stops of the gradient, sorted by position.
+    pub fn stops(&self) -> &[(f32, Color)] {
+        &self.stops
+    }
+
+    /// Returns the color at a position between `0.0` and `1.0`.
+    ///
+    /// Positions outside this range are clamped. An empty gradient returns [`Color::Reset`].
+    pub fn color_at(&self, position: f32) -> Color {
+        let position = normalize(position);
+        let Some(&(first_position, first)) = self.stops.first() else {
+            return Color::Reset;
+        };
+        if position <= first_position {
+            return first;
+        }
+        let mut previous = (first_position, first);
+        for &(stop_position, color) in &self.stops[1..] {
+            if position <= stop_position {
+                let (previous_position, previous_color) = previous;
+                let range = stop_position - previous_position;
+                let ratio = if range > 0.0 {
+                    (position - previous_position) / range
+                } else {
+                    1.0
+                };
+                return self.interpolate(previous_color, color, ratio);
+            }
+            previous = (stop_position, color);
+        }
+        previous.1
+    }
+
+    /// Returns an iterator over `count` colors evenly spaced along the gradient, from the color at
+    /// `0.0` to the color at `1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::{Color, Gradient};
+    ///
+    /// let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0)]);
+    /// let colors: Vec<Color> = gradient.colors(3).collect();
+    /// assert_eq!(
+    ///     colors,
+    ///     [
+    ///         Color::Rgb(0, 0, 0),
+    ///         Color::Rgb(100, 0, 0),
+    ///         Color::Rgb(200, 0, 0)
+    ///     ]
+    /// );
+    /// ```
+    pub fn colors(&self, count: usize) -> impl Iterator<Item = Color> + '_ {
+        let last = count.saturating_sub(1).max(1) as f32;
+        (0..count).map(move |index| self.color_at(index as f32 / last))
+    }
+
+    /// Returns the color of a cell in an area, following the direction of the gradient.
+    ///
+    /// For a horizontal gradient, the cells of the left column have the color at `0.0` and the
+    /// cells of the right column the color at `1.0`. The position is clamped to the area.
+    pub fn color_in(&self, area: Rect, position: Position) -> Color {
+        let ratio = |offset: u16, length: u16| {
+            f32::from(offset.min(length.saturating_sub(1)))
+                / f32::from(length.saturating_sub(1).max(1))
+        };
+        let x = position.x.saturating_sub(area.x);
+        let y = position.y.saturating_sub(area.y);
+        let position = match self.direction {
+            GradientDirection::Horizontal => ratio(x, area.width),
+            GradientDirection::Vertical => ratio(y, area.height),
+            GradientDirection::Diagonal => {
+                // computed in floating point, as the sums can overflow u16 for large areas
+                let width = area.width.saturating_sub(1);
+                let height = area.height.saturating_sub(1);
+                let offset = f32::from(x.min(width)) + f32::from(y.min(height));
+                offset / (f32::from(width) + f32::from(height)).max(1.0)
+            }
+        };
+        self.color_at(position)
+    }
+
+    /// Sets the background color of the cells of an area of a buffer to the colors of the
+    /// gradient, following the direction of the gradient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::layout::Rect;
+    /// use ratatui_core::style::{Color, Gradient, GradientDirection};
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+    /// let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(0, 0, 200)])
+    ///     .direction(GradientDirection::Vertical);
+    /// gradient.fill_bg(buffer.area, &mut buffer);
+    /// assert_eq!(buffer[(2, 0)].bg, Color::Rgb(0, 0, 0));
+    /// assert_eq!(buffer[(2, 1)].bg, Color::Rgb(0, 0, 100));
+    /// assert_eq!(buffer[(2, 2)].bg, Color::Rgb(0, 0, 200));
+    /// ```
+    pub fn fill_bg(&self, area: Rect, buf: &mut Buffer) {
+        for position in area.intersection(buf.area).positions() {
+            buf[position].set_bg(self.color_in(area, position));
+        }
+    }
+
+    fn interpolate(&self, from: Color, to: Color, ratio: f32) -> Color {
+        // the colors of the stops are returned unchanged
+        if ratio >= 1.0 || from == to {
+            return to;
+        }
+        let (Interpolation::Hsl, Some(from_rgb), Some(to_rgb)) =
+            (self.interpolation, from.to_rgb(), to.to_rgb())
+        else {
+            return from.mix(to, ratio);
+        };
+        let (mut from_hue, from_saturation, from_lightness) =
+            rgb_to_hsl(from_rgb.0, from_rgb.1, from_rgb.2);
+        let (mut to_hue, to_saturation, to_lightness) = rgb_to_hsl(to_rgb.0, to_rgb.1, to_rgb.2);
+        // grays have no hue, so they take the hue of the other color
+        if from_saturation == 0.0 {
+            from_hue = to_hue;
+        }
+        if to_saturation == 0.0 {
+            to_hue = from_hue;
+        }
+        // the difference between the hues along the shortest path, in the range [-180.0..180.0)
+        let hue_delta = (to_hue - from_hue + 540.0) % 360.0 - 180.0;
+        let lerp = |from: f32, to: f32| from + (to - from) * ratio;
+        let (r, g, b) = hsl_to_rgb(
+            from_hue + hue_delta * ratio,
+            lerp(from_saturation, to_saturation),
+            lerp(from_lightness, to_lightness),
+        );
+        Color::Rgb(r, g, b)
+    }
+
+    /// Returns the line with each grapheme in its own span, styled by `f` with the colors of the
+    /// gradient.
+    fn apply_to_line<'a>(&self, line: &Line<'a>, f: fn(Style, Color) -> Style) -> Line<'a> {
+        let count = line
+            .spans
+            .iter()
+            .map(|span| span.content.graphemes(true).count())
+            .sum();
+        let mut colors = self.colors(count);
+        let spans = line
+            .spans
+            .iter()
+            .flat_map(|span| {
+                span.content
+                    .graphemes(true)
+                    .map(move |grapheme| (grapheme, span.style))
+            })
+            .zip(&mut colors)
+            .map(|((grapheme, style), color)| Span::styled(grapheme.to_string(), f(style, color)))
+            .collect();
+        Line {
+            spans,
+            style: line.style,
+            alignment: line.alignment,
+        }
+    }
+}
+
+impl Line<'_> {
+    /// Sets the foreground color of each grapheme of the line to the colors of a gradient, from the
+    /// color at `0.0` for the first grapheme to the color at `1.0` for the last grapheme.
+    ///
+    /// Each grapheme is put in its own span, which keeps the style of the span it was in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::{Color, Gradient, Stylize};
+    /// use ratatui_core::text::Line;
+    ///
+    /// let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0)]);
+    /// let line = Line::from(vec!["a".bold(), "bc".into()]).fg_gradient(&gradient);
+    /// assert_eq!(
+    ///     line,
+    ///     Line::from(vec![
+    ///         "a".bold().fg(Color::Rgb(0, 0, 0)),
+    ///         "b".fg(Color::Rgb(100, 0, 0)),
+    ///         "c".fg(Color::Rgb(200, 0, 0)),
+    ///     ])
+    /// );
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn fg_gradient(self, gradient: &Gradient) -> Self {
+        gradient.apply_to_line(&self, Style::fg)
+    }
+
+    /// Sets the background color of each grapheme of the line to the colors of a gradient, from
+    /// the color at `0.0` for the first grapheme to the color at `1.0` for the last grapheme.
+    ///
+    /// Each grapheme is put in its own span, which keeps the style of the span it was in.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bg_gradient(self, gradient: &Gradient) -> Self {
+        gradient.apply_to_line(&self, Style::bg)
+    }
+}
+
+/// Clamps a position to the range [0.0..1.0], replacing NaN with `0.0`.
+fn normalize(position: f32) -> f32 {
+    if position.is_nan() {
+        0.0
+    } else {
+        // adding 0.0 turns -0.0 into 0.0, so that equal positions have equal bits
+        position.clamp(0.0, 1.0) + 0.0
+    }
+}
+
+impl PartialEq for Gradient {
+    fn eq(&self, other: &Self) -> bool {
+        self.interpolation == other.interpolation
+            && self.direction == other.direction
+            && self.stops.len() == other.stops.len()
+            && self
+                .stops
+                .iter()
+                .zip(&other.stops)
+                .all(|((a, a_color), (b, c_color))| {
+                    a.to_bits() == b.to_bits() && a_color == c_color
+                })
+    }
+}
+
+// The positions are never NaN, so the gradients can be compared and hashed by the bits of the
+// positions.
+impl Eq for Gradient {}
+
+impl Hash for Gradient {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.interpolation.hash(state);
+        self.direction.hash(state);
+        for (position, color) in &self.stops {
+            position.to_bits().hash(state);
+            color.hash(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Stylize;
+
+    const BLACK: Color = Color::Rgb(0, 0, 0);
+    const RED: Color = Color::Rgb(200, 0, 0);
+
+    #[rstest]
+    #[case::start(0.0, Color::Rgb(0, 0, 0))]
+    #[case::before_start(-1.0, Color::Rgb(0, 0, 0))]
+    #[case::nan(f32::NAN, Color::Rgb(0, 0, 0))]
+    #[case::first_segment(0.25, Color::Rgb(100, 0, 0))]
+    #[case::middle_stop(0.5, Color::Rgb(200, 0, 0))]
+    #[case::second_segment(0.75, Color::Rgb(100, 100, 0))]
+    #[case::end(1.0, Color::Rgb(0, 200, 0))]
+    #[case::after_end(2.0, Color::Rgb(0, 200, 0))]
+    fn color_at(#[case] position: f32, #[case] expected: Color) {
+        let gradient = Gradient::new([BLACK, RED, Color::Rgb(0, 200, 0)]);
+        assert_eq!(gradient.color_at(position), expected);
+    }
+
+    #[test]
+    fn empty_and_single_color() {
+        assert_eq!(Gradient::default().color_at(0.5), Color::Reset);
+        assert_eq!(Gradient::new([RED]).color_at(0.0), RED);
+        assert_eq!(Gradient::new([RED]).color_at(1.0), RED);
+    }
+
+    #[test]
+    fn from_stops_sorts_and_clamps() {
+        let gradient = Gradient::from_stops([(1.5, RED), (-0.0, BLACK), (0.5, Color::Blue)]);
+        assert_eq!(
+            gradient.stops(),
+            [(0.0, BLACK), (0.5, Color::Blue), (1.0, RED)]
+        );
+        assert_eq!(gradient, Gradient::new([BLACK, Color::Blue, RED]));
+    }
+
+    #[test]
+    fn hard_stop() {
+        let gradient = Gradient::from_stops([(0.0, BLACK), (0.5, BLACK), (0.5, RED), (1.0, RED)]);
+        assert_eq!(gradient.color_at(0.49), BLACK);
+        assert_eq!(gradient.color_at(0.51), RED);
+    }
+
+    #[rstest]
+    #[case::red_to_blue(Color::Rgb(255, 0, 0), Color::Rgb(0, 0, 255), Color::Rgb(255, 0, 255))]
+    #[case::blue_to_red(Color::Rgb(0, 0, 255), Color::Rgb(255, 0, 0), Color::Rgb(255, 0, 255))]
+    #[case::red_to_green(Color::Rgb(255, 0, 0), Color::Rgb(0, 255, 0), Color::Rgb(255, 255, 0))]
+    #[case::gray_keeps_hue(
+        Color::Rgb(255, 0, 0),
+        Color::Rgb(255, 255, 255),
+        Color::Rgb(223, 159, 159)
+    )]
+    #[case::reset(Color::Reset, Color::Rgb(0, 0, 255), Color::Rgb(0, 0, 255))]
+    fn hsl_interpolation(#[case] from: Color, #[case] to: Color, #[case] expected: Color) {
+        let gradient = Gradient::new([from, to]).interpolation(Interpolation::Hsl);
+        assert_eq!(gradient.color_at(0.5), expected);
+    }
+
+    #[test]
+    fn colors() {
+        let gradient = Gradient::new([BLACK, RED]);
+        assert_eq!(gradient.colors(0).count(), 0);
+        assert_eq!(gradient.colors(1).collect::<Vec<_>>(), [BLACK]);
+        assert_eq!(
+            gradient.colors(5).collect::<Vec<_>>(),
+            [
+                BLACK,
+                Color::Rgb(50, 0, 0),
+                Color::Rgb(100, 0, 0),
+                Color::Rgb(150, 0, 0),
+                RED
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::horizontal(GradientDirection::Horizontal, [[0, 100, 200], [0, 100, 200]])]
+    #[case::vertical(GradientDirection::Vertical, [[0, 0, 0], [200, 200, 200]])]
+    #[case::diagonal(GradientDirection::Diagonal, [[0, 67, 133], [67, 133, 200]])]
+    fn fill_bg(#[case] direction: GradientDirection, #[case] expected: [[u8; 3]; 2]) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        let area = Rect::new(1, 1, 3, 2);
+        Gradient::new([BLACK, RED])
+            .direction(direction)
+            .fill_bg(area, &mut buffer);
+        let mut expected_buffer = Buffer::empty(buffer.area);
+        for (y, row) in expected.iter().enumerate() {
+            for (x, red) in row.iter().enumerate() {
+                expected_buffer[(x as u16 + 1, y as u16 + 1)].set_bg(Color::Rgb(*red, 0, 0));
+            }
+        }
+        assert_eq!(buffer, expected_buffer);
+    }
+
+    #[rstest]
+    #[case::horizontal(GradientDirection::Horizontal)]
+    #[case::vertical(GradientDirection::Vertical)]
+    #[case::diagonal(GradientDirection::Diagonal)]
+    fn color_in_edge_areas(#[case] direction: GradientDirection) {
+        let gradient = Gradient::new([BLACK, RED]).direction(direction);
+        assert_eq!(gradient.color_in(Rect::ZERO, Position::ORIGIN), BLACK);
+        assert_eq!(
+            gradient.color_in(Rect::new(5, 5, 0, 3), Position::new(5, 5)),
+            BLACK
+        );
+        let area = Rect::new(0, 0, u16::MAX, u16::MAX);
+        assert_eq!(gradient.color_in(area, Position::ORIGIN), BLACK);
+        assert_eq!(
+            gradient.color_in(area, Position::new(u16::MAX, u16::MAX)),
+            RED
+        );
+    }
+
+    #[test]
+    fn fill_bg_outside_buffer() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        Gradient::new([BLACK, RED]).fill_bg(Rect::new(1, 0, 3, 1), &mut buffer);
+        assert_eq!(buffer[(0, 0)].bg, Color::Reset);
+        assert_eq!(buffer[(1, 0)].bg, BLACK);
+    }
+
+    #[test]
+    fn line_gradient() {
+        let gradient = Gradient::new([BLACK, RED]);
+        let line = Line::from(vec!["a".bold(), "👍c".into()])
+            .centered()
+            .fg_gradient(&gradient);
+        assert_eq!(
+            line,
+            Line::from(vec![
+                "a".bold().fg(BLACK),
+                "👍".fg(Color::Rgb(100, 0, 0)),
+                "c".fg(RED),
+            ])
+            .centered()
+        );
+        let line = Line::from("ab").bg_gradient(&gradient);
+        assert_eq!(line, Line::from(vec!["a".bg(BLACK), "b".bg(RED)]));
+        assert_eq!(Line::default().fg_gradient(&gradient), Line::default());
+    }
+}
@@ -2,5 +2,13 @@
 
 //! A module for defining color palettes.
 
+#[cfg(feature = "base16")]
+pub mod base16;
+pub mod catppuccin;
+pub mod dracula;
+pub mod gruvbox;
 pub mod material;
+pub mod nord;
+pub mod solarized;
 pub mod tailwind;
+pub mod xterm;
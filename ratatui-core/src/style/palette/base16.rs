@@ -0,0 +1,794 @@
+//! Loading of [base16] and [base24] color schemes.
+//!
+//! A base16 scheme is a palette of 16 colors, named `base00` to `base0F`, with a fixed meaning:
+//! `base00` to `base07` are shades from the background to the foreground (for dark schemes), and
+//! `base08` to `base0F` are accent colors. Base24 schemes add 8 more colors, `base10` to `base17`,
+//! for darker backgrounds and bright accents. Hundreds of schemes are available in the
+//! [tinted-theming schemes repository], as YAML files that can be loaded with
+//! [`Scheme::from_yaml`].
+//!
+//! Both the original format, where the colors are top-level keys next to `scheme` and `author`,
+//! and the newer tinted-theming format, where the colors are in a `palette` map next to `system`,
+//! `name`, `author` and `variant`, are supported. Colors are hex strings with or without a leading
+//! `#`. Scheme files are parsed with a small parser for the subset of YAML that they use: `key:
+//! value` pairs with plain or quoted string values, the nested `palette` map and comments.
+//!
+//! [base16]: https://github.com/tinted-theming/home/blob/main/styling.md
+//! [base24]: https://github.com/tinted-theming/base24/blob/main/styling.md
+//! [tinted-theming schemes repository]: https://github.com/tinted-theming/schemes
+//!
+//! # Examples
+//!
+//! ```
+//! use ratatui_core::style::Color;
+//! use ratatui_core::style::palette::base16::Scheme;
+//!
+//! let scheme = Scheme::from_yaml(
+//!     r##"
+//! system: "base16"
+//! name: "Example"
+//! author: "Ratatui"
+//! variant: "dark"
+//! palette:
+//!   base00: "#181818"
+//!   base01: "#282828"
+//!   base02: "#383838"
+//!   base03: "#585858"
+//!   base04: "#b8b8b8"
+//!   base05: "#d8d8d8"
+//!   base06: "#e8e8e8"
+//!   base07: "#f8f8f8"
+//!   base08: "#ab4642"
+//!   base09: "#dc9656"
+//!   base0A: "#f7ca88"
+//!   base0B: "#a1b56c"
+//!   base0C: "#86c1b9"
+//!   base0D: "#7cafc2"
+//!   base0E: "#ba8baf"
+//!   base0F: "#a16946"
+//! "##,
+//! )?;
+//!
+//! assert_eq!(scheme.name, "Example");
+//! assert_eq!(scheme.background(), Color::Rgb(0x18, 0x18, 0x18));
+//! assert_eq!(scheme.red(), Color::Rgb(0xab, 0x46, 0x42));
+//! // base16 schemes fall back to the base16 colors for the base24 colors
+//! assert_eq!(scheme.bright_red(), scheme.red());
+//! # Ok::<(), ratatui_core::style::palette::base16::LoadSchemeError>(())
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+
+use crate::style::Color;
+
+/// The names of the base16 colors, in order.
+const BASE16_NAMES: [&str; 16] = [
+    "base00", "base01", "base02", "base03", "base04", "base05", "base06", "base07", "base08",
+    "base09", "base0A", "base0B", "base0C", "base0D", "base0E", "base0F",
+];
+
+/// The names of the colors that base24 adds to base16, in order.
+const BASE24_NAMES: [&str; 8] = [
+    "base10", "base11", "base12", "base13", "base14", "base15", "base16", "base17",
+];
+
+/// A base16 or base24 color scheme.
+///
+/// The colors are available by their base16 name (e.g. [`Scheme::base08`]) and by their meaning in
+/// the base16 and base24 styling guidelines (e.g. [`Scheme::red`]). Base16 schemes have no
+/// [`base24`](Scheme::base24) colors, in which case the base24 accessors like
+/// [`Scheme::bright_red`] fall back to the base16 colors recommended by the base24 guidelines.
+///
+/// Schemes are usually loaded from YAML files with [`Scheme::from_yaml`], but can also be created
+/// from colors with [`Scheme::new`], which accepts anything that converts into a [`Color`], such
+/// as the colors of the [`palette`] crate when the `palette` feature is enabled.
+///
+/// [`palette`]: https://crates.io/crates/palette
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Scheme {
+    pub name: String,
+    pub author: String,
+    /// The variant of the scheme, usually `dark` or `light`.
+    pub variant: Option<String>,
+    pub base00: Color,
+    pub base01: Color,
+    pub base02: Color,
+    pub base03: Color,
+    pub base04: Color,
+    pub base05: Color,
+    pub base06: Color,
+    pub base07: Color,
+    pub base08: Color,
+    pub base09: Color,
+    pub base0a: Color,
+    pub base0b: Color,
+    pub base0c: Color,
+    pub base0d: Color,
+    pub base0e: Color,
+    pub base0f: Color,
+    /// The colors that base24 schemes add to base16, or `None` for base16 schemes.
+    pub base24: Option<Base24>,
+}
+
+/// The colors that a base24 [`Scheme`] adds to base16.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Base24 {
+    pub base10: Color,
+    pub base11: Color,
+    pub base12: Color,
+    pub base13: Color,
+    pub base14: Color,
+    pub base15: Color,
+    pub base16: Color,
+    pub base17: Color,
+}
+
+/// An error returned when a [`Scheme`] cannot be loaded.
+#[derive(Debug, thiserror::Error)]
+pub enum LoadSchemeError {
+    /// A line of the scheme cannot be parsed.
+    #[error("invalid scheme at line {line}: {message}")]
+    Parse {
+        /// The line number, starting at 1.
+        line: usize,
+        /// A description of the problem.
+        message: &'static str,
+    },
+    /// The scheme has no `name` (or `scheme` in the original format).
+    #[error("missing scheme name")]
+    MissingName,
+    /// The scheme does not define one of the colors it requires.
+    #[error("missing color `{0}`")]
+    MissingColor(&'static str),
+    /// One of the colors of the scheme is not a hex color.
+    #[error("invalid color `{name}`: `{value}`")]
+    InvalidColor {
+        /// The name of the color, e.g. `base08`.
+        name: &'static str,
+        /// The value of the color in the scheme.
+        value: String,
+    },
+}
+
+/// The fields of a scheme file in either format.
+#[derive(Default)]
+struct SchemeFile<'a> {
+    system: Option<String>,
+    name: Option<String>,
+    author: String,
+    variant: Option<String>,
+    /// The values of the other keys, from the palette or from the top level.
+    colors: BTreeMap<&'a str, String>,
+}
+
+impl<'a> SchemeFile<'a> {
+    /// Parses the contents of a scheme file.
+    ///
+    /// Each line is either a top-level `key: value` pair, a `key:` that starts a nested map, or an
+    /// indented line of that map. Only the entries of the `palette` map are kept, as well as the
+    /// top-level keys that are not metadata, which are the colors in the original format. Blank
+    /// lines, comments and document markers are ignored.
+    fn parse(yaml: &'a str) -> Result<Self, LoadSchemeError> {
+        let mut file = Self::default();
+        let mut section = None;
+        for (index, line) in yaml.lines().enumerate() {
+            let error = |message| LoadSchemeError::Parse {
+                line: index + 1,
+                message,
+            };
+            let content = line.trim_start();
+            let indented = content.len() < line.len();
+            if content.is_empty()
+                || content.starts_with('#')
+                || matches!(line, "---" | "...")
+                || indented && section.is_some_and(|section| section != "palette")
+            {
+                continue;
+            }
+            let (key, value) = content
+                .split_once(':')
+                .ok_or_else(|| error("expected `key: value`"))?;
+            let key = key.trim_end();
+            let value = parse_value(value).map_err(error)?;
+            if indented {
+                if section.is_none() {
+                    return Err(error("unexpected indentation"));
+                }
+                if let Some(value) = value {
+                    file.colors.insert(key, value);
+                }
+                continue;
+            }
+            section = None;
+            match (key, value) {
+                (_, None) => section = Some(key),
+                ("system", value) => file.system = value,
+                ("name" | "scheme", value) => file.name = value,
+                ("author", Some(value)) => file.author = value,
+                ("variant", value) => file.variant = value,
+                (_, Some(value)) => {
+                    file.colors.insert(key, value);
+                }
+            }
+        }
+        Ok(file)
+    }
+
+    /// Returns the color `name`, or an error if it is missing or invalid.
+    fn color(&self, name: &'static str) -> Result<Color, LoadSchemeError> {
+        let value = self
+            .colors
+            .get(name)
+            .ok_or(LoadSchemeError::MissingColor(name))?;
+        parse_hex(value).ok_or_else(|| LoadSchemeError::InvalidColor {
+            name,
+            value: value.clone(),
+        })
+    }
+
+    /// Returns the colors `names`, or an error if one of them is missing or invalid.
+    fn colors<const N: usize>(
+        &self,
+        names: [&'static str; N],
+    ) -> Result<[Color; N], LoadSchemeError> {
+        let mut colors = [Color::Reset; N];
+        for (color, name) in colors.iter_mut().zip(names) {
+            *color = self.color(name)?;
+        }
+        Ok(colors)
+    }
+}
+
+impl Scheme {
+    /// Creates a base16 scheme from its name and the colors `base00` to `base0F`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    /// use ratatui_core::style::palette::base16::Scheme;
+    /// use ratatui_core::style::palette::tailwind::{
+    ///     AMBER, BLUE, CYAN, GREEN, ORANGE, PURPLE, RED, SLATE, STONE,
+    /// };
+    ///
+    /// let scheme = Scheme::new(
+    ///     "Slate",
+    ///     [
+    ///         SLATE.c950,
+    ///         SLATE.c900,
+    ///         SLATE.c800,
+    ///         SLATE.c600,
+    ///         SLATE.c400,
+    ///         SLATE.c200,
+    ///         SLATE.c100,
+    ///         SLATE.c50,
+    ///         RED.c400,
+    ///         ORANGE.c400,
+    ///         AMBER.c300,
+    ///         GREEN.c400,
+    ///         CYAN.c400,
+    ///         BLUE.c400,
+    ///         PURPLE.c400,
+    ///         STONE.c500,
+    ///     ],
+    /// );
+    /// assert_eq!(scheme.foreground(), SLATE.c200);
+    /// ```
+    ///
+    /// With the `palette` feature, colors of the [`palette`] crate can be used directly:
+    ///
+    /// ```
+    /// # #[cfg(feature = "palette")] {
+    /// use palette::Srgb;
+    /// use ratatui_core::style::Color;
+    /// use ratatui_core::style::palette::base16::Scheme;
+    ///
+    /// let grays = core::array::from_fn(|index| {
+    ///     let level = index as f32 / 15.0;
+    ///     Srgb::new(level, level, level)
+    /// });
+    /// let scheme = Scheme::new("Grays", grays);
+    /// assert_eq!(scheme.background(), Color::Rgb(0, 0, 0));
+    /// assert_eq!(scheme.brown(), Color::Rgb(255, 255, 255));
+    /// # }
+    /// ```
+    ///
+    /// [`palette`]: https://crates.io/crates/palette
+    pub fn new<S: Into<String>, C: Into<Color>>(name: S, colors: [C; 16]) -> Self {
+        let colors = colors.map(Into::into);
+        Self {
+            name: name.into(),
+            author: String::new(),
+            variant: None,
+            base00: colors[0],
+            base01: colors[1],
+            base02: colors[2],
+            base03: colors[3],
+            base04: colors[4],
+            base05: colors[5],
+            base06: colors[6],
+            base07: colors[7],
+            base08: colors[8],
+            base09: colors[9],
+            base0a: colors[10],
+            base0b: colors[11],
+            base0c: colors[12],
+            base0d: colors[13],
+            base0e: colors[14],
+            base0f: colors[15],
+            base24: None,
+        }
+    }
+
+    /// Loads a base16 or base24 scheme from the contents of a YAML scheme file.
+    ///
+    /// The scheme is a base24 scheme if its `system` is `base24`, or if it defines any of the
+    /// colors `base10` to `base17`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a line of the scheme cannot be parsed, if the scheme has no name, or if
+    /// one of its colors is missing or is not a hex color like `#ab4642` or `ab4642`.
+    pub fn from_yaml(yaml: &str) -> Result<Self, LoadSchemeError> {
+        let file = SchemeFile::parse(yaml)?;
+        let is_base24 = file.system.as_deref() == Some("base24")
+            || BASE24_NAMES
+                .iter()
+                .any(|name| file.colors.contains_key(name));
+        let base24 = if is_base24 {
+            let [
+                base10,
+                base11,
+                base12,
+                base13,
+                base14,
+                base15,
+                base16,
+                base17,
+            ] = file.colors(BASE24_NAMES)?;
+            Some(Base24 {
+                base10,
+                base11,
+                base12,
+                base13,
+                base14,
+                base15,
+                base16,
+                base17,
+            })
+        } else {
+            None
+        };
+        let mut scheme = Self::new(String::new(), file.colors(BASE16_NAMES)?);
+        scheme.name = file.name.ok_or(LoadSchemeError::MissingName)?;
+        scheme.author = file.author;
+        scheme.variant = file.variant;
+        scheme.base24 = base24;
+        Ok(scheme)
+    }
+
+    /// Returns the colors `base00` to `base0F`.
+    pub const fn colors(&self) -> [Color; 16] {
+        [
+            self.base00,
+            self.base01,
+            self.base02,
+            self.base03,
+            self.base04,
+            self.base05,
+            self.base06,
+            self.base07,
+            self.base08,
+            self.base09,
+            self.base0a,
+            self.base0b,
+            self.base0c,
+            self.base0d,
+            self.base0e,
+            self.base0f,
+        ]
+    }
+}
+
+/// Accessors for the colors by their meaning in the base16 and base24 styling guidelines.
+impl Scheme {
+    /// The default background (`base00`).
+    pub const fn background(&self) -> Color {
+        self.base00
+    }
+
+    /// A lighter background, used for status bars and line numbers (`base01`).
+    pub const fn lighter_background(&self) -> Color {
+        self.base01
+    }
+
+    /// The background of selections (`base02`).
+    pub const fn selection_background(&self) -> Color {
+        self.base02
+    }
+
+    /// The color of comments and invisible characters (`base03`).
+    pub const fn comment(&self) -> Color {
+        self.base03
+    }
+
+    /// A dark foreground, used for status bars (`base04`).
+    pub const fn dark_foreground(&self) -> Color {
+        self.base04
+    }
+
+    /// The default foreground (`base05`).
+    pub const fn foreground(&self) -> Color {
+        self.base05
+    }
+
+    /// A light foreground (`base06`).
+    pub const fn light_foreground(&self) -> Color {
+        self.base06
+    }
+
+    /// The lightest background (`base07`).
+    pub const fn light_background(&self) -> Color {
+        self.base07
+    }
+
+    /// Red, used for variables and diff deletions (`base08`).
+    pub const fn red(&self) -> Color {
+        self.base08
+    }
+
+    /// Orange, used for numbers and constants (`base09`).
+    pub const fn orange(&self) -> Color {
+        self.base09
+    }
+
+    /// Yellow, used for classes and search highlights (`base0A`).
+    pub const fn yellow(&self) -> Color {
+        self.base0a
+    }
+
+    /// Green, used for strings and diff insertions (`base0B`).
+    pub const fn green(&self) -> Color {
+        self.base0b
+    }
+
+    /// Cyan, used for escape characters and regular expressions (`base0C`).
+    pub const fn cyan(&self) -> Color {
+        self.base0c
+    }
+
+    /// Blue, used for functions and headings (`base0D`).
+    pub const fn blue(&self) -> Color {
+        self.base0d
+    }
+
+    /// Magenta, used for keywords and diff changes (`base0E`).
+    pub const fn magenta(&self) -> Color {
+        self.base0e
+    }
+
+    /// Brown, used for deprecated items and embedded language tags (`base0F`).
+    pub const fn brown(&self) -> Color {
+        self.base0f
+    }
+
+    /// A darker background (`base10`, or `base00` for base16 schemes).
+    pub const fn darker_background(&self) -> Color {
+        match self.base24 {
+            Some(base24) => base24.base10,
+            None => self.base00,
+        }
+    }
+
+    /// The darkest background (`base11`, or `base00` for base16 schemes).
+    pub const fn darkest_background(&self) -> Color {
+        match self.base24 {
+            Some(base24) => base24.base11,
+            None => self.base00,
+        }
+    }
+
+    /// Bright red (`base12`, or `base08` for base16 schemes).
+    pub const fn bright_red(&self) -> Color {
+        match self.base24 {
+            Some(base24) => base24.base12,
+            None => self.base08,
+        }
+    }
+
+    /// Bright yellow (`base13`, or `base0A` for base16 schemes).
+    pub const fn bright_yellow(&self) -> Color {
+        match self.base24 {
+            Some(base24) => base24.base13,
+            None => self.base0a,
+        }
+    }
+
+    /// Bright green (`base14`, or `base0B` for base16 schemes).
+    pub const fn bright_green(&self) -> Color {
+        match self.base24 {
+            Some(base24) => base24.base14,
+            None => self.base0b,
+        }
+    }
+
+    /// Bright cyan (`base15`, or `base0C` for base16 schemes).
+    pub const fn bright_cyan(&self) -> Color {
+        match self.base24 {
+            Some(base24) => base24.base15,
+            None => self.base0c,
+        }
+    }
+
+    /// Bright blue (`base16`, or `base0D` for base16 schemes).
+    pub const fn bright_blue(&self) -> Color {
+        match self.base24 {
+            Some(base24) => base24.base16,
+            None => self.base0d,
+        }
+    }
+
+    /// Bright magenta (`base17`, or `base0E` for base16 schemes).
+    pub const fn bright_magenta(&self) -> Color {
+        match self.base24 {
+            Some(base24) => base24.base17,
+            None => self.base0e,
+        }
+    }
+}
+
+/// Parses a hex color like `#ab4642` or `ab4642`.
+fn parse_hex(value: &str) -> Option<Color> {
+    let hex = value.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(hex, 16).ok().map(Color::from_u32)
+}
+
+/// Parses the value of a `key: value` line, which is everything after the colon.
+///
+/// Returns `None` if there is no value, i.e. the key starts a nested map. The value can be a plain
+/// string, which ends at a comment, or a single-quoted or double-quoted string.
+fn parse_value(value: &str) -> Result<Option<String>, &'static str> {
+    let value = value.trim();
+    let (content, rest) = match value.chars().next() {
+        None | Some('#') => return Ok(None),
+        Some(quote @ ('"' | '\'')) => parse_quoted(value, quote)?,
+        Some(_) => {
+            let content = value.split(" #").next().unwrap_or_default();
+            return Ok(Some(String::from(content.trim_end())));
+        }
+    };
+    let rest = rest.trim_start();
+    if rest.is_empty() || rest.starts_with('#') {
+        Ok(Some(content))
+    } else {
+        Err("unexpected characters after string")
+    }
+}
+
+/// Parses the quoted string at the start of `value`, returning its content and the rest of
+/// `value`.
+///
+/// Double-quoted strings can contain backslash escapes, and single-quoted strings can contain `''`
+/// for a single quote.
+fn parse_quoted(value: &str, quote: char) -> Result<(String, &str), &'static str> {
+    let mut content = String::new();
+    let mut chars = value.char_indices().skip(1).peekable();
+    while let Some((index, char)) = chars.next() {
+        if char == '\\' && quote == '"' {
+            content.extend(chars.next().map(|(_, escaped)| escaped));
+        } else if char != quote {
+            content.push(char);
+        } else if quote == '\'' && chars.next_if(|&(_, next)| next == quote).is_some() {
+            content.push(quote);
+        } else {
+            return Ok((content, value.get(index + 1..).unwrap_or_default()));
+        }
+    }
+    Err("unterminated string")
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    const BASE16_LEGACY: &str = r#"
+scheme: "Default Dark"
+author: "Chris Kempson"
+base00: "181818"
+base01: "282828"
+base02: "383838"
+base03: "585858"
+base04: "b8b8b8"
+base05: "d8d8d8"
+base06: "e8e8e8"
+base07: "f8f8f8"
+base08: "ab4642"
+base09: "dc9656"
+base0A: "f7ca88"
+base0B: "a1b56c"
+base0C: "86c1b9"
+base0D: "7cafc2"
+base0E: "ba8baf"
+base0F: "a16946"
+"#;
+
+    const BASE24: &str = r##"
+system: "base24"
+name: "Example 24"
+author: "Ratatui"
+variant: "dark"
+palette:
+  base00: "#000000"
+  base01: "#010101"
+  base02: "#020202"
+  base03: "#030303"
+  base04: "#040404"
+  base05: "#050505"
+  base06: "#060606"
+  base07: "#070707"
+  base08: "#080808"
+  base09: "#090909"
+  base0A: "#0a0a0a"
+  base0B: "#0b0b0b"
+  base0C: "#0c0c0c"
+  base0D: "#0d0d0d"
+  base0E: "#0e0e0e"
+  base0F: "#0f0f0f"
+  base10: "#101010"
+  base11: "#111111"
+  base12: "#121212"
+  base13: "#131313"
+  base14: "#141414"
+  base15: "#151515"
+  base16: "#161616"
+  base17: "#171717"
+"##;
+
+    #[test]
+    fn from_yaml_legacy_format() {
+        let scheme = Scheme::from_yaml(BASE16_LEGACY).unwrap();
+        assert_eq!(scheme.name, "Default Dark");
+        assert_eq!(scheme.author, "Chris Kempson");
+        assert_eq!(scheme.variant, None);
+        assert_eq!(scheme.base24, None);
+        assert_eq!(scheme.background(), Color::from_u32(0x181818));
+        assert_eq!(scheme.foreground(), Color::from_u32(0xd8d8d8));
+        assert_eq!(scheme.yellow(), Color::from_u32(0xf7ca88));
+        assert_eq!(scheme.brown(), Color::from_u32(0xa16946));
+        assert_eq!(scheme.darker_background(), scheme.background());
+        assert_eq!(scheme.bright_magenta(), scheme.magenta());
+    }
+
+    #[test]
+    fn from_yaml_base24() {
+        let scheme = Scheme::from_yaml(BASE24).unwrap();
+        assert_eq!(scheme.name, "Example 24");
+        assert_eq!(scheme.variant.as_deref(), Some("dark"));
+        assert_eq!(
+            scheme.colors(),
+            core::array::from_fn(|index| Color::Rgb(index as u8, index as u8, index as u8))
+        );
+        assert_eq!(scheme.darker_background(), Color::from_u32(0x101010));
+        assert_eq!(scheme.darkest_background(), Color::from_u32(0x111111));
+        assert_eq!(scheme.bright_red(), Color::from_u32(0x121212));
+        assert_eq!(scheme.bright_yellow(), Color::from_u32(0x131313));
+        assert_eq!(scheme.bright_green(), Color::from_u32(0x141414));
+        assert_eq!(scheme.bright_cyan(), Color::from_u32(0x151515));
+        assert_eq!(scheme.bright_blue(), Color::from_u32(0x161616));
+        assert_eq!(scheme.bright_magenta(), Color::from_u32(0x171717));
+    }
+
+    #[test]
+    fn from_yaml_unquoted_values() {
+        let yaml = BASE16_LEGACY
+            .replace("\"Chris Kempson\"", "Chris Kempson")
+            .replace("\"181818\"", "181818")
+            .replace("\"ab4642\"", "ab4642 # red");
+        let scheme = Scheme::from_yaml(&yaml).unwrap();
+        assert_eq!(scheme.author, "Chris Kempson");
+        assert_eq!(scheme.base00, Color::from_u32(0x181818));
+        assert_eq!(scheme.base08, Color::from_u32(0xab4642));
+    }
+
+    #[test]
+    fn from_yaml_quoted_values() {
+        let yaml = BASE16_LEGACY
+            .replace("\"Chris Kempson\"", "'Chris O''Kempson' # comment")
+            .replace("\"Default Dark\"", r#""Default \"Dark\"""#)
+            .replace("\"181818\"", "'181818'");
+        let scheme = Scheme::from_yaml(&yaml).unwrap();
+        assert_eq!(scheme.name, "Default \"Dark\"");
+        assert_eq!(scheme.author, "Chris O'Kempson");
+        assert_eq!(scheme.base00, Color::from_u32(0x181818));
+    }
+
+    #[test]
+    fn from_yaml_ignores_comments_and_unknown_keys() {
+        let yaml = BASE24
+            .replacen('\n', "---\n# comment\n\n", 1)
+            .replace(
+                "palette:\n",
+                "slug: \"example\"\npalette: # colors\n  # comment\n",
+            )
+            .replace(
+                "variant: \"dark\"\n",
+                "variant: \"dark\"\nauthors:\n  - \"Ratatui\"\n",
+            );
+        assert_eq!(
+            Scheme::from_yaml(&yaml).unwrap(),
+            Scheme::from_yaml(BASE24).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case::missing("base0F: \"a16946\"\n", "", "missing color `base0F`")]
+    #[case::too_short("\"a16946\"", "\"a1694\"", "invalid color `base0F`: `a1694`")]
+    #[case::not_hex("\"a16946\"", "\"brown!\"", "invalid color `base0F`: `brown!`")]
+    #[case::not_string("\"a16946\"", "true", "invalid color `base0F`: `true`")]
+    #[case::no_name("scheme: \"Default Dark\"\n", "", "missing scheme name")]
+    #[case::no_key(
+        "base0F: \"a16946\"",
+        "a16946",
+        "invalid scheme at line 19: expected `key: value`"
+    )]
+    #[case::unterminated(
+        "\"a16946\"",
+        "\"a16946",
+        "invalid scheme at line 19: unterminated string"
+    )]
+    #[case::after_string(
+        "\"a16946\"",
+        "\"a16946\" 1",
+        "invalid scheme at line 19: unexpected characters after string"
+    )]
+    #[case::indented(
+        "base0F",
+        "  base0F",
+        "invalid scheme at line 19: unexpected indentation"
+    )]
+    fn from_yaml_errors(#[case] from: &str, #[case] to: &str, #[case] expected: &str) {
+        let yaml = BASE16_LEGACY.replace(from, to);
+        assert_eq!(Scheme::from_yaml(&yaml).unwrap_err().to_string(), expected);
+    }
+
+    #[test]
+    fn from_yaml_base24_missing_color() {
+        let yaml = BASE24.replace("  base15: \"#151515\"\n", "");
+        assert_eq!(
+            Scheme::from_yaml(&yaml).unwrap_err().to_string(),
+            "missing color `base15`"
+        );
+    }
+
+    #[test]
+    fn from_yaml_parse_error() {
+        let error = Scheme::from_yaml("author: \"Ratatui").unwrap_err();
+        assert!(matches!(
+            error,
+            LoadSchemeError::Parse {
+                line: 1,
+                message: "unterminated string"
+            }
+        ));
+    }
+
+    #[test]
+    fn new() {
+        let colors = core::array::from_fn(|index| Color::Indexed(index as u8));
+        let scheme = Scheme::new("Indexed", colors);
+        assert_eq!(scheme.name, "Indexed");
+        assert_eq!(scheme.colors(), colors);
+        assert_eq!(scheme.base0e, Color::Indexed(14));
+        assert_eq!(scheme.magenta(), Color::Indexed(14));
+        assert_eq!(scheme.bright_blue(), Color::Indexed(13));
+    }
+}
@@ -0,0 +1,266 @@
+//! Catppuccin color palettes.
+//!
+//! Represents the four flavors of the [Catppuccin] color scheme: [`LATTE`] (light), [`FRAPPE`],
+//! [`MACCHIATO`] and [`MOCHA`] (dark). Each flavor has 14 accent colors, and 12 colors for text,
+//! overlays, surfaces and backgrounds, from the brightest to the darkest in dark flavors (and the
+//! other way around in the light flavor).
+//!
+//! [Catppuccin]: https://catppuccin.com/palette
+//!
+//! <style>
+//! .color { display: flex; align-items: center; }
+//! .color > div { width: 2rem; height: 2rem; }
+//! .color > div.name { width: 150px; !important; }
+//! </style>
+//! <div style="overflow-x: auto">
+//! <div style="display: flex; flex-direction:column; text-align: left">
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`LATTE`]</div>
+//!     <div style="background-color: #dc8a78"></div> <div style="background-color: #dd7878"></div>
+//!     <div style="background-color: #ea76cb"></div> <div style="background-color: #8839ef"></div>
+//!     <div style="background-color: #d20f39"></div> <div style="background-color: #e64553"></div>
+//!     <div style="background-color: #fe640b"></div> <div style="background-color: #df8e1d"></div>
+//!     <div style="background-color: #40a02b"></div> <div style="background-color: #179299"></div>
+//!     <div style="background-color: #04a5e5"></div> <div style="background-color: #209fb5"></div>
+//!     <div style="background-color: #1e66f5"></div> <div style="background-color: #7287fd"></div>
+//!     <div style="background-color: #4c4f69"></div> <div style="background-color: #5c5f77"></div>
+//!     <div style="background-color: #6c6f85"></div> <div style="background-color: #7c7f93"></div>
+//!     <div style="background-color: #8c8fa1"></div> <div style="background-color: #9ca0b0"></div>
+//!     <div style="background-color: #acb0be"></div> <div style="background-color: #bcc0cc"></div>
+//!     <div style="background-color: #ccd0da"></div> <div style="background-color: #eff1f5"></div>
+//!     <div style="background-color: #e6e9ef"></div> <div style="background-color: #dce0e8"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`FRAPPE`]</div>
+//!     <div style="background-color: #f2d5cf"></div> <div style="background-color: #eebebe"></div>
+//!     <div style="background-color: #f4b8e4"></div> <div style="background-color: #ca9ee6"></div>
+//!     <div style="background-color: #e78284"></div> <div style="background-color: #ea999c"></div>
+//!     <div style="background-color: #ef9f76"></div> <div style="background-color: #e5c890"></div>
+//!     <div style="background-color: #a6d189"></div> <div style="background-color: #81c8be"></div>
+//!     <div style="background-color: #99d1db"></div> <div style="background-color: #85c1dc"></div>
+//!     <div style="background-color: #8caaee"></div> <div style="background-color: #babbf1"></div>
+//!     <div style="background-color: #c6d0f5"></div> <div style="background-color: #b5bfe2"></div>
+//!     <div style="background-color: #a5adce"></div> <div style="background-color: #949cbb"></div>
+//!     <div style="background-color: #838ba7"></div> <div style="background-color: #737994"></div>
+//!     <div style="background-color: #626880"></div> <div style="background-color: #51576d"></div>
+//!     <div style="background-color: #414559"></div> <div style="background-color: #303446"></div>
+//!     <div style="background-color: #292c3c"></div> <div style="background-color: #232634"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`MACCHIATO`]</div>
+//!     <div style="background-color: #f4dbd6"></div> <div style="background-color: #f0c6c6"></div>
+//!     <div style="background-color: #f5bde6"></div> <div style="background-color: #c6a0f6"></div>
+//!     <div style="background-color: #ed8796"></div> <div style="background-color: #ee99a0"></div>
+//!     <div style="background-color: #f5a97f"></div> <div style="background-color: #eed49f"></div>
+//!     <div style="background-color: #a6da95"></div> <div style="background-color: #8bd5ca"></div>
+//!     <div style="background-color: #91d7e3"></div> <div style="background-color: #7dc4e4"></div>
+//!     <div style="background-color: #8aadf4"></div> <div style="background-color: #b7bdf8"></div>
+//!     <div style="background-color: #cad3f5"></div> <div style="background-color: #b8c0e0"></div>
+//!     <div style="background-color: #a5adcb"></div> <div style="background-color: #939ab7"></div>
+//!     <div style="background-color: #8087a2"></div> <div style="background-color: #6e738d"></div>
+//!     <div style="background-color: #5b6078"></div> <div style="background-color: #494d64"></div>
+//!     <div style="background-color: #363a4f"></div> <div style="background-color: #24273a"></div>
+//!     <div style="background-color: #1e2030"></div> <div style="background-color: #181926"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`MOCHA`]</div>
+//!     <div style="background-color: #f5e0dc"></div> <div style="background-color: #f2cdcd"></div>
+//!     <div style="background-color: #f5c2e7"></div> <div style="background-color: #cba6f7"></div>
+//!     <div style="background-color: #f38ba8"></div> <div style="background-color: #eba0ac"></div>
+//!     <div style="background-color: #fab387"></div> <div style="background-color: #f9e2af"></div>
+//!     <div style="background-color: #a6e3a1"></div> <div style="background-color: #94e2d5"></div>
+//!     <div style="background-color: #89dceb"></div> <div style="background-color: #74c7ec"></div>
+//!     <div style="background-color: #89b4fa"></div> <div style="background-color: #b4befe"></div>
+//!     <div style="background-color: #cdd6f4"></div> <div style="background-color: #bac2de"></div>
+//!     <div style="background-color: #a6adc8"></div> <div style="background-color: #9399b2"></div>
+//!     <div style="background-color: #7f849c"></div> <div style="background-color: #6c7086"></div>
+//!     <div style="background-color: #585b70"></div> <div style="background-color: #45475a"></div>
+//!     <div style="background-color: #313244"></div> <div style="background-color: #1e1e2e"></div>
+//!     <div style="background-color: #181825"></div> <div style="background-color: #11111b"></div>
+//! </div>
+//! </div>
+//! </div>
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_core::style::Color;
+//! use ratatui_core::style::palette::catppuccin::{LATTE, MOCHA};
+//!
+//! assert_eq!(MOCHA.base, Color::Rgb(30, 30, 46));
+//! assert_eq!(LATTE.blue, Color::Rgb(30, 102, 245));
+//! ```
+
+use crate::style::Color;
+
+/// The colors of a Catppuccin flavor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Flavor {
+    pub rosewater: Color,
+    pub flamingo: Color,
+    pub pink: Color,
+    pub mauve: Color,
+    pub red: Color,
+    pub maroon: Color,
+    pub peach: Color,
+    pub yellow: Color,
+    pub green: Color,
+    pub teal: Color,
+    pub sky: Color,
+    pub sapphire: Color,
+    pub blue: Color,
+    pub lavender: Color,
+    pub text: Color,
+    pub subtext1: Color,
+    pub subtext0: Color,
+    pub overlay2: Color,
+    pub overlay1: Color,
+    pub overlay0: Color,
+    pub surface2: Color,
+    pub surface1: Color,
+    pub surface0: Color,
+    pub base: Color,
+    pub mantle: Color,
+    pub crust: Color,
+}
+
+#[rustfmt::skip]
+/// <style>.palette div{width:2rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #dc8a78"></div><div style="background-color: #dd7878"></div><div style="background-color: #ea76cb"></div><div style="background-color: #8839ef"></div><div style="background-color: #d20f39"></div><div style="background-color: #e64553"></div><div style="background-color: #fe640b"></div><div style="background-color: #df8e1d"></div><div style="background-color: #40a02b"></div><div style="background-color: #179299"></div><div style="background-color: #04a5e5"></div><div style="background-color: #209fb5"></div><div style="background-color: #1e66f5"></div><div style="background-color: #7287fd"></div><div style="background-color: #4c4f69"></div><div style="background-color: #5c5f77"></div><div style="background-color: #6c6f85"></div><div style="background-color: #7c7f93"></div><div style="background-color: #8c8fa1"></div><div style="background-color: #9ca0b0"></div><div style="background-color: #acb0be"></div><div style="background-color: #bcc0cc"></div><div style="background-color: #ccd0da"></div><div style="background-color: #eff1f5"></div><div style="background-color: #e6e9ef"></div><div style="background-color: #dce0e8"></div></div>
+///
+/// Latte, the light flavor.
+pub const LATTE: Flavor = Flavor {
+    rosewater: Color::from_u32(0xdc8a78),
+    flamingo: Color::from_u32(0xdd7878),
+    pink: Color::from_u32(0xea76cb),
+    mauve: Color::from_u32(0x8839ef),
+    red: Color::from_u32(0xd20f39),
+    maroon: Color::from_u32(0xe64553),
+    peach: Color::from_u32(0xfe640b),
+    yellow: Color::from_u32(0xdf8e1d),
+    green: Color::from_u32(0x40a02b),
+    teal: Color::from_u32(0x179299),
+    sky: Color::from_u32(0x04a5e5),
+    sapphire: Color::from_u32(0x209fb5),
+    blue: Color::from_u32(0x1e66f5),
+    lavender: Color::from_u32(0x7287fd),
+    text: Color::from_u32(0x4c4f69),
+    subtext1: Color::from_u32(0x5c5f77),
+    subtext0: Color::from_u32(0x6c6f85),
+    overlay2: Color::from_u32(0x7c7f93),
+    overlay1: Color::from_u32(0x8c8fa1),
+    overlay0: Color::from_u32(0x9ca0b0),
+    surface2: Color::from_u32(0xacb0be),
+    surface1: Color::from_u32(0xbcc0cc),
+    surface0: Color::from_u32(0xccd0da),
+    base: Color::from_u32(0xeff1f5),
+    mantle: Color::from_u32(0xe6e9ef),
+    crust: Color::from_u32(0xdce0e8),
+};
+
+#[rustfmt::skip]
+/// <style>.palette div{width:2rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #f2d5cf"></div><div style="background-color: #eebebe"></div><div style="background-color: #f4b8e4"></div><div style="background-color: #ca9ee6"></div><div style="background-color: #e78284"></div><div style="background-color: #ea999c"></div><div style="background-color: #ef9f76"></div><div style="background-color: #e5c890"></div><div style="background-color: #a6d189"></div><div style="background-color: #81c8be"></div><div style="background-color: #99d1db"></div><div style="background-color: #85c1dc"></div><div style="background-color: #8caaee"></div><div style="background-color: #babbf1"></div><div style="background-color: #c6d0f5"></div><div style="background-color: #b5bfe2"></div><div style="background-color: #a5adce"></div><div style="background-color: #949cbb"></div><div style="background-color: #838ba7"></div><div style="background-color: #737994"></div><div style="background-color: #626880"></div><div style="background-color: #51576d"></div><div style="background-color: #414559"></div><div style="background-color: #303446"></div><div style="background-color: #292c3c"></div><div style="background-color: #232634"></div></div>
+///
+/// Frappé, the least dark of the dark flavors.
+pub const FRAPPE: Flavor = Flavor {
+    rosewater: Color::from_u32(0xf2d5cf),
+    flamingo: Color::from_u32(0xeebebe),
+    pink: Color::from_u32(0xf4b8e4),
+    mauve: Color::from_u32(0xca9ee6),
+    red: Color::from_u32(0xe78284),
+    maroon: Color::from_u32(0xea999c),
+    peach: Color::from_u32(0xef9f76),
+    yellow: Color::from_u32(0xe5c890),
+    green: Color::from_u32(0xa6d189),
+    teal: Color::from_u32(0x81c8be),
+    sky: Color::from_u32(0x99d1db),
+    sapphire: Color::from_u32(0x85c1dc),
+    blue: Color::from_u32(0x8caaee),
+    lavender: Color::from_u32(0xbabbf1),
+    text: Color::from_u32(0xc6d0f5),
+    subtext1: Color::from_u32(0xb5bfe2),
+    subtext0: Color::from_u32(0xa5adce),
+    overlay2: Color::from_u32(0x949cbb),
+    overlay1: Color::from_u32(0x838ba7),
+    overlay0: Color::from_u32(0x737994),
+    surface2: Color::from_u32(0x626880),
+    surface1: Color::from_u32(0x51576d),
+    surface0: Color::from_u32(0x414559),
+    base: Color::from_u32(0x303446),
+    mantle: Color::from_u32(0x292c3c),
+    crust: Color::from_u32(0x232634),
+};
+
+#[rustfmt::skip]
+/// <style>.palette div{width:2rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #f4dbd6"></div><div style="background-color: #f0c6c6"></div><div style="background-color: #f5bde6"></div><div style="background-color: #c6a0f6"></div><div style="background-color: #ed8796"></div><div style="background-color: #ee99a0"></div><div style="background-color: #f5a97f"></div><div style="background-color: #eed49f"></div><div style="background-color: #a6da95"></div><div style="background-color: #8bd5ca"></div><div style="background-color: #91d7e3"></div><div style="background-color: #7dc4e4"></div><div style="background-color: #8aadf4"></div><div style="background-color: #b7bdf8"></div><div style="background-color: #cad3f5"></div><div style="background-color: #b8c0e0"></div><div style="background-color: #a5adcb"></div><div style="background-color: #939ab7"></div><div style="background-color: #8087a2"></div><div style="background-color: #6e738d"></div><div style="background-color: #5b6078"></div><div style="background-color: #494d64"></div><div style="background-color: #363a4f"></div><div style="background-color: #24273a"></div><div style="background-color: #1e2030"></div><div style="background-color: #181926"></div></div>
+///
+/// Macchiato, a dark flavor with medium contrast.
+pub const MACCHIATO: Flavor = Flavor {
+    rosewater: Color::from_u32(0xf4dbd6),
+    flamingo: Color::from_u32(0xf0c6c6),
+    pink: Color::from_u32(0xf5bde6),
+    mauve: Color::from_u32(0xc6a0f6),
+    red: Color::from_u32(0xed8796),
+    maroon: Color::from_u32(0xee99a0),
+    peach: Color::from_u32(0xf5a97f),
+    yellow: Color::from_u32(0xeed49f),
+    green: Color::from_u32(0xa6da95),
+    teal: Color::from_u32(0x8bd5ca),
+    sky: Color::from_u32(0x91d7e3),
+    sapphire: Color::from_u32(0x7dc4e4),
+    blue: Color::from_u32(0x8aadf4),
+    lavender: Color::from_u32(0xb7bdf8),
+    text: Color::from_u32(0xcad3f5),
+    subtext1: Color::from_u32(0xb8c0e0),
+    subtext0: Color::from_u32(0xa5adcb),
+    overlay2: Color::from_u32(0x939ab7),
+    overlay1: Color::from_u32(0x8087a2),
+    overlay0: Color::from_u32(0x6e738d),
+    surface2: Color::from_u32(0x5b6078),
+    surface1: Color::from_u32(0x494d64),
+    surface0: Color::from_u32(0x363a4f),
+    base: Color::from_u32(0x24273a),
+    mantle: Color::from_u32(0x1e2030),
+    crust: Color::from_u32(0x181926),
+};
+
+#[rustfmt::skip]
+/// <style>.palette div{width:2rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #f5e0dc"></div><div style="background-color: #f2cdcd"></div><div style="background-color: #f5c2e7"></div><div style="background-color: #cba6f7"></div><div style="background-color: #f38ba8"></div><div style="background-color: #eba0ac"></div><div style="background-color: #fab387"></div><div style="background-color: #f9e2af"></div><div style="background-color: #a6e3a1"></div><div style="background-color: #94e2d5"></div><div style="background-color: #89dceb"></div><div style="background-color: #74c7ec"></div><div style="background-color: #89b4fa"></div><div style="background-color: #b4befe"></div><div style="background-color: #cdd6f4"></div><div style="background-color: #bac2de"></div><div style="background-color: #a6adc8"></div><div style="background-color: #9399b2"></div><div style="background-color: #7f849c"></div><div style="background-color: #6c7086"></div><div style="background-color: #585b70"></div><div style="background-color: #45475a"></div><div style="background-color: #313244"></div><div style="background-color: #1e1e2e"></div><div style="background-color: #181825"></div><div style="background-color: #11111b"></div></div>
+///
+/// Mocha, the darkest flavor.
+pub const MOCHA: Flavor = Flavor {
+    rosewater: Color::from_u32(0xf5e0dc),
+    flamingo: Color::from_u32(0xf2cdcd),
+    pink: Color::from_u32(0xf5c2e7),
+    mauve: Color::from_u32(0xcba6f7),
+    red: Color::from_u32(0xf38ba8),
+    maroon: Color::from_u32(0xeba0ac),
+    peach: Color::from_u32(0xfab387),
+    yellow: Color::from_u32(0xf9e2af),
+    green: Color::from_u32(0xa6e3a1),
+    teal: Color::from_u32(0x94e2d5),
+    sky: Color::from_u32(0x89dceb),
+    sapphire: Color::from_u32(0x74c7ec),
+    blue: Color::from_u32(0x89b4fa),
+    lavender: Color::from_u32(0xb4befe),
+    text: Color::from_u32(0xcdd6f4),
+    subtext1: Color::from_u32(0xbac2de),
+    subtext0: Color::from_u32(0xa6adc8),
+    overlay2: Color::from_u32(0x9399b2),
+    overlay1: Color::from_u32(0x7f849c),
+    overlay0: Color::from_u32(0x6c7086),
+    surface2: Color::from_u32(0x585b70),
+    surface1: Color::from_u32(0x45475a),
+    surface0: Color::from_u32(0x313244),
+    base: Color::from_u32(0x1e1e2e),
+    mantle: Color::from_u32(0x181825),
+    crust: Color::from_u32(0x11111b),
+};
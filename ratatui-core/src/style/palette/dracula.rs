@@ -0,0 +1,137 @@
+//! Dracula color palette.
+//!
+//! Represents the 11 colors of the [Dracula] color scheme.
+//!
+//! [Dracula]: https://draculatheme.com/contribute
+//!
+//! <style>
+//! .color { display: flex; align-items: center; }
+//! .color > div { width: 2rem; height: 2rem; }
+//! .color > div.name { width: 150px; !important; }
+//! </style>
+//! <div style="overflow-x: auto">
+//! <div style="display: flex; flex-direction:column; text-align: left">
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BACKGROUND`]</div>
+//!     <div style="background-color: #282a36; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`CURRENT_LINE`]</div>
+//!     <div style="background-color: #44475a; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`FOREGROUND`]</div>
+//!     <div style="background-color: #f8f8f2; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`COMMENT`]</div>
+//!     <div style="background-color: #6272a4; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`CYAN`]</div>
+//!     <div style="background-color: #8be9fd; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`GREEN`]</div>
+//!     <div style="background-color: #50fa7b; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`ORANGE`]</div>
+//!     <div style="background-color: #ffb86c; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`PINK`]</div>
+//!     <div style="background-color: #ff79c6; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`PURPLE`]</div>
+//!     <div style="background-color: #bd93f9; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`RED`]</div>
+//!     <div style="background-color: #ff5555; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`YELLOW`]</div>
+//!     <div style="background-color: #f1fa8c; width:22rem"></div>
+//! </div>
+//! </div>
+//! </div>
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_core::style::Color;
+//! use ratatui_core::style::palette::dracula::{BACKGROUND, PURPLE};
+//!
+//! assert_eq!(BACKGROUND, Color::Rgb(40, 42, 54));
+//! assert_eq!(PURPLE, Color::Rgb(189, 147, 249));
+//! ```
+
+use crate::style::Color;
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #282a36"></div></div>
+pub const BACKGROUND: Color = Color::from_u32(0x282a36);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #44475a"></div></div>
+pub const CURRENT_LINE: Color = Color::from_u32(0x44475a);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #f8f8f2"></div></div>
+pub const FOREGROUND: Color = Color::from_u32(0xf8f8f2);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #6272a4"></div></div>
+pub const COMMENT: Color = Color::from_u32(0x6272a4);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #8be9fd"></div></div>
+pub const CYAN: Color = Color::from_u32(0x8be9fd);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #50fa7b"></div></div>
+pub const GREEN: Color = Color::from_u32(0x50fa7b);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #ffb86c"></div></div>
+pub const ORANGE: Color = Color::from_u32(0xffb86c);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #ff79c6"></div></div>
+pub const PINK: Color = Color::from_u32(0xff79c6);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #bd93f9"></div></div>
+pub const PURPLE: Color = Color::from_u32(0xbd93f9);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #ff5555"></div></div>
+pub const RED: Color = Color::from_u32(0xff5555);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #f1fa8c"></div></div>
+pub const YELLOW: Color = Color::from_u32(0xf1fa8c);
@@ -0,0 +1,391 @@
+//! Gruvbox color palette.
+//!
+//! Represents the colors of the [Gruvbox] color scheme by Pavel Pertsev: the dark tones
+//! ([`DARK0_HARD`] to [`DARK4`]) used as backgrounds of the dark mode and foregrounds of the light
+//! mode, the light tones ([`LIGHT0_HARD`] to [`LIGHT4`]) used the other way around, and three
+//! variants of each accent color: bright (foregrounds of the dark mode), neutral (shared by both
+//! modes) and faded (foregrounds of the light mode).
+//!
+//! [Gruvbox]: https://github.com/morhetz/gruvbox
+//!
+//! <style>
+//! .color { display: flex; align-items: center; }
+//! .color > div { width: 2rem; height: 2rem; }
+//! .color > div.name { width: 150px; !important; }
+//! </style>
+//! <div style="overflow-x: auto">
+//! <div style="display: flex; flex-direction:column; text-align: left">
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`DARK0_HARD`]</div>
+//!     <div style="background-color: #1d2021; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`DARK0`]</div>
+//!     <div style="background-color: #282828; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`DARK0_SOFT`]</div>
+//!     <div style="background-color: #32302f; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`DARK1`]</div>
+//!     <div style="background-color: #3c3836; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`DARK2`]</div>
+//!     <div style="background-color: #504945; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`DARK3`]</div>
+//!     <div style="background-color: #665c54; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`DARK4`]</div>
+//!     <div style="background-color: #7c6f64; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`GRAY`]</div>
+//!     <div style="background-color: #928374; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`LIGHT0_HARD`]</div>
+//!     <div style="background-color: #f9f5d7; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`LIGHT0`]</div>
+//!     <div style="background-color: #fbf1c7; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`LIGHT0_SOFT`]</div>
+//!     <div style="background-color: #f2e5bc; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`LIGHT1`]</div>
+//!     <div style="background-color: #ebdbb2; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`LIGHT2`]</div>
+//!     <div style="background-color: #d5c4a1; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`LIGHT3`]</div>
+//!     <div style="background-color: #bdae93; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`LIGHT4`]</div>
+//!     <div style="background-color: #a89984; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BRIGHT_RED`]</div>
+//!     <div style="background-color: #fb4934; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BRIGHT_GREEN`]</div>
+//!     <div style="background-color: #b8bb26; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BRIGHT_YELLOW`]</div>
+//!     <div style="background-color: #fabd2f; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BRIGHT_BLUE`]</div>
+//!     <div style="background-color: #83a598; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BRIGHT_PURPLE`]</div>
+//!     <div style="background-color: #d3869b; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BRIGHT_AQUA`]</div>
+//!     <div style="background-color: #8ec07c; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BRIGHT_ORANGE`]</div>
+//!     <div style="background-color: #fe8019; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NEUTRAL_RED`]</div>
+//!     <div style="background-color: #cc241d; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NEUTRAL_GREEN`]</div>
+//!     <div style="background-color: #98971a; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NEUTRAL_YELLOW`]</div>
+//!     <div style="background-color: #d79921; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NEUTRAL_BLUE`]</div>
+//!     <div style="background-color: #458588; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NEUTRAL_PURPLE`]</div>
+//!     <div style="background-color: #b16286; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NEUTRAL_AQUA`]</div>
+//!     <div style="background-color: #689d6a; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NEUTRAL_ORANGE`]</div>
+//!     <div style="background-color: #d65d0e; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`FADED_RED`]</div>
+//!     <div style="background-color: #9d0006; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`FADED_GREEN`]</div>
+//!     <div style="background-color: #79740e; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`FADED_YELLOW`]</div>
+//!     <div style="background-color: #b57614; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`FADED_BLUE`]</div>
+//!     <div style="background-color: #076678; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`FADED_PURPLE`]</div>
+//!     <div style="background-color: #8f3f71; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`FADED_AQUA`]</div>
+//!     <div style="background-color: #427b58; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`FADED_ORANGE`]</div>
+//!     <div style="background-color: #af3a03; width:22rem"></div>
+//! </div>
+//! </div>
+//! </div>
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_core::style::Color;
+//! use ratatui_core::style::palette::gruvbox::{BRIGHT_ORANGE, DARK0};
+//!
+//! assert_eq!(DARK0, Color::Rgb(40, 40, 40));
+//! assert_eq!(BRIGHT_ORANGE, Color::Rgb(254, 128, 25));
+//! ```
+
+use crate::style::Color;
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #1d2021"></div></div>
+pub const DARK0_HARD: Color = Color::from_u32(0x1d2021);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #282828"></div></div>
+pub const DARK0: Color = Color::from_u32(0x282828);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #32302f"></div></div>
+pub const DARK0_SOFT: Color = Color::from_u32(0x32302f);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #3c3836"></div></div>
+pub const DARK1: Color = Color::from_u32(0x3c3836);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #504945"></div></div>
+pub const DARK2: Color = Color::from_u32(0x504945);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #665c54"></div></div>
+pub const DARK3: Color = Color::from_u32(0x665c54);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #7c6f64"></div></div>
+pub const DARK4: Color = Color::from_u32(0x7c6f64);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #928374"></div></div>
+pub const GRAY: Color = Color::from_u32(0x928374);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #f9f5d7"></div></div>
+pub const LIGHT0_HARD: Color = Color::from_u32(0xf9f5d7);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #fbf1c7"></div></div>
+pub const LIGHT0: Color = Color::from_u32(0xfbf1c7);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #f2e5bc"></div></div>
+pub const LIGHT0_SOFT: Color = Color::from_u32(0xf2e5bc);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #ebdbb2"></div></div>
+pub const LIGHT1: Color = Color::from_u32(0xebdbb2);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #d5c4a1"></div></div>
+pub const LIGHT2: Color = Color::from_u32(0xd5c4a1);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #bdae93"></div></div>
+pub const LIGHT3: Color = Color::from_u32(0xbdae93);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #a89984"></div></div>
+pub const LIGHT4: Color = Color::from_u32(0xa89984);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #fb4934"></div></div>
+pub const BRIGHT_RED: Color = Color::from_u32(0xfb4934);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #b8bb26"></div></div>
+pub const BRIGHT_GREEN: Color = Color::from_u32(0xb8bb26);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #fabd2f"></div></div>
+pub const BRIGHT_YELLOW: Color = Color::from_u32(0xfabd2f);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #83a598"></div></div>
+pub const BRIGHT_BLUE: Color = Color::from_u32(0x83a598);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #d3869b"></div></div>
+pub const BRIGHT_PURPLE: Color = Color::from_u32(0xd3869b);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #8ec07c"></div></div>
+pub const BRIGHT_AQUA: Color = Color::from_u32(0x8ec07c);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #fe8019"></div></div>
+pub const BRIGHT_ORANGE: Color = Color::from_u32(0xfe8019);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #cc241d"></div></div>
+pub const NEUTRAL_RED: Color = Color::from_u32(0xcc241d);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #98971a"></div></div>
+pub const NEUTRAL_GREEN: Color = Color::from_u32(0x98971a);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #d79921"></div></div>
+pub const NEUTRAL_YELLOW: Color = Color::from_u32(0xd79921);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #458588"></div></div>
+pub const NEUTRAL_BLUE: Color = Color::from_u32(0x458588);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #b16286"></div></div>
+pub const NEUTRAL_PURPLE: Color = Color::from_u32(0xb16286);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #689d6a"></div></div>
+pub const NEUTRAL_AQUA: Color = Color::from_u32(0x689d6a);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #d65d0e"></div></div>
+pub const NEUTRAL_ORANGE: Color = Color::from_u32(0xd65d0e);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #9d0006"></div></div>
+pub const FADED_RED: Color = Color::from_u32(0x9d0006);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #79740e"></div></div>
+pub const FADED_GREEN: Color = Color::from_u32(0x79740e);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #b57614"></div></div>
+pub const FADED_YELLOW: Color = Color::from_u32(0xb57614);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #076678"></div></div>
+pub const FADED_BLUE: Color = Color::from_u32(0x076678);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #8f3f71"></div></div>
+pub const FADED_PURPLE: Color = Color::from_u32(0x8f3f71);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #427b58"></div></div>
+pub const FADED_AQUA: Color = Color::from_u32(0x427b58);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #af3a03"></div></div>
+pub const FADED_ORANGE: Color = Color::from_u32(0xaf3a03);
@@ -0,0 +1,221 @@
+//! Nord color palette.
+//!
+//! Represents the 16 colors of the [Nord] color scheme, in four groups: Polar Night ([`NORD0`] to
+//! [`NORD3`]) for backgrounds, Snow Storm ([`NORD4`] to [`NORD6`]) for text, Frost ([`NORD7`] to
+//! [`NORD10`]) for primary UI elements, and Aurora ([`NORD11`] to [`NORD15`]) for accents.
+//!
+//! [Nord]: https://www.nordtheme.com/docs/colors-and-palettes
+//!
+//! <style>
+//! .color { display: flex; align-items: center; }
+//! .color > div { width: 2rem; height: 2rem; }
+//! .color > div.name { width: 150px; !important; }
+//! </style>
+//! <div style="overflow-x: auto">
+//! <div style="display: flex; flex-direction:column; text-align: left">
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD0`]</div>
+//!     <div style="background-color: #2e3440; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD1`]</div>
+//!     <div style="background-color: #3b4252; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD2`]</div>
+//!     <div style="background-color: #434c5e; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD3`]</div>
+//!     <div style="background-color: #4c566a; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD4`]</div>
+//!     <div style="background-color: #d8dee9; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD5`]</div>
+//!     <div style="background-color: #e5e9f0; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD6`]</div>
+//!     <div style="background-color: #eceff4; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD7`]</div>
+//!     <div style="background-color: #8fbcbb; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD8`]</div>
+//!     <div style="background-color: #88c0d0; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD9`]</div>
+//!     <div style="background-color: #81a1c1; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD10`]</div>
+//!     <div style="background-color: #5e81ac; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD11`]</div>
+//!     <div style="background-color: #bf616a; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD12`]</div>
+//!     <div style="background-color: #d08770; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD13`]</div>
+//!     <div style="background-color: #ebcb8b; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD14`]</div>
+//!     <div style="background-color: #a3be8c; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`NORD15`]</div>
+//!     <div style="background-color: #b48ead; width:22rem"></div>
+//! </div>
+//! </div>
+//! </div>
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_core::style::Color;
+//! use ratatui_core::style::palette::nord::{NORD0, NORD8};
+//!
+//! assert_eq!(NORD0, Color::Rgb(46, 52, 64));
+//! assert_eq!(NORD8, Color::Rgb(136, 192, 208));
+//! ```
+
+use crate::style::Color;
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #2e3440"></div></div>
+///
+/// Polar Night: the background.
+pub const NORD0: Color = Color::from_u32(0x2e3440);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #3b4252"></div></div>
+///
+/// Polar Night: elevated elements like status bars and panels.
+pub const NORD1: Color = Color::from_u32(0x3b4252);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #434c5e"></div></div>
+///
+/// Polar Night: selections and active elements.
+pub const NORD2: Color = Color::from_u32(0x434c5e);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #4c566a"></div></div>
+///
+/// Polar Night: comments, guides and inactive elements.
+pub const NORD3: Color = Color::from_u32(0x4c566a);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #d8dee9"></div></div>
+///
+/// Snow Storm: the body text.
+pub const NORD4: Color = Color::from_u32(0xd8dee9);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #e5e9f0"></div></div>
+///
+/// Snow Storm: subtle highlights of text.
+pub const NORD5: Color = Color::from_u32(0xe5e9f0);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #eceff4"></div></div>
+///
+/// Snow Storm: emphasized text.
+pub const NORD6: Color = Color::from_u32(0xeceff4);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #8fbcbb"></div></div>
+///
+/// Frost: classes and types.
+pub const NORD7: Color = Color::from_u32(0x8fbcbb);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #88c0d0"></div></div>
+///
+/// Frost: the primary accent color.
+pub const NORD8: Color = Color::from_u32(0x88c0d0);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #81a1c1"></div></div>
+///
+/// Frost: secondary UI elements and keywords.
+pub const NORD9: Color = Color::from_u32(0x81a1c1);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #5e81ac"></div></div>
+///
+/// Frost: tertiary UI elements and pragmas.
+pub const NORD10: Color = Color::from_u32(0x5e81ac);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #bf616a"></div></div>
+///
+/// Aurora: red, for errors.
+pub const NORD11: Color = Color::from_u32(0xbf616a);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #d08770"></div></div>
+///
+/// Aurora: orange, for advanced functionality.
+pub const NORD12: Color = Color::from_u32(0xd08770);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #ebcb8b"></div></div>
+///
+/// Aurora: yellow, for warnings.
+pub const NORD13: Color = Color::from_u32(0xebcb8b);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #a3be8c"></div></div>
+///
+/// Aurora: green, for success.
+pub const NORD14: Color = Color::from_u32(0xa3be8c);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #b48ead"></div></div>
+///
+/// Aurora: purple, for numbers.
+pub const NORD15: Color = Color::from_u32(0xb48ead);
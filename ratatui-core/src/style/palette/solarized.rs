@@ -0,0 +1,205 @@
+//! Solarized color palette.
+//!
+//! Represents the 16 colors of the [Solarized] color scheme by Ethan Schoonover: 8 monotones from
+//! [`BASE03`] to [`BASE3`], and 8 accent colors. The dark mode uses [`BASE03`] as background and
+//! [`BASE0`] as body text, while the light mode uses [`BASE3`] and [`BASE00`].
+//!
+//! [Solarized]: https://ethanschoonover.com/solarized/
+//!
+//! <style>
+//! .color { display: flex; align-items: center; }
+//! .color > div { width: 2rem; height: 2rem; }
+//! .color > div.name { width: 150px; !important; }
+//! </style>
+//! <div style="overflow-x: auto">
+//! <div style="display: flex; flex-direction:column; text-align: left">
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BASE03`]</div>
+//!     <div style="background-color: #002b36; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BASE02`]</div>
+//!     <div style="background-color: #073642; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BASE01`]</div>
+//!     <div style="background-color: #586e75; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BASE00`]</div>
+//!     <div style="background-color: #657b83; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BASE0`]</div>
+//!     <div style="background-color: #839496; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BASE1`]</div>
+//!     <div style="background-color: #93a1a1; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BASE2`]</div>
+//!     <div style="background-color: #eee8d5; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BASE3`]</div>
+//!     <div style="background-color: #fdf6e3; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`YELLOW`]</div>
+//!     <div style="background-color: #b58900; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`ORANGE`]</div>
+//!     <div style="background-color: #cb4b16; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`RED`]</div>
+//!     <div style="background-color: #dc322f; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`MAGENTA`]</div>
+//!     <div style="background-color: #d33682; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`VIOLET`]</div>
+//!     <div style="background-color: #6c71c4; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`BLUE`]</div>
+//!     <div style="background-color: #268bd2; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`CYAN`]</div>
+//!     <div style="background-color: #2aa198; width:22rem"></div>
+//! </div>
+//! <div class="color">
+//!     <div class="name">
+//!
+//! [`GREEN`]</div>
+//!     <div style="background-color: #859900; width:22rem"></div>
+//! </div>
+//! </div>
+//! </div>
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui_core::style::Color;
+//! use ratatui_core::style::palette::solarized::{BASE03, BLUE};
+//!
+//! assert_eq!(BASE03, Color::Rgb(0, 43, 54));
+//! assert_eq!(BLUE, Color::Rgb(38, 139, 210));
+//! ```
+
+use crate::style::Color;
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #002b36"></div></div>
+///
+/// The background of the dark mode.
+pub const BASE03: Color = Color::from_u32(0x002b36);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #073642"></div></div>
+///
+/// Background highlights of the dark mode.
+pub const BASE02: Color = Color::from_u32(0x073642);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #586e75"></div></div>
+///
+/// Secondary content of the dark mode, and emphasized content of the light mode.
+pub const BASE01: Color = Color::from_u32(0x586e75);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #657b83"></div></div>
+///
+/// The body text of the light mode.
+pub const BASE00: Color = Color::from_u32(0x657b83);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #839496"></div></div>
+///
+/// The body text of the dark mode.
+pub const BASE0: Color = Color::from_u32(0x839496);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #93a1a1"></div></div>
+///
+/// Emphasized content of the dark mode, and secondary content of the light mode.
+pub const BASE1: Color = Color::from_u32(0x93a1a1);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #eee8d5"></div></div>
+///
+/// Background highlights of the light mode.
+pub const BASE2: Color = Color::from_u32(0xeee8d5);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #fdf6e3"></div></div>
+///
+/// The background of the light mode.
+pub const BASE3: Color = Color::from_u32(0xfdf6e3);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #b58900"></div></div>
+pub const YELLOW: Color = Color::from_u32(0xb58900);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #cb4b16"></div></div>
+pub const ORANGE: Color = Color::from_u32(0xcb4b16);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #dc322f"></div></div>
+pub const RED: Color = Color::from_u32(0xdc322f);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #d33682"></div></div>
+pub const MAGENTA: Color = Color::from_u32(0xd33682);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #6c71c4"></div></div>
+pub const VIOLET: Color = Color::from_u32(0x6c71c4);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #268bd2"></div></div>
+pub const BLUE: Color = Color::from_u32(0x268bd2);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #2aa198"></div></div>
+pub const CYAN: Color = Color::from_u32(0x2aa198);
+
+#[rustfmt::skip]
+/// <style>.palette div{width:22rem;height:2rem}</style><div class="palette" style="display:flex;flex-direction:row"><div style="background-color: #859900"></div></div>
+pub const GREEN: Color = Color::from_u32(0x859900);
@@ -0,0 +1,84 @@
+//! The default 256 color palette of xterm.
+//!
+//! Terminals let users change the colors of the 16 named colors (and some also the 240 other
+//! [indexed colors]), so the actual colors displayed for [`Color::Red`] or [`Color::Indexed`] are
+//! not known. This palette is the default palette of [xterm], which is used when RGB values are
+//! needed for these colors, e.g. by [`Color::to_rgb`] and the color manipulation methods of
+//! [`Color`].
+//!
+//! The palette consists of:
+//!
+//! - the 16 named colors (indexes 0 to 15), with the values used by xterm
+//! - a 6×6×6 color cube (indexes 16 to 231), with the levels 0, 95, 135, 175, 215 and 255 for each
+//!   component
+//! - a grayscale ramp of 24 shades (indexes 232 to 255), from `#080808` to `#EEEEEE`
+//!
+//! [indexed colors]: https://en.wikipedia.org/wiki/ANSI_escape_code#8-bit
+//! [xterm]: https://invisible-island.net/xterm/
+//!
+//! # Examples
+//!
+//! ```
+//! use ratatui_core::style::Color;
+//! use ratatui_core::style::palette::xterm;
+//!
+//! assert_eq!(xterm::COLORS[1], Color::Rgb(205, 0, 0));
+//! assert_eq!(xterm::COLORS[196], Color::Rgb(255, 0, 0));
+//! assert_eq!(xterm::COLORS[244], Color::Rgb(128, 128, 128));
+//! ```
+
+use crate::style::Color;
+
+/// The levels of each component of the colors in the 6×6×6 color cube
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The 16 named colors of xterm
+const NAMED: [u32; 16] = [
+    0x000000, 0xcd0000, 0x00cd00, 0xcdcd00, 0x0000ee, 0xcd00cd, 0x00cdcd, 0xe5e5e5, 0x7f7f7f,
+    0xff0000, 0x00ff00, 0xffff00, 0x5c5cff, 0xff00ff, 0x00ffff, 0xffffff,
+];
+
+/// The 256 colors of the default xterm palette, indexed like [`Color::Indexed`].
+///
+/// All the colors are [`Color::Rgb`] colors.
+pub const COLORS: [Color; 256] = {
+    let mut colors = [Color::Reset; 256];
+    let mut index = 0;
+    while index < 256 {
+        colors[index] = match index {
+            0..16 => Color::from_u32(NAMED[index]),
+            16..232 => {
+                let cube = index - 16;
+                Color::Rgb(
+                    CUBE_LEVELS[cube / 36],
+                    CUBE_LEVELS[cube / 6 % 6],
+                    CUBE_LEVELS[cube % 6],
+                )
+            }
+            _ => {
+                let level = 8 + 10 * (index - 232) as u8;
+                Color::Rgb(level, level, level)
+            }
+        };
+        index += 1;
+    }
+    colors
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors() {
+        assert_eq!(COLORS[0], Color::Rgb(0, 0, 0));
+        assert_eq!(COLORS[12], Color::Rgb(0x5c, 0x5c, 0xff));
+        assert_eq!(COLORS[15], Color::Rgb(255, 255, 255));
+        assert_eq!(COLORS[16], Color::Rgb(0, 0, 0));
+        assert_eq!(COLORS[17], Color::Rgb(0, 0, 95));
+        assert_eq!(COLORS[67], Color::Rgb(95, 135, 175));
+        assert_eq!(COLORS[231], Color::Rgb(255, 255, 255));
+        assert_eq!(COLORS[232], Color::Rgb(8, 8, 8));
+        assert_eq!(COLORS[255], Color::Rgb(238, 238, 238));
+    }
+}
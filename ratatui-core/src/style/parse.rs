@@ -0,0 +1,428 @@
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+
+use thiserror::Error;
+
+use crate::style::{Color, Modifier, Style};
+
+/// Error type indicating a failure to parse a style string.
+///
+/// The error contains the [kind](ParseStyleErrorKind) of error and the byte offset of the word
+/// in the input at which it occurred, so that it can be reported to whoever wrote the style (e.g.
+/// in a configuration file).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
+#[error("{kind} at position {position}")]
+pub struct ParseStyleError {
+    /// The kind of error.
+    pub kind: ParseStyleErrorKind,
+    /// The byte offset in the input at which the error occurred.
+    pub position: usize,
+}
+
+/// The kind of a [`ParseStyleError`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
+pub enum ParseStyleErrorKind {
+    /// A word is neither a color nor a modifier.
+    #[error("unknown style `{0}`")]
+    UnknownStyle(String),
+    /// `on` is not followed by a color.
+    #[error("expected a background color after `on`")]
+    MissingColor,
+    /// `not` is not followed by a modifier.
+    #[error("expected a modifier after `not`")]
+    MissingModifier,
+}
+
+impl ParseStyleError {
+    const fn new(kind: ParseStyleErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+
+    fn unknown(word: &str, position: usize) -> Self {
+        Self::new(
+            ParseStyleErrorKind::UnknownStyle(word.to_string()),
+            position,
+        )
+    }
+}
+
+/// Parses a style from a list of words separated by whitespace.
+///
+/// This is a human readable format for styles, e.g. for themes stored in configuration files:
+///
+/// - a color sets the foreground color, e.g. `red`, `light-blue`, `#ff8800` or `208` (see [`Color`]
+///   for all the supported formats),
+/// - `on` followed by a color sets the background color, e.g. `on blue`,
+/// - a modifier adds the modifier, e.g. `bold`, `italic` or `underlined`. The abbreviations `b`,
+///   `i`, `u` and `s` (for `crossed_out`) are also supported,
+/// - `not` followed by a modifier removes the modifier, e.g. `not bold`,
+/// - `underline:` (or `ul:`) followed by a color sets the underline color, e.g. `underline:red`
+///   (requires the `underline-color` feature).
+///
+/// Words are case-insensitive. When a color is given more than once, the last one is used. An
+/// empty string is the default style. The [`Display`](fmt::Display) implementation of [`Style`]
+/// writes this format, so that styles round-trip.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::style::{Color, ParseStyleErrorKind, Style, Stylize};
+///
+/// let style: Style = "bold italic #ff8800 on blue".parse()?;
+/// assert_eq!(
+///     style,
+///     Style::new()
+///         .bold()
+///         .italic()
+///         .fg(Color::Rgb(255, 136, 0))
+///         .on_blue()
+/// );
+/// assert_eq!(style.to_string().parse(), Ok(style));
+///
+/// let error = "bold blink".parse::<Style>().unwrap_err();
+/// assert_eq!(
+///     error.kind,
+///     ParseStyleErrorKind::UnknownStyle("blink".into())
+/// );
+/// assert_eq!(error.position, 5);
+/// # Ok::<(), ratatui_core::style::ParseStyleError>(())
+/// ```
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s
+            .split_whitespace()
+            .map(|word| (word, word.as_ptr() as usize - s.as_ptr() as usize));
+        let mut style = Self::new();
+        while let Some((word, position)) = words.next() {
+            match word.to_lowercase().as_str() {
+                "on" => {
+                    let error = ParseStyleError::new(ParseStyleErrorKind::MissingColor, position);
+                    let (color, position) = words.next().ok_or(error)?;
+                    style = style.bg(parse_color(color, position)?);
+                }
+                "not" => {
+                    let error =
+                        ParseStyleError::new(ParseStyleErrorKind::MissingModifier, position);
+                    let (modifier, position) = words.next().ok_or(error)?;
+                    let modifier = parse_modifier(modifier)
+                        .ok_or_else(|| ParseStyleError::unknown(modifier, position))?;
+                    style = style.remove_modifier(modifier);
+                }
+                #[cfg(feature = "underline-color")]
+                lower if lower.starts_with("underline:") || lower.starts_with("ul:") => {
+                    let (_, color) = word.split_once(':').unwrap_or_default();
+                    let color = color
+                        .parse()
+                        .ok()
+                        .ok_or_else(|| ParseStyleError::unknown(word, position))?;
+                    style = style.underline_color(color);
+                }
+                _ => {
+                    if let Some(modifier) = parse_modifier(word) {
+                        style = style.add_modifier(modifier);
+                    } else {
+                        style = style.fg(parse_color(word, position)?);
+                    }
+                }
+            }
+        }
+        Ok(style)
+    }
+}
+
+fn parse_color(word: &str, position: usize) -> Result<Color, ParseStyleError> {
+    word.parse()
+        .ok()
+        .ok_or_else(|| ParseStyleError::unknown(word, position))
+}
+
+fn parse_modifier(word: &str) -> Option<Modifier> {
+    let modifier = match word.to_lowercase().replace('-', "_").as_str() {
+        "b" | "bold" => Modifier::BOLD,
+        "dim" => Modifier::DIM,
+        "i" | "italic" => Modifier::ITALIC,
+        "u" | "underline" | "underlined" => Modifier::UNDERLINED,
+        "slow_blink" => Modifier::SLOW_BLINK,
+        "rapid_blink" => Modifier::RAPID_BLINK,
+        "reverse" | "reversed" => Modifier::REVERSED,
+        "hidden" => Modifier::HIDDEN,
+        "s" | "strike" | "crossed_out" => Modifier::CROSSED_OUT,
+        _ => return None,
+    };
+    Some(modifier)
+}
+
+/// Writes the style in the format parsed by [`Style::from_str`].
+///
+/// The foreground color is written first, followed by the background color, the underline color,
+/// the added modifiers and the removed modifiers, e.g. `red on black bold not italic`. The default
+/// style is written as an empty string.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::style::{Style, Stylize};
+///
+/// let style = Style::new().red().on_black().bold().not_italic();
+/// assert_eq!(style.to_string(), "red on black bold not italic");
+/// assert_eq!(Style::new().to_string(), "");
+/// ```
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        let mut write_word = |f: &mut fmt::Formatter<'_>, args: fmt::Arguments| {
+            f.write_str(separator)?;
+            separator = " ";
+            f.write_fmt(args)
+        };
+        if let Some(fg) = self.fg {
+            write_word(f, format_args!("{}", LowercaseColor(fg)))?;
+        }
+        if let Some(bg) = self.bg {
+            write_word(f, format_args!("on {}", LowercaseColor(bg)))?;
+        }
+        #[cfg(feature = "underline-color")]
+        if let Some(color) = self.underline_color {
+            write_word(f, format_args!("underline:{}", LowercaseColor(color)))?;
+        }
+        for (name, _) in self.add_modifier.iter_names() {
+            write_word(f, format_args!("{}", name.to_lowercase()))?;
+        }
+        for (name, _) in self.sub_modifier.iter_names() {
+            write_word(f, format_args!("not {}", name.to_lowercase()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Displays a color in lowercase, e.g. `lightblue` or `#ff8800`.
+struct LowercaseColor(Color);
+
+impl fmt::Display for LowercaseColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.to_string().to_lowercase())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Style {
+    /// Deserializes a style from either its string form (see [`Style::from_str`]) or its struct
+    /// form, which is the form that styles are serialized to.
+    ///
+    /// The string form is only supported by self-describing formats such as JSON, YAML or TOML.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Style, Stylize};
+    ///
+    /// #[derive(Debug, serde::Deserialize)]
+    /// struct Theme {
+    ///     title: Style,
+    ///     border: Style,
+    /// }
+    ///
+    /// let theme: Theme = serde_json::from_str(
+    ///     r#"{
+    ///         "title": "bold yellow on blue",
+    ///         "border": { "fg": "DarkGray", "add_modifier": "DIM" }
+    ///     }"#,
+    /// )?;
+    /// assert_eq!(theme.title, Style::new().bold().yellow().on_blue());
+    /// assert_eq!(theme.border, Style::new().dark_gray().dim());
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // the string form can only be detected in self-describing formats
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(StyleVisitor)
+        } else {
+            StyleFields::deserialize(deserializer).map(Self::from)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct StyleVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for StyleVisitor {
+    type Value = Style;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a style string or a style struct")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        use serde::Deserialize;
+
+        let deserializer = serde::de::value::MapAccessDeserializer::new(map);
+        StyleFields::deserialize(deserializer).map(Style::from)
+    }
+}
+
+/// The struct form of a [`Style`], as written by its `Serialize` implementation.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct StyleFields {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    #[cfg(feature = "underline-color")]
+    underline_color: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_modifier")]
+    add_modifier: Modifier,
+    #[serde(default, deserialize_with = "deserialize_modifier")]
+    sub_modifier: Modifier,
+}
+
+#[cfg(feature = "serde")]
+impl From<StyleFields> for Style {
+    fn from(fields: StyleFields) -> Self {
+        Self {
+            fg: fields.fg,
+            bg: fields.bg,
+            #[cfg(feature = "underline-color")]
+            underline_color: fields.underline_color,
+            add_modifier: fields.add_modifier,
+            sub_modifier: fields.sub_modifier,
+        }
+    }
+}
+
+/// Deserialize a [`Modifier`] while treating missing or `null` values as empty.
+///
+/// This helper is used with serde to coerce absent or `null` modifier fields to
+/// [`Modifier::empty`], allowing configuration files to omit these fields
+/// without triggering deserialization errors.
+#[cfg(feature = "serde")]
+fn deserialize_modifier<'de, D>(deserializer: D) -> Result<Modifier, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::Deserialize;
+
+    Option::<Modifier>::deserialize(deserializer)
+        .map(|modifier| modifier.unwrap_or_else(Modifier::empty))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::empty("", Style::new())]
+    #[case::whitespace(" \t ", Style::new())]
+    #[case::named("Light-Blue", Style::new().light_blue())]
+    #[case::hex("#ff8000", Style::new().fg(Color::Rgb(255, 128, 0)))]
+    #[case::indexed("208", Style::new().fg(Color::Indexed(208)))]
+    #[case::background("on red", Style::new().on_red())]
+    #[case::abbreviations("b i u s", Style::new().bold().italic().underlined().crossed_out())]
+    #[case::modifiers(
+        "dim slow-blink rapid_blink reversed hidden",
+        Style::new().dim().slow_blink().rapid_blink().reversed().hidden()
+    )]
+    #[case::remove_modifier("not italic", Style::new().not_italic())]
+    #[case::last_color_wins("red blue", Style::new().blue())]
+    #[case::case_insensitive("BOLD On Red", Style::new().bold().on_red())]
+    #[case::extra_whitespace("  bold\t\nred ", Style::new().bold().red())]
+    #[case::full(
+        "bold italic #ff8800 on blue not dim",
+        Style::new().bold().italic().fg(Color::Rgb(255, 136, 0)).on_blue().not_dim()
+    )]
+    fn from_str(#[case] input: &str, #[case] expected: Style) {
+        assert_eq!(input.parse(), Ok(expected));
+    }
+
+    #[cfg(feature = "underline-color")]
+    #[rstest]
+    #[case::long("underline underline:red", Style::new().underlined().underline_color(Color::Red))]
+    #[case::short("ul:#010203", Style::new().underline_color(Color::Rgb(1, 2, 3)))]
+    fn from_str_underline_color(#[case] input: &str, #[case] expected: Style) {
+        assert_eq!(input.parse(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::unknown_style("bold blink", ParseStyleErrorKind::UnknownStyle("blink".into()), 5)]
+    #[case::unknown_background("on sky", ParseStyleErrorKind::UnknownStyle("sky".into()), 3)]
+    #[case::missing_color("red on", ParseStyleErrorKind::MissingColor, 4)]
+    #[case::missing_modifier("not", ParseStyleErrorKind::MissingModifier, 0)]
+    #[case::unknown_modifier("not red", ParseStyleErrorKind::UnknownStyle("red".into()), 4)]
+    #[case::unicode_whitespace("bold\u{3000}blink", ParseStyleErrorKind::UnknownStyle("blink".into()), 7)]
+    fn from_str_errors(
+        #[case] input: &str,
+        #[case] kind: ParseStyleErrorKind,
+        #[case] position: usize,
+    ) {
+        assert_eq!(
+            input.parse::<Style>(),
+            Err(ParseStyleError { kind, position })
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        let error = "red on".parse::<Style>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected a background color after `on` at position 4"
+        );
+    }
+
+    #[rstest]
+    #[case::empty(Style::new(), "")]
+    #[case::colors(Style::new().light_blue().on_black(), "lightblue on black")]
+    #[case::rgb_and_indexed(Style::new().fg(Color::Rgb(255, 136, 0)).bg(Color::Indexed(42)), "#ff8800 on 42")]
+    #[case::modifiers(Style::new().bold().slow_blink().not_italic(), "bold slow_blink not italic")]
+    #[case::reset(Style::new().fg(Color::Reset).not_bold().not_dim(), "reset not bold not dim")]
+    fn display(#[case] style: Style, #[case] expected: &str) {
+        assert_eq!(style.to_string(), expected);
+        assert_eq!(expected.parse(), Ok(style));
+    }
+
+    #[cfg(feature = "underline-color")]
+    #[test]
+    fn display_underline_color() {
+        let style = Style::new().underlined().underline_color(Color::Red);
+        assert_eq!(style.to_string(), "underline:red underlined");
+        assert_eq!(style.to_string().parse(), Ok(style));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_string() {
+        let style: Style = serde_json::from_str(r#""bold #ff8800 on blue""#).unwrap();
+        assert_eq!(
+            style,
+            Style::new().bold().fg(Color::Rgb(255, 136, 0)).on_blue()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_string_error() {
+        let error = serde_json::from_str::<Style>(r#""bold blink""#).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unknown style `blink` at position 5 at line 1 column 12"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_struct_unknown_field() {
+        let style: Style = serde_json::from_str(r#"{"fg": "Red", "extra": 1}"#).unwrap();
+        assert_eq!(style, Style::new().red());
+    }
+}
@@ -0,0 +1,549 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::sync::Arc;
+
+use crate::style::Style;
+
+/// Names of the roles used by the built-in widgets, and of common semantic roles.
+///
+/// Roles are dot-separated paths, and a role inherits the style of its parent role (see
+/// [`Theme::style`]). Applications can use any other role name for their own widgets.
+pub mod roles {
+    /// The base style of a [`Block`](https://docs.rs/ratatui/latest/ratatui/widgets/struct.Block.html).
+    pub const BLOCK: &str = "block";
+    /// The style of the borders of a block.
+    pub const BLOCK_BORDER: &str = "block.border";
+    /// The style of the titles of a block.
+    pub const BLOCK_TITLE: &str = "block.title";
+    /// The base style of a `Gauge` and a `LineGauge`.
+    pub const GAUGE: &str = "gauge";
+    /// The style of the filled part of a gauge.
+    pub const GAUGE_BAR: &str = "gauge.bar";
+    /// The base style of a `List`.
+    pub const LIST: &str = "list";
+    /// The style of the selected item of a list.
+    pub const LIST_HIGHLIGHT: &str = "list.highlight";
+    /// The base style of a `Paragraph`.
+    pub const PARAGRAPH: &str = "paragraph";
+    /// The base style of a `Table`.
+    pub const TABLE: &str = "table";
+    /// The style of the header row of a table.
+    pub const TABLE_HEADER: &str = "table.header";
+    /// The style of the selected row of a table.
+    pub const TABLE_HIGHLIGHT: &str = "table.highlight";
+    /// The base style of `Tabs`.
+    pub const TABS: &str = "tabs";
+    /// The style of the selected tab.
+    pub const TABS_HIGHLIGHT: &str = "tabs.highlight";
+    /// The style of error messages.
+    pub const ERROR: &str = "error";
+    /// The style of warning messages.
+    pub const WARNING: &str = "warning";
+    /// The style of informational messages.
+    pub const INFO: &str = "info";
+    /// The style of success messages.
+    pub const SUCCESS: &str = "success";
+}
+
+/// A set of styles for named roles, used to change the look of an application in one place.
+///
+/// A theme maps semantic roles (e.g. `block.border`, `list.highlight` or `error`) to [`Style`]s.
+/// Roles are dot-separated paths, and each role inherits the style of its parent role, so
+/// `list.highlight` is the style of `list` [patched](Style::patch) with the style of
+/// `list.highlight`. The empty role `""` is the parent of all the top-level roles. The names of
+/// the roles used by the built-in widgets are defined in the [`roles`] module.
+///
+/// With the `std` feature, a theme can be made the active theme of the application with
+/// [`Theme::set_global`]. The built-in widgets of `ratatui-widgets` use the styles of the active
+/// theme as defaults: the styles set on a widget are patched onto the styles of the theme, so
+/// explicitly set styles take precedence. The parts of a widget that are drawn over its base style
+/// (e.g. the borders of a block) use the style of their role without the inherited styles, see
+/// [`Theme::active_role_style`].
+///
+/// # Serialization
+///
+/// With the `serde` feature, a theme can be loaded from configuration files. A style is written
+/// either in the string form parsed by [`Style::from_str`](core::str::FromStr::from_str) or in its
+/// struct form. Roles can be written as dotted keys or as nested tables, in which case the style
+/// fields (`fg`, `bg`, `underline_color`, `add_modifier` and `sub_modifier`) of a table are the
+/// style of the table's role itself:
+///
+/// ```toml
+/// fg = "white"
+/// error = "bold red"
+///
+/// [block]
+/// border = "dark-gray"
+/// title = "bold"
+///
+/// [list]
+/// bg = "black"
+/// highlight = "black on yellow"
+/// ```
+///
+/// Themes are serialized as a flat map from role names to styles in string form.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::style::{Style, Stylize, Theme, roles};
+///
+/// let theme = Theme::new()
+///     .with_style(roles::LIST, Style::new().white().on_black())
+///     .with_style(roles::LIST_HIGHLIGHT, Style::new().black().on_yellow())
+///     .with_style(roles::ERROR, Style::new().red().bold());
+///
+/// assert_eq!(theme.style(roles::LIST), Style::new().white().on_black());
+/// assert_eq!(
+///     theme.style(roles::LIST_HIGHLIGHT),
+///     Style::new().black().on_yellow()
+/// );
+/// // roles without a style inherit the style of their parent
+/// assert_eq!(theme.style("list.item"), Style::new().white().on_black());
+/// assert_eq!(theme.style("error.details"), Style::new().red().bold());
+/// assert_eq!(theme.style(roles::BLOCK_BORDER), Style::new());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Theme {
+    styles: BTreeMap<String, Style>,
+}
+
+impl Theme {
+    /// Creates an empty theme.
+    pub const fn new() -> Self {
+        Self {
+            styles: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the style of a role, replacing any previous style of the role.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_style<R: Into<String>, S: Into<Style>>(mut self, role: R, style: S) -> Self {
+        self.set_style(role, style);
+        self
+    }
+
+    /// Sets the style of a role, replacing any previous style of the role.
+    pub fn set_style<R: Into<String>, S: Into<Style>>(&mut self, role: R, style: S) {
+        self.styles.insert(role.into(), style.into());
+    }
+
+    /// Returns the style that was set for a role, without the styles of its parent roles.
+    pub fn get(&self, role: &str) -> Option<Style> {
+        self.styles.get(role).copied()
+    }
+
+    /// Returns the style of a role, including the styles inherited from its parent roles.
+    ///
+    /// The styles of the empty role, of the parent roles and of the role itself are patched in
+    /// this order, e.g. `""`, `table`, `table.header` and `table.header.cell` for
+    /// `table.header.cell`. Roles without a style are skipped, so a role that is not in the theme
+    /// falls back to its closest parent.
+    pub fn style(&self, role: &str) -> Style {
+        let mut style = self.get("").unwrap_or_default();
+        if role.is_empty() {
+            return style;
+        }
+        let ends = role
+            .match_indices('.')
+            .map(|(index, _)| index)
+            .chain([role.len()]);
+        for end in ends {
+            if let Some(parent) = role.get(..end).and_then(|parent| self.get(parent)) {
+                style = style.patch(parent);
+            }
+        }
+        style
+    }
+
+    /// Returns an iterator over the roles of the theme and their styles, ordered by role name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Style)> {
+        self.styles
+            .iter()
+            .map(|(role, style)| (role.as_str(), *style))
+    }
+
+    /// Returns true if the theme has no styles.
+    pub fn is_empty(&self) -> bool {
+        self.styles.is_empty()
+    }
+
+    /// Returns the style of a role in the active theme.
+    ///
+    /// This is the style returned by [`Theme::style`] for the theme set with
+    /// [`Theme::set_global`], or the default style if no theme is active or without the `std`
+    /// feature. The built-in widgets use this method to get their default styles.
+    pub fn active_style(role: &str) -> Style {
+        global::get().map_or_else(Style::new, |theme| theme.style(role))
+    }
+
+    /// Returns the style that was set for a role in the active theme, without the styles of its
+    /// parent roles.
+    ///
+    /// The built-in widgets use this method for the parts of a widget that are drawn over the base
+    /// style of the widget (e.g. the borders of a block or the selected item of a list), as the
+    /// styles of the parent roles are already included in the base style. This way, the styles
+    /// set on the widget are not overridden by the parent styles of the theme.
+    pub fn active_role_style(role: &str) -> Style {
+        global::get()
+            .and_then(|theme| theme.get(role))
+            .unwrap_or_default()
+    }
+
+    /// Makes this theme the active theme of the application.
+    ///
+    /// The active theme provides the default styles of the built-in widgets (see
+    /// [`Theme::active_style`]). Replacing the active theme (e.g. to switch from a dark theme to
+    /// a light one) takes effect the next time the widgets are rendered.
+    ///
+    /// This method is only available with the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn set_global(self) {
+        global::set(Some(self));
+    }
+
+    /// Returns the active theme of the application, if any.
+    ///
+    /// This method is only available with the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn global() -> Option<Arc<Self>> {
+        global::get()
+    }
+
+    /// Removes the active theme of the application, so that the built-in widgets use their
+    /// default styles again.
+    ///
+    /// This method is only available with the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn clear_global() {
+        global::set(None);
+    }
+}
+
+/// The active theme, which is only supported with the `std` feature.
+mod global {
+    use alloc::sync::Arc;
+    #[cfg(feature = "std")]
+    use core::sync::atomic::{AtomicBool, Ordering};
+    #[cfg(feature = "std")]
+    use std::sync::{PoisonError, RwLock};
+
+    use super::Theme;
+
+    /// Whether there is an active theme, which avoids taking the lock in the common case
+    #[cfg(feature = "std")]
+    static IS_SET: AtomicBool = AtomicBool::new(false);
+
+    #[cfg(feature = "std")]
+    static ACTIVE: RwLock<Option<Arc<Theme>>> = RwLock::new(None);
+
+    #[cfg(feature = "std")]
+    pub fn get() -> Option<Arc<Theme>> {
+        if !IS_SET.load(Ordering::Acquire) {
+            return None;
+        }
+        ACTIVE
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub const fn get() -> Option<Arc<Theme>> {
+        None
+    }
+
+    #[cfg(feature = "std")]
+    pub fn set(theme: Option<Theme>) {
+        let is_set = theme.is_some();
+        *ACTIVE.write().unwrap_or_else(PoisonError::into_inner) = theme.map(Arc::new);
+        IS_SET.store(is_set, Ordering::Release);
+    }
+}
+
+impl<R: Into<String>, S: Into<Style>> FromIterator<(R, S)> for Theme {
+    fn from_iter<I: IntoIterator<Item = (R, S)>>(iter: I) -> Self {
+        let mut theme = Self::new();
+        theme.extend(iter);
+        theme
+    }
+}
+
+impl<R: Into<String>, S: Into<Style>> Extend<(R, S)> for Theme {
+    fn extend<I: IntoIterator<Item = (R, S)>>(&mut self, iter: I) {
+        for (role, style) in iter {
+            self.set_style(role, style);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Theme {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.styles.len()))?;
+        for (role, style) in &self.styles {
+            map.serialize_entry(role, &alloc::string::ToString::to_string(style))?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Theme {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut theme = Self::new();
+        deserializer.deserialize_map(de::GroupVisitor {
+            theme: &mut theme,
+            prefix: "",
+        })?;
+        Ok(theme)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod de {
+    use alloc::format;
+    use alloc::string::String;
+    use core::fmt;
+
+    use serde::de::{self, DeserializeSeed, MapAccess, Visitor};
+
+    use super::Theme;
+    use crate::style::{Color, Modifier, Style};
+
+    /// Returns the name of the role `key` in the group of roles `prefix`.
+    fn role(prefix: &str, key: &str) -> String {
+        if prefix.is_empty() {
+            String::from(key)
+        } else {
+            format!("{prefix}.{key}")
+        }
+    }
+
+    /// Deserializes a table of roles, adding the styles to the theme.
+    pub struct GroupVisitor<'t, 'p> {
+        pub theme: &'t mut Theme,
+        pub prefix: &'p str,
+    }
+
+    impl<'de> Visitor<'de> for GroupVisitor<'_, '_> {
+        type Value = ();
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a map of roles to styles")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+            let mut style = None::<Style>;
+            while let Some(key) = map.next_key::<String>()? {
+                let field = style.get_or_insert_default();
+                match key.as_str() {
+                    "fg" => field.fg = Some(map.next_value::<Color>()?),
+                    "bg" => field.bg = Some(map.next_value::<Color>()?),
+                    #[cfg(feature = "underline-color")]
+                    "underline_color" => field.underline_color = Some(map.next_value::<Color>()?),
+                    "add_modifier" => field.add_modifier = map.next_value::<Modifier>()?,
+                    "sub_modifier" => field.sub_modifier = map.next_value::<Modifier>()?,
+                    _ => {
+                        if field == &Style::new() {
+                            style = None;
+                        }
+                        let role = role(self.prefix, &key);
+                        map.next_value_seed(EntrySeed {
+                            theme: self.theme,
+                            role: &role,
+                        })?;
+                    }
+                }
+            }
+            if let Some(style) = style {
+                self.theme.set_style(self.prefix, style);
+            }
+            Ok(())
+        }
+    }
+
+    /// Deserializes the value of a role: a style string, or a table of style fields and roles.
+    struct EntrySeed<'t, 'r> {
+        theme: &'t mut Theme,
+        role: &'r str,
+    }
+
+    impl<'de> DeserializeSeed<'de> for EntrySeed<'_, '_> {
+        type Value = ();
+
+        fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+            deserializer.deserialize_any(self)
+        }
+    }
+
+    impl<'de> Visitor<'de> for EntrySeed<'_, '_> {
+        type Value = ();
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a style string or a table of styles")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<(), E> {
+            let style: Style = value.parse().map_err(|error| {
+                E::custom(format!("invalid style for `{}`: {error}", self.role))
+            })?;
+            self.theme.set_style(self.role, style);
+            Ok(())
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<(), A::Error> {
+            GroupVisitor {
+                theme: self.theme,
+                prefix: self.role,
+            }
+            .visit_map(map)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Color;
+
+    fn theme() -> Theme {
+        Theme::from_iter([
+            ("", Style::new().white()),
+            ("list", Style::new().on_black().italic()),
+            ("list.highlight", Style::new().black().on_yellow()),
+            ("list.highlight.marker", Style::new().not_italic()),
+            ("error", Style::new().red().bold()),
+        ])
+    }
+
+    #[rstest]
+    #[case::root("", Style::new().white())]
+    #[case::unknown("block.border", Style::new().white())]
+    #[case::top_level("list", Style::new().white().on_black().italic())]
+    #[case::nested("list.highlight", Style::new().black().on_yellow().italic())]
+    #[case::deeply_nested("list.highlight.marker", Style::new().black().on_yellow().not_italic())]
+    #[case::fallback("error.details.more", Style::new().red().bold())]
+    #[case::similar_name("lists", Style::new().white())]
+    fn style(#[case] role: &str, #[case] expected: Style) {
+        assert_eq!(theme().style(role), expected);
+    }
+
+    #[test]
+    fn get() {
+        let theme = theme();
+        assert_eq!(
+            theme.get("list.highlight"),
+            Some(Style::new().black().on_yellow())
+        );
+        assert_eq!(theme.get("list.item"), None);
+    }
+
+    #[test]
+    fn empty_theme() {
+        let theme = Theme::new();
+        assert!(theme.is_empty());
+        assert_eq!(theme.style("list"), Style::new());
+    }
+
+    #[test]
+    fn with_style_replaces() {
+        let theme = Theme::new()
+            .with_style("error", Color::Red)
+            .with_style("error", Color::LightRed);
+        assert_eq!(
+            theme.iter().collect::<Vec<_>>(),
+            [("error", Style::new().light_red())]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_json() {
+        let theme: Theme = serde_json::from_str(
+            r#"{
+                "fg": "White",
+                "error": "bold red",
+                "block.border": "dark-gray",
+                "list": {
+                    "bg": "Black",
+                    "highlight": { "fg": "Black", "bg": "Yellow", "add_modifier": "BOLD" }
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            theme,
+            Theme::from_iter([
+                ("", Style::new().white()),
+                ("error", Style::new().bold().red()),
+                ("block.border", Style::new().dark_gray()),
+                ("list", Style::new().on_black()),
+                ("list.highlight", Style::new().black().on_yellow().bold()),
+            ])
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_toml() {
+        let theme: Theme = toml::from_str(
+            r#"
+            error = "bold red"
+
+            [block]
+            border = "dark-gray"
+            title = "bold"
+
+            [list]
+            bg = "black"
+            highlight = "black on yellow"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            theme,
+            Theme::from_iter([
+                ("error", Style::new().bold().red()),
+                ("block.border", Style::new().dark_gray()),
+                ("block.title", Style::new().bold()),
+                ("list", Style::new().on_black()),
+                ("list.highlight", Style::new().black().on_yellow()),
+            ])
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_error() {
+        use alloc::string::ToString;
+
+        let error =
+            serde_json::from_str::<Theme>(r#"{"list": {"highlight": "bold blink"}}"#).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid style for `list.highlight`: unknown style `blink` at position 5 at line 1 \
+             column 35"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_round_trip() {
+        let theme = theme();
+        let json = serde_json::to_string(&theme).unwrap();
+        assert_eq!(
+            json,
+            r#"{"":"white","error":"red bold","list":"on black italic","list.highlight":"black on yellow","list.highlight.marker":"not italic"}"#
+        );
+        assert_eq!(serde_json::from_str::<Theme>(&json).unwrap(), theme);
+    }
+}
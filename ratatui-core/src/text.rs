@@ -48,17 +48,52 @@
 //! ]);
 //! ```
 
+#[cfg(feature = "ansi")]
+mod ansi;
+#[cfg(feature = "ansi")]
+pub use ansi::AnsiParser;
+
+#[cfg(feature = "unicode-bidi")]
+mod bidi;
+
+mod columns;
+
 mod grapheme;
 pub use grapheme::StyledGrapheme;
 
+mod highlight;
+pub use highlight::{SearchPattern, TextMatch};
+
 mod line;
 pub use line::{Line, ToLine};
 
+#[cfg(feature = "markdown")]
+mod markdown;
+#[cfg(feature = "markdown")]
+pub use markdown::MarkdownStyleSheet;
+
+#[cfg(feature = "markup")]
+mod markup;
+#[cfg(feature = "markup")]
+pub use markup::{MarkupError, MarkupErrorKind};
+
 mod masked;
 pub use masked::Masked;
 
 mod span;
 pub use span::{Span, ToSpan};
 
+mod truncate;
+pub use truncate::{Truncation, TruncationMode};
+
+mod tab;
+pub use tab::TabStops;
+
 mod text;
 pub use text::{Text, ToText};
+
+mod width;
+pub use width::{AmbiguousWidth, EmojiPresentation, WidthPolicy};
+
+mod wrap;
+pub use wrap::{BreakMode, WordWrapper, WrapOptions, WrappedLine};
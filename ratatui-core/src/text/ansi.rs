@@ -0,0 +1,584 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::style::{Color, Modifier, Style};
+use crate::text::{Line, Span, Text};
+
+/// The maximum number of parameter bytes that are kept for a single control sequence.
+///
+/// Longer sequences are still consumed, but their parameters are ignored. This bounds the memory
+/// used by malformed input.
+const MAX_PARAMS_LEN: usize = 256;
+
+impl Text<'_> {
+    /// Parses text containing ANSI escape sequences into a styled `Text`.
+    ///
+    /// This is useful to display the output of other programs (e.g. compilers, version control
+    /// tools or test runners) that color their output with escape sequences:
+    ///
+    /// - SGR (Select Graphic Rendition) sequences such as `\x1b[1;31m` are converted into [`Span`]
+    ///   styles. The 8 and 16 colors, 256 colors (`38;5;n`), truecolor (`38;2;r;g;b`) and the colon
+    ///   separated variants of these (`38:2::r:g:b`) are supported for the foreground (`38`) and
+    ///   background (`48`) colors, and for the underline color (`58`) with the `underline-color`
+    ///   feature. `0` and the individual resets (e.g. `22` or `39`) remove the corresponding parts
+    ///   of the style.
+    /// - All other escape sequences (e.g. cursor movement, erasing, or OSC sequences such as
+    ///   hyperlinks and window titles) are removed.
+    /// - `\n` and `\r\n` separate lines. A lone `\r` starts the current line over, which is how
+    ///   progress bars redraw themselves. Other control characters except `\t` are removed.
+    /// - Invalid UTF-8 is replaced with [`U+FFFD REPLACEMENT
+    ///   CHARACTER`](char::REPLACEMENT_CHARACTER).
+    ///
+    /// Styles that are reset are cleared rather than set to [`Color::Reset`], so that text without
+    /// a style inherits the style of the widget that renders it.
+    ///
+    /// To parse output that arrives in chunks (e.g. from a child process), use [`AnsiParser`],
+    /// which keeps the style and any partial escape sequence between chunks.
+    ///
+    /// This method is only available with the `ansi` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Color, Stylize};
+    /// use ratatui_core::text::{Line, Text};
+    ///
+    /// let text = Text::from_ansi(b"\x1b[1;31merror\x1b[0m: \x1b[38;5;208mwarning\x1b[39m\ndone");
+    /// assert_eq!(
+    ///     text,
+    ///     Text::from(vec![
+    ///         Line::from(vec![
+    ///             "error".bold().red(),
+    ///             ": ".into(),
+    ///             "warning".fg(Color::Indexed(208)),
+    ///         ]),
+    ///         Line::from("done"),
+    ///     ])
+    /// );
+    /// ```
+    pub fn from_ansi(bytes: &[u8]) -> Text<'static> {
+        let mut parser = AnsiParser::new();
+        parser.push(bytes);
+        parser.finish()
+    }
+}
+
+/// An incremental parser for text containing ANSI escape sequences.
+///
+/// The parser accepts input in chunks with [`AnsiParser::push`], which makes it suitable for
+/// streaming output such as the log of a running child process. Escape sequences and UTF-8
+/// characters that are split between chunks are handled correctly, and the current style carries
+/// over from one chunk to the next.
+///
+/// Complete lines can be taken out of the parser with [`AnsiParser::take_lines`] as they arrive,
+/// while the line that is still being written stays in the parser. [`AnsiParser::finish`] returns
+/// everything that is left.
+///
+/// See [`Text::from_ansi`] for the supported escape sequences.
+///
+/// This type is only available with the `ansi` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::style::Stylize;
+/// use ratatui_core::text::{AnsiParser, Line};
+///
+/// let mut parser = AnsiParser::new();
+/// parser.push(b"\x1b[32mCompiling\x1b[0m foo\n\x1b[3");
+/// assert_eq!(
+///     parser.take_lines(),
+///     [Line::from(vec!["Compiling".green(), " foo".into()])]
+/// );
+///
+/// parser.push(b"3mWarn");
+/// parser.push(b"ing\x1b[0m: unused\n");
+/// assert_eq!(
+///     parser.take_lines(),
+///     [Line::from(vec!["Warning".yellow(), ": unused".into()])]
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct AnsiParser {
+    lines: Vec<Line<'static>>,
+    style: Style,
+    state: State,
+    params: Vec<u8>,
+    utf8: Vec<u8>,
+    line_finished: bool,
+    carriage_return: bool,
+}
+
+/// The state of the escape sequence parser.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+enum State {
+    /// Plain text.
+    #[default]
+    Ground,
+    /// After an `ESC`.
+    Escape,
+    /// After an `ESC` and one or more intermediate bytes, e.g. `ESC (`.
+    EscapeIntermediate,
+    /// Inside a control sequence (`ESC [`).
+    Csi,
+    /// Inside a control sequence with a private marker or intermediate bytes, which is ignored.
+    CsiIgnore,
+    /// Inside a control string (OSC, DCS, SOS, PM or APC), which ends with `BEL` or `ESC \`.
+    String,
+    /// After an `ESC` inside a control string.
+    StringEscape,
+}
+
+impl AnsiParser {
+    /// Creates a new parser with no text and the default style.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the current style, i.e. the style that is applied to the next text that is pushed.
+    pub const fn style(&self) -> Style {
+        self.style
+    }
+
+    /// Returns the lines that have been parsed so far and not taken out of the parser yet.
+    ///
+    /// UTF-8 characters that are incomplete at the end of the last chunk are not part of the text
+    /// until the rest of the character is pushed.
+    pub fn lines(&self) -> &[Line<'static>] {
+        &self.lines
+    }
+
+    /// Parses a chunk of input.
+    pub fn push(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.advance(byte);
+        }
+        self.flush_utf8(false);
+    }
+
+    /// Takes the lines that have been completed by a newline out of the parser.
+    ///
+    /// The line that is still being written, if any, stays in the parser and is continued by the
+    /// next chunk.
+    pub fn take_lines(&mut self) -> Vec<Line<'static>> {
+        if self.line_finished {
+            self.line_finished = false;
+            return mem::take(&mut self.lines);
+        }
+        let current = self.lines.pop();
+        let lines = mem::take(&mut self.lines);
+        self.lines.extend(current);
+        lines
+    }
+
+    /// Returns all the text that is left in the parser.
+    ///
+    /// Incomplete UTF-8 characters at the end of the input are replaced with
+    /// [`U+FFFD REPLACEMENT CHARACTER`](char::REPLACEMENT_CHARACTER), and an incomplete escape
+    /// sequence is dropped.
+    pub fn finish(mut self) -> Text<'static> {
+        self.flush_utf8(true);
+        Text::from(self.lines)
+    }
+
+    fn advance(&mut self, byte: u8) {
+        match self.state {
+            State::Ground => self.ground(byte),
+            State::Escape => self.escape(byte),
+            State::EscapeIntermediate => match byte {
+                0x1B => self.state = State::Escape,
+                0x20..=0x2F => {}
+                _ => self.state = State::Ground,
+            },
+            State::Csi => match byte {
+                b'0'..=b';' if self.params.len() < MAX_PARAMS_LEN => self.params.push(byte),
+                b'0'..=b';' => {}
+                b'<'..=b'?' | 0x20..=0x2F => self.state = State::CsiIgnore,
+                0x1B => self.state = State::Escape,
+                b'm' => {
+                    self.state = State::Ground;
+                    let params = mem::take(&mut self.params);
+                    self.select_graphic_rendition(&params);
+                    self.params = params;
+                }
+                _ => self.state = State::Ground,
+            },
+            State::CsiIgnore => match byte {
+                0x1B => self.state = State::Escape,
+                0x40..=0x7E => self.state = State::Ground,
+                _ => {}
+            },
+            State::String => match byte {
+                0x07 => self.state = State::Ground,
+                0x1B => self.state = State::StringEscape,
+                _ => {}
+            },
+            State::StringEscape => match byte {
+                b'\\' => self.state = State::Ground,
+                // an escape sequence that is not a string terminator ends the string and starts a
+                // new sequence
+                _ => self.escape(byte),
+            },
+        }
+    }
+
+    fn ground(&mut self, byte: u8) {
+        match byte {
+            0x1B => {
+                self.flush_utf8(true);
+                self.state = State::Escape;
+            }
+            b'\n' => {
+                self.flush_utf8(true);
+                if self.line_finished || self.lines.is_empty() {
+                    self.lines.push(Line::default());
+                }
+                self.line_finished = true;
+                self.carriage_return = false;
+            }
+            b'\r' => {
+                self.flush_utf8(true);
+                self.carriage_return = true;
+            }
+            b'\t' => self.utf8.push(byte),
+            0x00..=0x1F | 0x7F => self.flush_utf8(true),
+            _ => self.utf8.push(byte),
+        }
+    }
+
+    fn escape(&mut self, byte: u8) {
+        self.state = match byte {
+            b'[' => {
+                self.params.clear();
+                State::Csi
+            }
+            b']' | b'P' | b'X' | b'^' | b'_' => State::String,
+            0x1B => State::Escape,
+            0x20..=0x2F => State::EscapeIntermediate,
+            _ => State::Ground,
+        };
+    }
+
+    /// Decodes the pending UTF-8 bytes and appends them to the current line.
+    ///
+    /// If `complete` is false, an incomplete character at the end is kept until more input arrives.
+    fn flush_utf8(&mut self, complete: bool) {
+        let bytes = mem::take(&mut self.utf8);
+        let mut rest = bytes.as_slice();
+        loop {
+            match core::str::from_utf8(rest) {
+                Ok(valid) => {
+                    self.push_str(valid);
+                    return;
+                }
+                Err(error) => {
+                    let (valid, invalid) = rest.split_at(error.valid_up_to());
+                    self.push_str(core::str::from_utf8(valid).unwrap_or_default());
+                    match error.error_len() {
+                        Some(len) => {
+                            self.push_str("\u{FFFD}");
+                            rest = invalid.get(len..).unwrap_or_default();
+                        }
+                        None if complete => {
+                            self.push_str("\u{FFFD}");
+                            return;
+                        }
+                        None => {
+                            self.utf8.extend_from_slice(invalid);
+                            return;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn push_str(&mut self, s: &str) {
+        if s.is_empty() {
+            return;
+        }
+        if self.line_finished || self.lines.is_empty() {
+            self.lines.push(Line::default());
+            self.line_finished = false;
+        }
+        let Some(line) = self.lines.last_mut() else {
+            return;
+        };
+        if mem::take(&mut self.carriage_return) {
+            line.spans.clear();
+        }
+        match line.spans.last_mut() {
+            Some(span) if span.style == self.style => span.content.to_mut().push_str(s),
+            _ => line
+                .spans
+                .push(Span::styled(Cow::Owned(s.into()), self.style)),
+        }
+    }
+
+    /// Applies the parameters of an SGR sequence (`ESC [ ... m`) to the current style.
+    fn select_graphic_rendition(&mut self, params: &[u8]) {
+        // Parameters are separated by `;`, and a parameter can have sub-parameters separated by
+        // `:`. Empty (sub-)parameters are `None`, which usually means `0`.
+        let mut groups = params.split(|&b| b == b';').map(|group| {
+            group
+                .split(|&b| b == b':')
+                .map(parse_param)
+                .collect::<Vec<_>>()
+        });
+        while let Some(group) = groups.next() {
+            let code = group.first().copied().flatten().unwrap_or(0);
+            let subparams = group.get(1..).unwrap_or_default();
+            match code {
+                0 => self.style = Style::default(),
+                1 => self.add_modifier(Modifier::BOLD),
+                2 => self.add_modifier(Modifier::DIM),
+                3 => self.add_modifier(Modifier::ITALIC),
+                // `4:0` is "no underline", and `4:1` to `4:5` are the underline styles
+                4 if subparams.first() == Some(&Some(0)) => {
+                    self.remove_modifier(Modifier::UNDERLINED);
+                }
+                4 | 21 => self.add_modifier(Modifier::UNDERLINED),
+                5 => self.add_modifier(Modifier::SLOW_BLINK),
+                6 => self.add_modifier(Modifier::RAPID_BLINK),
+                7 => self.add_modifier(Modifier::REVERSED),
+                8 => self.add_modifier(Modifier::HIDDEN),
+                9 => self.add_modifier(Modifier::CROSSED_OUT),
+                22 => self.remove_modifier(Modifier::BOLD | Modifier::DIM),
+                23 => self.remove_modifier(Modifier::ITALIC),
+                24 => self.remove_modifier(Modifier::UNDERLINED),
+                25 => self.remove_modifier(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK),
+                27 => self.remove_modifier(Modifier::REVERSED),
+                28 => self.remove_modifier(Modifier::HIDDEN),
+                29 => self.remove_modifier(Modifier::CROSSED_OUT),
+                30..=37 => self.style.fg = Some(ansi_color(code - 30)),
+                38 => self.style.fg = extended_color(subparams, &mut groups),
+                39 => self.style.fg = None,
+                40..=47 => self.style.bg = Some(ansi_color(code - 40)),
+                48 => self.style.bg = extended_color(subparams, &mut groups),
+                49 => self.style.bg = None,
+                #[cfg(feature = "underline-color")]
+                58 => self.style.underline_color = extended_color(subparams, &mut groups),
+                #[cfg(not(feature = "underline-color"))]
+                58 => {
+                    extended_color(subparams, &mut groups);
+                }
+                #[cfg(feature = "underline-color")]
+                59 => self.style.underline_color = None,
+                90..=97 => self.style.fg = Some(ansi_color(code - 90 + 8)),
+                100..=107 => self.style.bg = Some(ansi_color(code - 100 + 8)),
+                _ => {}
+            }
+        }
+    }
+
+    /// Adds a modifier to the current style.
+    fn add_modifier(&mut self, modifier: Modifier) {
+        self.style.add_modifier.insert(modifier);
+    }
+
+    /// Removes a modifier from the current style.
+    ///
+    /// Unlike [`Style::remove_modifier`], this doesn't add the modifier to the modifiers that are
+    /// removed from the style of the widget that renders the text.
+    fn remove_modifier(&mut self, modifier: Modifier) {
+        self.style.add_modifier.remove(modifier);
+    }
+}
+
+fn parse_param(param: &[u8]) -> Option<u16> {
+    if param.is_empty() {
+        return None;
+    }
+    Some(param.iter().fold(0u16, |value, &digit| {
+        value
+            .saturating_mul(10)
+            .saturating_add(u16::from(digit.wrapping_sub(b'0')))
+    }))
+}
+
+/// Returns the color for an index in the 16 color palette.
+const fn ansi_color(index: u16) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        7 => Color::Gray,
+        8 => Color::DarkGray,
+        9 => Color::LightRed,
+        10 => Color::LightGreen,
+        11 => Color::LightYellow,
+        12 => Color::LightBlue,
+        13 => Color::LightMagenta,
+        14 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+/// Parses an extended color (`38`, `48` or `58`).
+///
+/// The color is either given as sub-parameters (`38:5:n`, `38:2:r:g:b` or `38:2:id:r:g:b`), or as
+/// the following parameters (`38;5;n` or `38;2;r;g;b`), which are consumed from `groups`.
+fn extended_color(
+    subparams: &[Option<u16>],
+    groups: &mut impl Iterator<Item = Vec<Option<u16>>>,
+) -> Option<Color> {
+    let byte = |param: Option<u16>| param.and_then(|param| u8::try_from(param).ok());
+    if subparams.is_empty() {
+        let mut next = || {
+            groups
+                .next()
+                .and_then(|group| group.first().copied().flatten())
+        };
+        return match next() {
+            Some(5) => byte(next()).map(Color::Indexed),
+            Some(2) => {
+                let (r, g, b) = (next(), next(), next());
+                Some(Color::Rgb(byte(r)?, byte(g)?, byte(b)?))
+            }
+            _ => None,
+        };
+    }
+    match subparams {
+        [Some(5), index] => byte(*index).map(Color::Indexed),
+        [Some(2), r, g, b] | [Some(2), _, r, g, b, ..] => {
+            Some(Color::Rgb(byte(*r)?, byte(*g)?, byte(*b)?))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Stylize;
+
+    #[rstest]
+    #[case::plain(b"hello", Text::from("hello"))]
+    #[case::empty(b"", Text::default())]
+    #[case::lines(b"a\nb\r\nc\n", Text::from(vec![Line::from("a"), Line::from("b"), Line::from("c")]))]
+    #[case::empty_lines(b"a\n\nb", Text::from(vec![Line::from("a"), Line::default(), Line::from("b")]))]
+    #[case::leading_newline(b"\na", Text::from(vec![Line::default(), Line::from("a")]))]
+    #[case::carriage_return(b"10%\r50%\r100%", Text::from("100%"))]
+    #[case::tab(b"a\tb", Text::from("a\tb"))]
+    #[case::controls(b"a\x07\x08b", Text::from("ab"))]
+    #[case::invalid_utf8(b"a\xffb\xe2\x82", Text::from("a\u{FFFD}b\u{FFFD}"))]
+    fn text(#[case] input: &[u8], #[case] expected: Text<'static>) {
+        assert_eq!(Text::from_ansi(input), expected);
+    }
+
+    #[rstest]
+    #[case::reset(b"\x1b[1;31ma\x1b[0mb\x1b[1mc\x1b[mc", vec!["a".bold().red(), "b".into(), "c".bold(), "c".into()])]
+    #[case::modifiers(b"\x1b[1;2;3;4;5;6;7;8;9ma", vec![Span::styled("a", Style::new().add_modifier(Modifier::all()))])]
+    #[case::modifier_resets(
+        b"\x1b[1;2;3;4;5;7;8;9m\x1b[22;23;24;25;27;28;29ma",
+        vec!["a".into()]
+    )]
+    #[case::underline_styles(b"\x1b[4:3ma\x1b[4:0mb", vec!["a".underlined(), "b".into()])]
+    #[case::colors(b"\x1b[32;44ma\x1b[92;104mb", vec!["a".green().on_blue(), "b".light_green().on_light_blue()])]
+    #[case::color_resets(b"\x1b[1;31;42ma\x1b[39mb\x1b[49mc", vec!["a".bold().red().on_green(), "b".bold().on_green(), "c".bold()])]
+    #[case::indexed(b"\x1b[38;5;208;48;5;17ma", vec![Span::styled("a", Style::new().fg(Color::Indexed(208)).bg(Color::Indexed(17)))])]
+    #[case::indexed_colon(b"\x1b[38:5:208ma", vec![Span::styled("a", Style::new().fg(Color::Indexed(208)))])]
+    #[case::rgb(b"\x1b[38;2;255;128;0;1ma", vec![Span::styled("a", Style::new().fg(Color::Rgb(255, 128, 0)).bold())])]
+    #[case::rgb_colon(b"\x1b[48:2::1:2:3ma\x1b[48:2:4:5:6mb", vec![
+        Span::styled("a", Style::new().bg(Color::Rgb(1, 2, 3))),
+        Span::styled("b", Style::new().bg(Color::Rgb(4, 5, 6))),
+    ])]
+    #[case::invalid_color(b"\x1b[38;2;300;0;0ma\x1b[38;5ma", vec!["aa".into()])]
+    #[case::unknown(b"\x1b[1;1234;3ma", vec!["a".bold().italic()])]
+    fn sgr(#[case] input: &[u8], #[case] expected: Vec<Span<'static>>) {
+        assert_eq!(Text::from_ansi(input), Text::from(Line::from(expected)));
+    }
+
+    #[cfg(feature = "underline-color")]
+    #[test]
+    fn underline_color() {
+        assert_eq!(
+            Text::from_ansi(b"\x1b[4;58;5;1ma\x1b[58:2::1:2:3mb\x1b[59mc"),
+            Text::from(Line::from(vec![
+                Span::styled(
+                    "a",
+                    Style::new().underlined().underline_color(Color::Indexed(1))
+                ),
+                Span::styled(
+                    "b",
+                    Style::new()
+                        .underlined()
+                        .underline_color(Color::Rgb(1, 2, 3))
+                ),
+                "c".underlined(),
+            ]))
+        );
+    }
+
+    #[rstest]
+    #[case::erase_line(b"a\x1b[2Kb")]
+    #[case::cursor(b"a\x1b[10;20Hb")]
+    #[case::private(b"a\x1b[?25lb")]
+    #[case::private_m(b"a\x1b[>4;2mb")]
+    #[case::osc_bel(b"a\x1b]0;title\x07b")]
+    #[case::osc_st(b"a\x1b]8;;https://ratatui.rs\x1b\\b")]
+    #[case::dcs(b"a\x1bPq#0\x1b\\b")]
+    #[case::charset(b"a\x1b(Bb")]
+    #[case::keypad(b"a\x1b=b")]
+    fn strips_sequences(#[case] input: &[u8]) {
+        assert_eq!(Text::from_ansi(input), Text::from("ab"));
+    }
+
+    #[test]
+    fn osc_interrupted_by_escape() {
+        assert_eq!(
+            Text::from_ansi(b"\x1b]0;title\x1b[1ma"),
+            Text::from(Line::from("a".bold()))
+        );
+    }
+
+    #[test]
+    fn incremental() {
+        let input: &[u8] = b"\x1b[1;38;2;255;0;0mer\xe2\x9c\x97r\x1b[0m\nok\r\n\x1b]8;;x\x1b\\last";
+        let expected = Text::from_ansi(input);
+        // split the input at every possible position
+        for split in 0..=input.len() {
+            let (head, tail) = input.split_at(split);
+            let mut parser = AnsiParser::new();
+            parser.push(head);
+            parser.push(tail);
+            assert_eq!(parser.finish(), expected, "split at {split}");
+        }
+        // and byte by byte
+        let mut parser = AnsiParser::new();
+        for &byte in input {
+            parser.push(&[byte]);
+        }
+        assert_eq!(parser.finish(), expected);
+    }
+
+    #[test]
+    fn take_lines() {
+        let mut parser = AnsiParser::new();
+        parser.push(b"\x1b[31mone\ntw");
+        assert_eq!(parser.take_lines(), [Line::from("one".red())]);
+        assert_eq!(parser.lines(), [Line::from("tw".red())]);
+        assert_eq!(parser.style(), Style::new().red());
+
+        parser.push(b"o\n\n");
+        assert_eq!(
+            parser.take_lines(),
+            [Line::from("two".red()), Line::default()]
+        );
+        assert_eq!(parser.take_lines(), []);
+
+        parser.push(b"\x1b[0mthree");
+        assert_eq!(parser.take_lines(), []);
+        assert_eq!(parser.finish(), Text::from("three"));
+    }
+}
@@ -0,0 +1,252 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use unicode_bidi::{Level, ParagraphBidiInfo};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::layout::WritingDirection;
+use crate::style::Style;
+use crate::text::{Line, Span, Text};
+
+impl Line<'_> {
+    /// Returns a copy of the line with its content reordered for display using the Unicode
+    /// Bidirectional Algorithm.
+    ///
+    /// Lines are stored in logical order (the order in which the characters are typed), but
+    /// rendering writes graphemes from left to right. Right-to-left scripts such as Hebrew and
+    /// Arabic, and mixed-direction strings (e.g. Hebrew with embedded numbers or English words),
+    /// display correctly only after being reordered into visual order. `direction` is the base
+    /// direction of the paragraph, which decides the order of runs that have no strong direction
+    /// of their own (e.g. punctuation at the end of the line).
+    ///
+    /// The style of each span is kept, spans that contain text in both directions are split, and
+    /// paired brackets in right-to-left runs are mirrored. The style and alignment of the line are
+    /// unchanged. The line should be reordered just before rendering, as other operations such as
+    /// wrapping or truncation expect logical order.
+    ///
+    /// This method is only available with the `unicode-bidi` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::WritingDirection;
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::Line;
+    ///
+    /// let line = Line::from(vec!["שלום ".bold(), "(world)".into()]);
+    /// let visual = line.to_visual_order(WritingDirection::RightToLeft);
+    /// assert_eq!(visual, Line::from(vec!["(world)".into(), " םולש".bold()]));
+    /// ```
+    #[must_use = "returns the reordered line"]
+    pub fn to_visual_order(&self, direction: WritingDirection) -> Line<'static> {
+        let mut text = String::new();
+        let mut ranges = Vec::with_capacity(self.spans.len());
+        for span in &self.spans {
+            let start = text.len();
+            text.push_str(&span.content);
+            ranges.push(start..text.len());
+        }
+
+        if text.is_empty() {
+            return Line {
+                style: self.style,
+                alignment: self.alignment,
+                spans: Vec::new(),
+            };
+        }
+
+        let level = match direction {
+            WritingDirection::LeftToRight => Level::ltr(),
+            WritingDirection::RightToLeft => Level::rtl(),
+        };
+        let info = ParagraphBidiInfo::new(&text, Some(level));
+        let (levels, runs) = info.visual_runs(0..text.len());
+
+        let mut spans = Vec::with_capacity(self.spans.len());
+        for run in runs {
+            let pieces = self
+                .spans
+                .iter()
+                .zip(&ranges)
+                .filter_map(|(span, range)| Some((span, intersect(range, &run)?)));
+            if levels[run.start].is_rtl() {
+                for (span, range) in pieces.rev() {
+                    let piece = text.get(range).unwrap_or_default();
+                    let content: String = piece.graphemes(true).rev().map(mirror).collect();
+                    push_span(&mut spans, content, span.style);
+                }
+            } else {
+                for (span, range) in pieces {
+                    let piece = text.get(range).unwrap_or_default();
+                    push_span(&mut spans, String::from(piece), span.style);
+                }
+            }
+        }
+
+        Line {
+            style: self.style,
+            alignment: self.alignment,
+            spans,
+        }
+    }
+}
+
+impl Text<'_> {
+    /// Returns a copy of the text with each line reordered for display using the Unicode
+    /// Bidirectional Algorithm.
+    ///
+    /// See [`Line::to_visual_order`] for more details.
+    ///
+    /// This method is only available with the `unicode-bidi` feature.
+    #[must_use = "returns the reordered text"]
+    pub fn to_visual_order(&self, direction: WritingDirection) -> Text<'static> {
+        Text {
+            alignment: self.alignment,
+            style: self.style,
+            lines: self
+                .lines
+                .iter()
+                .map(|line| line.to_visual_order(direction))
+                .collect(),
+        }
+    }
+}
+
+/// Appends the content to the last span if it has the same style, or as a new span otherwise.
+fn push_span(spans: &mut Vec<Span<'static>>, content: String, style: Style) {
+    match spans.last_mut() {
+        Some(last) if last.style == style => last.content.to_mut().push_str(&content),
+        _ => spans.push(Span::styled(content, style)),
+    }
+}
+
+/// Returns the overlapping part of two byte ranges, if any.
+fn intersect(a: &Range<usize>, b: &Range<usize>) -> Option<Range<usize>> {
+    let range = a.start.max(b.start)..a.end.min(b.end);
+    (!range.is_empty()).then_some(range)
+}
+
+/// Returns the mirrored form of paired brackets, which are displayed flipped in right-to-left runs.
+fn mirror(grapheme: &str) -> &str {
+    match grapheme {
+        "(" => ")",
+        ")" => "(",
+        "[" => "]",
+        "]" => "[",
+        "{" => "}",
+        "}" => "{",
+        "<" => ">",
+        ">" => "<",
+        "«" => "»",
+        "»" => "«",
+        _ => grapheme,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::layout::Alignment;
+    use crate::style::Stylize;
+
+    #[test]
+    fn left_to_right_is_unchanged() {
+        let line = Line::from(vec!["hello ".red(), "world".into()]);
+        assert_eq!(line.to_visual_order(WritingDirection::LeftToRight), line);
+        assert_eq!(line.to_visual_order(WritingDirection::RightToLeft), line);
+    }
+
+    #[test]
+    fn empty() {
+        let line = Line::default().centered();
+        assert_eq!(line.to_visual_order(WritingDirection::RightToLeft), line);
+    }
+
+    #[test]
+    fn right_to_left() {
+        let line = Line::from("אבג");
+        assert_eq!(
+            line.to_visual_order(WritingDirection::LeftToRight),
+            Line::from("גבא")
+        );
+    }
+
+    #[test]
+    fn mixed_directions() {
+        // the english word and the number keep their order inside the hebrew sentence
+        let line = Line::from("אב abc 12 גד");
+        assert_eq!(
+            String::from(line.to_visual_order(WritingDirection::RightToLeft)),
+            "דג abc 12 בא"
+        );
+        let line = Line::from("אב 12 גד");
+        assert_eq!(
+            String::from(line.to_visual_order(WritingDirection::RightToLeft)),
+            "דג 12 בא"
+        );
+        let line = Line::from("abc אב def");
+        assert_eq!(
+            String::from(line.to_visual_order(WritingDirection::LeftToRight)),
+            "abc בא def"
+        );
+    }
+
+    #[test]
+    fn base_direction_orders_neutral_runs() {
+        let line = Line::from("אב!");
+        assert_eq!(
+            String::from(line.to_visual_order(WritingDirection::LeftToRight)),
+            "בא!"
+        );
+        assert_eq!(
+            String::from(line.to_visual_order(WritingDirection::RightToLeft)),
+            "!בא"
+        );
+    }
+
+    #[test]
+    fn keeps_styles_and_splits_spans() {
+        let line = Line::from(vec!["אב".red(), "גד ef".blue()])
+            .style(Style::new().italic())
+            .alignment(Alignment::Right);
+        assert_eq!(
+            line.to_visual_order(WritingDirection::LeftToRight),
+            Line::from(vec!["דג".blue(), "בא".red(), " ef".blue()])
+                .style(Style::new().italic())
+                .alignment(Alignment::Right)
+        );
+    }
+
+    #[test]
+    fn mirrors_brackets() {
+        let line = Line::from("א(ב)");
+        assert_eq!(
+            String::from(line.to_visual_order(WritingDirection::RightToLeft)),
+            "(ב)א"
+        );
+    }
+
+    #[test]
+    fn keeps_grapheme_clusters() {
+        // the combining point stays attached to its base letter
+        let line = Line::from("ש\u{05BC}ל");
+        assert_eq!(
+            String::from(line.to_visual_order(WritingDirection::RightToLeft)),
+            "לש\u{05BC}"
+        );
+    }
+
+    #[test]
+    fn text() {
+        let text = Text::from(vec![Line::from("אב"), Line::from("ab")]).right_aligned();
+        assert_eq!(
+            text.to_visual_order(WritingDirection::RightToLeft),
+            Text::from(vec![Line::from("בא"), Line::from("ab")]).right_aligned()
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::ops::{Bound, RangeBounds};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::text::truncate::slice;
+use crate::text::{Line, Span, TabStops, Text, width};
+
+impl Line<'_> {
+    /// Returns the part of this line that is displayed in the given range of columns.
+    ///
+    /// This is useful to implement horizontal scrolling, e.g. showing columns `40..120` of a log
+    /// line. The styles of the spans and the style and alignment of the line are preserved.
+    /// Borrowed content stays borrowed.
+    ///
+    /// Wide graphemes (e.g. CJK characters or emoji) that are split by the start or the end of the
+    /// range are replaced by spaces in the style of their span, so that the result is always
+    /// exactly as wide as the part of the range that is covered by the line. Tabs are expanded to
+    /// the global [`TabStops`] first, so the columns match those of [`Line::width`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::Line;
+    ///
+    /// let line = Line::from(vec!["Hello".red(), ", world!".into()]);
+    /// assert_eq!(
+    ///     line.slice_columns(3..9),
+    ///     Line::from(vec!["lo".red(), ", wo".into()])
+    /// );
+    /// assert_eq!(line.slice_columns(7..), Line::from("world!"));
+    ///
+    /// // the wide character split at the start of the range is replaced by a space
+    /// let line = Line::from("日本語");
+    /// assert_eq!(line.slice_columns(1..4).to_string(), " 本");
+    /// ```
+    #[must_use]
+    pub fn slice_columns<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        if self.has_tabs() {
+            // expand the tabs so that the graphemes are measured at the columns they are displayed
+            return self.expand_tabs(&TabStops::global()).slice_columns(range);
+        }
+        let (start, end) = column_bounds(&range);
+        let mut spans = Vec::new();
+        let mut column = 0;
+        for span in &self.spans {
+            if column >= end {
+                break;
+            }
+            let span_width = span.width();
+            let span_end = column + span_width;
+            let before_range = span_end < start || (span_end == start && span_width > 0);
+            if !before_range {
+                if column >= start && span_end <= end {
+                    spans.push(span.clone());
+                } else {
+                    slice_span(span, column, start, end, &mut spans);
+                }
+            }
+            column = span_end;
+        }
+        Self {
+            spans,
+            style: self.style,
+            alignment: self.alignment,
+        }
+    }
+}
+
+impl Text<'_> {
+    /// Returns the part of each line of this text that is displayed in the given range of columns.
+    ///
+    /// Each line is sliced separately with [`Line::slice_columns`]. The style and alignment of the
+    /// text are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::Text;
+    ///
+    /// let text = Text::from("first line\nsecond line");
+    /// assert_eq!(text.slice_columns(2..8).to_string(), "rst li\ncond l");
+    /// ```
+    #[must_use]
+    pub fn slice_columns<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        let bounds = column_bounds(&range);
+        Self {
+            lines: self
+                .lines
+                .iter()
+                .map(|line| line.slice_columns(bounds.0..bounds.1))
+                .collect(),
+            style: self.style,
+            alignment: self.alignment,
+        }
+    }
+}
+
+/// Converts a range of columns into a start (inclusive) and end (exclusive) column.
+fn column_bounds<R: RangeBounds<usize>>(range: &R) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => usize::MAX,
+    };
+    (start, end.max(start))
+}
+
+/// Pushes the part of a span starting at `column` that is within `start..end`, padding wide
+/// graphemes that are split by the range with spaces.
+fn slice_span<'a>(
+    span: &Span<'a>,
+    mut column: usize,
+    start: usize,
+    end: usize,
+    spans: &mut Vec<Span<'a>>,
+) {
+    let mut content_start = None;
+    let mut content_end = 0;
+    let mut leading = 0;
+    let mut trailing = 0;
+    for (index, grapheme) in span.content.grapheme_indices(true) {
+        let grapheme_start = column;
+        let grapheme_end = column + width::str_width(grapheme);
+        column = grapheme_end;
+        if grapheme_start >= end {
+            break;
+        }
+        if grapheme_start < start {
+            // the grapheme is before the range, or split by its start
+            leading = grapheme_end.min(end).saturating_sub(start);
+            continue;
+        }
+        if grapheme_end > end {
+            // the grapheme is split by the end of the range
+            trailing = end - grapheme_start;
+            break;
+        }
+        content_start.get_or_insert(index);
+        content_end = index + grapheme.len();
+    }
+    if leading > 0 {
+        spans.push(padding(leading, span));
+    }
+    if let Some(content_start) = content_start {
+        let content = slice(&span.content, content_start, content_end);
+        spans.push(Span::styled(content, span.style));
+    }
+    if trailing > 0 {
+        spans.push(padding(trailing, span));
+    }
+}
+
+fn padding<'a>(width: usize, span: &Span<'_>) -> Span<'a> {
+    Span::styled(Cow::Owned(" ".repeat(width)), span.style)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::layout::Alignment;
+    use crate::style::{Style, Stylize};
+
+    #[rstest]
+    #[case::full(0..13, "Hello, world!")]
+    #[case::unbounded(.., "Hello, world!")]
+    #[case::start(7.., "world!")]
+    #[case::end(..5, "Hello")]
+    #[case::inclusive(0..=4, "Hello")]
+    #[case::middle(3..9, "lo, wo")]
+    #[case::beyond(10..40, "ld!")]
+    #[case::after(20..40, "")]
+    #[case::empty(5..5, "")]
+    #[case::reversed(core::ops::Range { start: 8, end: 2 }, "")]
+    fn slice_columns<R: RangeBounds<usize>>(#[case] range: R, #[case] expected: &str) {
+        let line = Line::from("Hello, world!");
+        assert_eq!(line.slice_columns(range).to_string(), expected);
+    }
+
+    #[rstest]
+    #[case::aligned(2..4, "本")]
+    #[case::split_start(1..4, " 本")]
+    #[case::split_end(2..5, "本 ")]
+    #[case::split_both(1..5, " 本 ")]
+    #[case::inside_single(1..2, " ")]
+    #[case::split_single(3..4, " ")]
+    fn slice_columns_wide_graphemes(
+        #[case] range: core::ops::Range<usize>,
+        #[case] expected: &str,
+    ) {
+        let line = Line::from("日本語");
+        assert_eq!(line.slice_columns(range).to_string(), expected);
+    }
+
+    #[test]
+    fn slice_columns_keeps_span_styles() {
+        let line = Line::from(vec!["abc".red(), "日本".green(), "def".blue()]);
+        assert_eq!(
+            line.slice_columns(2..6),
+            Line::from(vec![
+                "c".red(),
+                "日".green(),
+                Span::styled(" ", Style::new().green())
+            ])
+        );
+    }
+
+    #[test]
+    fn slice_columns_keeps_combining_characters() {
+        let line = Line::from("ae\u{301}b");
+        assert_eq!(line.slice_columns(1..2).to_string(), "e\u{301}");
+    }
+
+    #[test]
+    fn slice_columns_expands_tabs() {
+        let line = Line::from(vec!["\tab".into(), "c\td".red()]);
+        assert_eq!(line.slice_columns(8..10), Line::from("ab"));
+        assert_eq!(line.slice_columns(10..16), Line::from("c     ".red()));
+        assert_eq!(line.slice_columns(16..), Line::from("d".red()));
+    }
+
+    #[test]
+    fn slice_columns_keeps_line_style_and_alignment() {
+        let line = Line::from("Hello, world!").yellow().centered();
+        let sliced = line.slice_columns(2..6);
+        assert_eq!(sliced.style, line.style);
+        assert_eq!(sliced.alignment, Some(Alignment::Center));
+    }
+
+    #[test]
+    fn slice_columns_keeps_borrowed_content() {
+        let line = Line::from("Hello, world!");
+        let sliced = line.slice_columns(2..6);
+        assert!(matches!(sliced.spans[0].content, Cow::Borrowed("llo,")));
+    }
+
+    #[test]
+    fn slice_columns_text() {
+        let text = Text::from("first line\nsecond line\nx").red();
+        let sliced = text.slice_columns(2..=7);
+        assert_eq!(sliced.to_string(), "rst li\ncond l\n");
+        assert_eq!(sliced.style, text.style);
+    }
+}
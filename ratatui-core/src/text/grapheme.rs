@@ -1,4 +1,5 @@
 use crate::style::{Style, Styled};
+use crate::text::width;
 
 const NBSP: &str = "\u{00a0}";
 const ZWSP: &str = "\u{200b}";
@@ -28,6 +29,19 @@ impl<'a> StyledGrapheme<'a> {
         }
     }
 
+    /// Returns the display width of the grapheme according to the global [`WidthPolicy`].
+    ///
+    /// This is the width that [`Span::width`], [`Line::width`] and [`Buffer::set_stringn`] use for
+    /// the grapheme, so widgets that place graphemes themselves should use it to stay in sync.
+    ///
+    /// [`WidthPolicy`]: crate::text::WidthPolicy
+    /// [`Span::width`]: crate::text::Span::width
+    /// [`Line::width`]: crate::text::Line::width
+    /// [`Buffer::set_stringn`]: crate::buffer::Buffer::set_stringn
+    pub fn width(&self) -> usize {
+        width::str_width(self.symbol)
+    }
+
     pub fn is_whitespace(&self) -> bool {
         let symbol = self.symbol;
         symbol == ZWSP || symbol.chars().all(char::is_whitespace) && symbol != NBSP
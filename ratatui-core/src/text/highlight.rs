@@ -0,0 +1,493 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::style::Style;
+use crate::text::truncate::slice;
+use crate::text::{Line, Span, TabStops, Text, width};
+
+/// A pattern to search for with [`Text::highlight`] and [`Line::highlight`].
+///
+/// A `&str` can be converted into a [`SearchPattern::Substring`]. With the `regex` feature, a
+/// `regex::Regex` can be converted into a `SearchPattern::Regex`.
+///
+/// Patterns never match across lines, and empty matches are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::style::Style;
+/// use ratatui_core::text::{SearchPattern, Text};
+///
+/// let mut text = Text::from("Error: disk full\nerror: retrying");
+/// let matches = text.highlight(SearchPattern::case_insensitive("error"), Style::new().red());
+/// assert_eq!(matches.len(), 2);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum SearchPattern<'p> {
+    /// Matches the exact substring.
+    Substring(&'p str),
+    /// Matches the substring, ignoring case.
+    ///
+    /// Case is compared using the Unicode lowercase mapping of each character.
+    CaseInsensitive(&'p str),
+    /// Matches a regular expression.
+    #[cfg(feature = "regex")]
+    Regex(&'p regex::Regex),
+}
+
+impl<'p> SearchPattern<'p> {
+    /// Creates a pattern that matches the exact substring.
+    pub const fn substring(pattern: &'p str) -> Self {
+        Self::Substring(pattern)
+    }
+
+    /// Creates a pattern that matches the substring, ignoring case.
+    pub const fn case_insensitive(pattern: &'p str) -> Self {
+        Self::CaseInsensitive(pattern)
+    }
+
+    /// Creates a pattern that matches a regular expression.
+    #[cfg(feature = "regex")]
+    pub const fn regex(regex: &'p regex::Regex) -> Self {
+        Self::Regex(regex)
+    }
+
+    /// Returns the byte ranges of the non-overlapping, non-empty matches in `haystack`.
+    fn find(&self, haystack: &str) -> Vec<Range<usize>> {
+        match self {
+            Self::Substring(pattern) if !pattern.is_empty() => haystack
+                .match_indices(pattern)
+                .map(|(start, matched)| start..start + matched.len())
+                .collect(),
+            Self::CaseInsensitive(pattern) if !pattern.is_empty() => {
+                find_case_insensitive(haystack, pattern)
+            }
+            #[cfg(feature = "regex")]
+            Self::Regex(regex) => regex
+                .find_iter(haystack)
+                .map(|found| found.range())
+                .filter(|range| !range.is_empty())
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl<'p> From<&'p str> for SearchPattern<'p> {
+    fn from(pattern: &'p str) -> Self {
+        Self::Substring(pattern)
+    }
+}
+
+impl<'p> From<&'p String> for SearchPattern<'p> {
+    fn from(pattern: &'p String) -> Self {
+        Self::Substring(pattern)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<'p> From<&'p regex::Regex> for SearchPattern<'p> {
+    fn from(regex: &'p regex::Regex) -> Self {
+        Self::Regex(regex)
+    }
+}
+
+/// The position of a match found by [`Text::highlight`] or [`Line::highlight`].
+///
+/// Positions are in display columns rather than bytes or characters, so they can be used directly
+/// to scroll a widget (e.g. with [`Line::slice_columns`]) to the next match.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TextMatch {
+    /// The index of the line containing the match. This is always 0 for [`Line::highlight`].
+    pub line: usize,
+    /// The column at which the match starts.
+    pub column: usize,
+    /// The width of the match in columns.
+    pub width: usize,
+}
+
+impl Text<'_> {
+    /// Patches the given style onto all the matches of a pattern, and returns their positions.
+    ///
+    /// Spans are split at the match boundaries, so only the matched graphemes are styled. Matches
+    /// that start or end in the middle of a grapheme are extended to the whole grapheme. See
+    /// [`SearchPattern`] for the supported patterns.
+    ///
+    /// `pattern` accepts a `&str` (matched as an exact substring) or any [`SearchPattern`].
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Style, Stylize};
+    /// use ratatui_core::text::{Line, Text, TextMatch};
+    ///
+    /// let mut text = Text::from("fn main() {\n    main_loop();\n}");
+    /// let matches = text.highlight("main", Style::new().on_yellow());
+    /// assert_eq!(
+    ///     matches,
+    ///     [
+    ///         TextMatch {
+    ///             line: 0,
+    ///             column: 3,
+    ///             width: 4
+    ///         },
+    ///         TextMatch {
+    ///             line: 1,
+    ///             column: 4,
+    ///             width: 4
+    ///         },
+    ///     ]
+    /// );
+    /// assert_eq!(
+    ///     text.lines[0],
+    ///     Line::from(vec!["fn ".into(), "main".on_yellow(), "() {".into()])
+    /// );
+    /// ```
+    ///
+    /// [`Color`]: crate::style::Color
+    pub fn highlight<'p, P, S>(&mut self, pattern: P, style: S) -> Vec<TextMatch>
+    where
+        P: Into<SearchPattern<'p>>,
+        S: Into<Style>,
+    {
+        let pattern = pattern.into();
+        let style = style.into();
+        let mut matches = Vec::new();
+        for (index, line) in self.lines.iter_mut().enumerate() {
+            let found = line.highlight(pattern, style);
+            matches.extend(found.into_iter().map(|m| TextMatch { line: index, ..m }));
+        }
+        matches
+    }
+}
+
+impl Line<'_> {
+    /// Patches the given style onto all the matches of a pattern, and returns their positions.
+    ///
+    /// Spans are split at the match boundaries, so only the matched graphemes are styled. A match
+    /// can span several spans. Matches that start or end in the middle of a grapheme are extended
+    /// to the whole grapheme. The [`TextMatch::line`] of the returned positions is always 0, and
+    /// their columns count tabs as the global [`TabStops`] expand them.
+    ///
+    /// `pattern` accepts a `&str` (matched as an exact substring) or any [`SearchPattern`].
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Style, Stylize};
+    /// use ratatui_core::text::{Line, SearchPattern};
+    ///
+    /// let mut line = Line::from(vec!["Hello, ".into(), "World".blue(), "!".into()]);
+    /// let matches = line.highlight(SearchPattern::case_insensitive("o, w"), Style::new().bold());
+    /// assert_eq!(matches[0].column, 4);
+    /// assert_eq!(
+    ///     line,
+    ///     Line::from(vec![
+    ///         "Hell".into(),
+    ///         "o, ".bold(),
+    ///         "W".blue().bold(),
+    ///         "orld".blue(),
+    ///         "!".into(),
+    ///     ])
+    /// );
+    /// ```
+    ///
+    /// [`Color`]: crate::style::Color
+    pub fn highlight<'p, P, S>(&mut self, pattern: P, style: S) -> Vec<TextMatch>
+    where
+        P: Into<SearchPattern<'p>>,
+        S: Into<Style>,
+    {
+        let content: String = self.spans.iter().map(|span| &*span.content).collect();
+        let ranges = snap_to_graphemes(&content, pattern.into().find(&content));
+        if ranges.is_empty() {
+            return Vec::new();
+        }
+        // measure with the tab stops used by `Line::width`, so that tabs before or in a match are
+        // counted as the columns they are displayed as
+        let tab_stops = if content.contains('\t') {
+            TabStops::global()
+        } else {
+            TabStops::default()
+        };
+        let matches = ranges
+            .iter()
+            .map(|range| {
+                let before = content.get(..range.start).unwrap_or_default();
+                let matched = content.get(range.clone()).unwrap_or_default();
+                let column = tab_stops.end_column(before, 0, width::str_width);
+                TextMatch {
+                    line: 0,
+                    column,
+                    width: tab_stops.end_column(matched, column, width::str_width) - column,
+                }
+            })
+            .collect();
+        self.spans = split_spans(&self.spans, &ranges, style.into());
+        matches
+    }
+}
+
+/// Finds the matches of `pattern` in `haystack`, comparing the lowercase forms of both.
+fn find_case_insensitive(haystack: &str, pattern: &str) -> Vec<Range<usize>> {
+    let pattern = pattern.to_lowercase();
+    // the lowercase form of the haystack, and the byte range in the haystack of the character
+    // each of its bytes comes from
+    let mut folded = String::with_capacity(haystack.len());
+    let mut origins = Vec::with_capacity(haystack.len());
+    for (index, c) in haystack.char_indices() {
+        let origin = index..index + c.len_utf8();
+        for lower in c.to_lowercase() {
+            folded.push(lower);
+            origins.extend(core::iter::repeat_n(origin.clone(), lower.len_utf8()));
+        }
+    }
+    folded
+        .match_indices(&pattern)
+        .filter_map(|(start, matched)| {
+            let first = origins.get(start)?;
+            let last = origins.get(start + matched.len() - 1)?;
+            Some(first.start..last.end)
+        })
+        .collect()
+}
+
+/// Extends the ranges to grapheme boundaries, merging ranges that end up overlapping.
+fn snap_to_graphemes(content: &str, ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    if ranges.is_empty() {
+        return ranges;
+    }
+    let boundaries: Vec<usize> = content
+        .grapheme_indices(true)
+        .map(|(index, _)| index)
+        .chain([content.len()])
+        .collect();
+    let mut snapped: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        let start = match boundaries.binary_search(&range.start) {
+            Ok(_) => range.start,
+            Err(index) => boundaries
+                .get(index.saturating_sub(1))
+                .copied()
+                .unwrap_or(0),
+        };
+        let end = match boundaries.binary_search(&range.end) {
+            Ok(_) => range.end,
+            Err(index) => boundaries.get(index).copied().unwrap_or(content.len()),
+        };
+        match snapped.last_mut() {
+            Some(last) if last.end > start => last.end = last.end.max(end),
+            _ => snapped.push(start..end),
+        }
+    }
+    snapped
+}
+
+/// Splits the spans at the boundaries of the (sorted, non-overlapping) byte ranges, and patches
+/// `style` onto the parts that are inside a range.
+fn split_spans<'a>(spans: &[Span<'a>], ranges: &[Range<usize>], style: Style) -> Vec<Span<'a>> {
+    let mut result = Vec::with_capacity(spans.len() + ranges.len() * 2);
+    let mut ranges = ranges.iter().peekable();
+    let mut offset = 0;
+    for span in spans {
+        let span_end = offset + span.content.len();
+        let mut position = offset;
+        while position < span_end {
+            // skip the ranges that end before the current position
+            while ranges.next_if(|range| range.end <= position).is_some() {}
+            let (end, highlighted) = match ranges.peek() {
+                Some(range) if range.start <= position => (range.end.min(span_end), true),
+                Some(range) => (range.start.min(span_end), false),
+                None => (span_end, false),
+            };
+            let content = slice(&span.content, position - offset, end - offset);
+            let part_style = if highlighted {
+                span.style.patch(style)
+            } else {
+                span.style
+            };
+            result.push(Span::styled(content, part_style));
+            position = end;
+        }
+        offset = span_end;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Stylize;
+
+    #[rstest]
+    #[case::substring(SearchPattern::substring("ab"), vec![0..2, 4..6])]
+    #[case::no_match(SearchPattern::substring("xyz"), vec![])]
+    #[case::empty(SearchPattern::substring(""), vec![])]
+    #[case::case_insensitive(SearchPattern::case_insensitive("AB"), vec![0..2, 4..6, 8..10])]
+    #[case::case_insensitive_empty(SearchPattern::case_insensitive(""), vec![])]
+    fn find(#[case] pattern: SearchPattern, #[case] expected: Vec<Range<usize>>) {
+        assert_eq!(pattern.find("ab--ab--AB"), expected);
+    }
+
+    #[test]
+    fn find_case_insensitive_maps_back_to_original_bytes() {
+        // 'ẞ' (3 bytes) lowercases to 'ß' (2 bytes)
+        assert_eq!(find_case_insensitive("xẞy STRASSE", "ßy"), vec![1..5]);
+        assert!(find_case_insensitive("Straße", "STRASSE").is_empty());
+        assert_eq!(
+            find_case_insensitive("ÉCOLE école", "École"),
+            vec![0..6, 7..13]
+        );
+    }
+
+    #[test]
+    fn highlight_single_span() {
+        let mut line = Line::from("one two one");
+        let matches = line.highlight("one", Style::new().red());
+        assert_eq!(
+            matches,
+            [
+                TextMatch {
+                    line: 0,
+                    column: 0,
+                    width: 3
+                },
+                TextMatch {
+                    line: 0,
+                    column: 8,
+                    width: 3
+                },
+            ]
+        );
+        assert_eq!(
+            line,
+            Line::from(vec!["one".red(), " two ".into(), "one".red()])
+        );
+    }
+
+    #[test]
+    fn highlight_across_spans() {
+        let mut line = Line::from(vec!["ab".green(), "cd".blue(), "ef".into()]);
+        line.highlight("bcde", Style::new().bold());
+        assert_eq!(
+            line,
+            Line::from(vec![
+                "a".green(),
+                "b".green().bold(),
+                "cd".blue().bold(),
+                "e".bold(),
+                "f".into(),
+            ])
+        );
+    }
+
+    #[test]
+    fn highlight_no_match_keeps_spans() {
+        let mut line = Line::from(vec!["ab".green(), "cd".blue()]);
+        let expected = line.clone();
+        assert!(line.highlight("x", Style::new().bold()).is_empty());
+        assert_eq!(line, expected);
+    }
+
+    #[test]
+    fn highlight_columns_use_display_width() {
+        let mut line = Line::from("日本語 text");
+        let matches = line.highlight("text", Style::new().bold());
+        assert_eq!(
+            matches,
+            [TextMatch {
+                line: 0,
+                column: 7,
+                width: 4
+            }]
+        );
+    }
+
+    #[test]
+    fn highlight_columns_expand_tabs() {
+        let mut line = Line::from("\tfoo\tbar");
+        let matches = line.highlight("foo", Style::new().bold());
+        assert_eq!(
+            matches,
+            [TextMatch {
+                line: 0,
+                column: 8,
+                width: 3
+            }]
+        );
+        let matches = line.highlight("o\tb", Style::new().bold());
+        assert_eq!(
+            matches,
+            [TextMatch {
+                line: 0,
+                column: 10,
+                width: 7
+            }]
+        );
+    }
+
+    #[test]
+    fn highlight_extends_to_graphemes() {
+        let mut line = Line::from("cafe\u{301} bar");
+        let matches = line.highlight("cafe", Style::new().bold());
+        assert_eq!(matches[0].width, 4);
+        assert_eq!(line, Line::from(vec!["cafe\u{301}".bold(), " bar".into()]));
+    }
+
+    #[test]
+    fn highlight_text() {
+        let mut text = Text::from("alpha\nbeta\nalphabet");
+        let matches = text.highlight(SearchPattern::case_insensitive("ALPHA"), Style::new().red());
+        assert_eq!(
+            matches,
+            [
+                TextMatch {
+                    line: 0,
+                    column: 0,
+                    width: 5
+                },
+                TextMatch {
+                    line: 2,
+                    column: 0,
+                    width: 5
+                },
+            ]
+        );
+        assert_eq!(text.lines[1], Line::from("beta"));
+        assert_eq!(text.lines[2], Line::from(vec!["alpha".red(), "bet".into()]));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn highlight_regex() {
+        let regex = regex::Regex::new(r"\d+").unwrap();
+        let mut line = Line::from("a1 b22 c");
+        let matches = line.highlight(&regex, Style::new().bold());
+        assert_eq!(matches.len(), 2);
+        assert_eq!(
+            line,
+            Line::from(vec![
+                "a".into(),
+                "1".bold(),
+                " b".into(),
+                "22".bold(),
+                " c".into()
+            ])
+        );
+    }
+}
@@ -12,7 +12,7 @@ use unicode_width::UnicodeWidthStr;
 use crate::buffer::Buffer;
 use crate::layout::{Alignment, Rect};
 use crate::style::{Style, Styled};
-use crate::text::{Span, StyledGrapheme, Text};
+use crate::text::{Span, StyledGrapheme, TabStops, Text, width};
 use crate::widgets::Widget;
 
 /// A line of text, consisting of one or more [`Span`]s.
@@ -213,6 +213,7 @@ impl fmt::Debug for Line<'_> {
             Some(Alignment::Left) => write!(f, ".left_aligned()"),
             Some(Alignment::Center) => write!(f, ".centered()"),
             Some(Alignment::Right) => write!(f, ".right_aligned()"),
+            Some(Alignment::Justify) => write!(f, ".alignment(HorizontalAlignment::Justify)"),
             None => Ok(()),
         }
     }
@@ -427,6 +428,9 @@ impl<'a> Line<'a> {
 
     /// Returns the width of the underlying string.
     ///
+    /// Tab characters are expanded to the global [`TabStops`], counting from the start of the
+    /// line.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -565,12 +569,32 @@ impl<'a> Line<'a> {
 }
 
 impl UnicodeWidthStr for Line<'_> {
+    /// Returns the width of the line, with tab characters expanded to the global [`TabStops`] and
+    /// other graphemes measured with the global [`WidthPolicy`].
+    ///
+    /// [`WidthPolicy`]: crate::text::WidthPolicy
     fn width(&self) -> usize {
-        self.spans.iter().map(UnicodeWidthStr::width).sum()
+        if !self.has_tabs() {
+            return self
+                .spans
+                .iter()
+                .map(|span| width::str_width(&span.content))
+                .sum();
+        }
+        let tab_stops = TabStops::global();
+        self.spans.iter().fold(0, |column, span| {
+            tab_stops.end_column(&span.content, column, width::str_width)
+        })
     }
 
     fn width_cjk(&self) -> usize {
-        self.spans.iter().map(UnicodeWidthStr::width_cjk).sum()
+        if !self.has_tabs() {
+            return self.spans.iter().map(|span| span.content.width_cjk()).sum();
+        }
+        let tab_stops = TabStops::global();
+        self.spans.iter().fold(0, |column, span| {
+            tab_stops.end_column(&span.content, column, UnicodeWidthStr::width_cjk)
+        })
     }
 }
 
@@ -708,6 +732,11 @@ impl Line<'_> {
         if area.is_empty() {
             return;
         }
+        if self.has_tabs() {
+            let expanded = self.expand_tabs(&TabStops::global());
+            expanded.render_with_alignment(area, buf, parent_alignment);
+            return;
+        }
         let area = Rect { height: 1, ..area };
         let line_width = self.width();
         if line_width == 0 {
@@ -724,7 +753,7 @@ impl Line<'_> {
             let indent_width = match alignment {
                 Some(Alignment::Center) => (area_width.saturating_sub(line_width)) / 2,
                 Some(Alignment::Right) => area_width.saturating_sub(line_width),
-                Some(Alignment::Left) | None => 0,
+                Some(Alignment::Left | Alignment::Justify) | None => 0,
             };
             let indent_width = u16::try_from(indent_width).unwrap_or(u16::MAX);
             let area = area.indent_x(indent_width);
@@ -735,7 +764,7 @@ impl Line<'_> {
             let skip_width = match alignment {
                 Some(Alignment::Center) => (line_width.saturating_sub(area_width)) / 2,
                 Some(Alignment::Right) => line_width.saturating_sub(area_width),
-                Some(Alignment::Left) | None => 0,
+                Some(Alignment::Left | Alignment::Justify) | None => 0,
             };
             render_spans(&self.spans, area, buf, skip_width);
         }
@@ -0,0 +1,752 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::mem;
+
+use pulldown_cmark::{Alignment, Event, Options, Parser, Tag, TagEnd};
+
+use crate::style::{Color, Style};
+use crate::text::{Line, Span, Text};
+
+/// The minimum width of a horizontal rule.
+const MIN_RULE_WIDTH: usize = 3;
+
+/// The maximum width of a horizontal rule.
+const MAX_RULE_WIDTH: usize = 80;
+
+/// The styles that are used to render Markdown with [`Text::from_markdown_with_style_sheet`].
+///
+/// Each style is [patched](Style::patch) onto the style of the surrounding content, so that e.g.
+/// emphasized text in a heading keeps the style of the heading.
+///
+/// This type is only available with the `markdown` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::style::{Style, Stylize};
+/// use ratatui_core::text::{Line, MarkdownStyleSheet, Text};
+///
+/// let style_sheet = MarkdownStyleSheet {
+///     strong: Style::new().bold().red(),
+///     ..MarkdownStyleSheet::default()
+/// };
+/// let text = Text::from_markdown_with_style_sheet("**Warning:** unsaved changes", &style_sheet);
+/// assert_eq!(
+///     text,
+///     Text::from(Line::from(vec![
+///         "Warning:".bold().red(),
+///         " unsaved changes".into(),
+///     ]))
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct MarkdownStyleSheet {
+    /// The styles of the headings, from level 1 (`#`) to level 6 (`######`).
+    pub headings: [Style; 6],
+    /// The style of emphasized text (`*text*`).
+    pub emphasis: Style,
+    /// The style of strong text (`**text**`).
+    pub strong: Style,
+    /// The style of strikethrough text (`~~text~~`).
+    pub strikethrough: Style,
+    /// The style of inline code (`` `code` ``).
+    pub code: Style,
+    /// The style of code blocks.
+    pub code_block: Style,
+    /// The style of the text of links and the alternative text of images.
+    pub link: Style,
+    /// The style of the URL that is shown after the text of links and images.
+    pub link_url: Style,
+    /// The style of block quotes, including the bar in front of each line.
+    pub block_quote: Style,
+    /// The style of the bullets and numbers of list items, and of task list markers.
+    pub list_marker: Style,
+    /// The style of the header row of tables.
+    pub table_header: Style,
+    /// The style of the lines between the columns and below the header of tables.
+    pub table_border: Style,
+    /// The style of horizontal rules.
+    pub horizontal_rule: Style,
+}
+
+impl Default for MarkdownStyleSheet {
+    fn default() -> Self {
+        Self {
+            headings: [
+                Style::new().bold().underlined(),
+                Style::new().bold(),
+                Style::new().bold().italic(),
+                Style::new().italic(),
+                Style::new().italic(),
+                Style::new().italic(),
+            ],
+            emphasis: Style::new().italic(),
+            strong: Style::new().bold(),
+            strikethrough: Style::new().crossed_out(),
+            code: Style::new().fg(Color::Yellow),
+            code_block: Style::new().fg(Color::Yellow),
+            link: Style::new().fg(Color::Blue).underlined(),
+            link_url: Style::new().fg(Color::DarkGray),
+            block_quote: Style::new().fg(Color::Gray).italic(),
+            list_marker: Style::new(),
+            table_header: Style::new().bold(),
+            table_border: Style::new().fg(Color::DarkGray),
+            horizontal_rule: Style::new().fg(Color::DarkGray),
+        }
+    }
+}
+
+impl Text<'_> {
+    /// Renders Markdown into a styled `Text` with the default [`MarkdownStyleSheet`].
+    ///
+    /// See [`Text::from_markdown_with_style_sheet`] for more details.
+    ///
+    /// This method is only available with the `markdown` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::{Line, Text};
+    ///
+    /// let text = Text::from_markdown("# Help\n\nPress `q` to *quit*.");
+    /// assert_eq!(
+    ///     text,
+    ///     Text::from(vec![
+    ///         Line::from("Help".bold().underlined()),
+    ///         Line::default(),
+    ///         Line::from(vec![
+    ///             "Press ".into(),
+    ///             "q".yellow(),
+    ///             " to ".into(),
+    ///             "quit".italic(),
+    ///             ".".into(),
+    ///         ]),
+    ///     ])
+    /// );
+    /// ```
+    pub fn from_markdown(input: &str) -> Text<'static> {
+        Self::from_markdown_with_style_sheet(input, &MarkdownStyleSheet::default())
+    }
+
+    /// Renders Markdown into a styled `Text` with the given style sheet.
+    ///
+    /// The input is parsed as [CommonMark](https://commonmark.org) with the tables, strikethrough
+    /// and task list extensions:
+    ///
+    /// - Blocks (paragraphs, headings, code blocks, lists, block quotes, tables and horizontal
+    ///   rules) are separated by an empty line.
+    /// - A paragraph is a single line, with soft line breaks replaced by spaces, so that it can be
+    ///   wrapped to the available width by rendering the text in a `Paragraph` with `Wrap`. Hard
+    ///   line breaks start a new line.
+    /// - Code blocks keep their lines as they are. Use `Wrap { trim: false }` to keep their
+    ///   indentation when they are wrapped.
+    /// - List items start with `•` or their number, and block quotes with a `│` bar. Nested blocks
+    ///   are indented below the list item that contains them. Lines that are wrapped by the
+    ///   `Paragraph` are not indented.
+    /// - Links and images are shown as their text, followed by the URL in parentheses unless the
+    ///   URL is the same as the text.
+    /// - Tables are laid out with aligned columns. Lines in tables are not wrapped by the
+    ///   `Paragraph` as long as they fit.
+    /// - Horizontal rules are as wide as the widest line, but no narrower than 3 and no wider than
+    ///   80 columns.
+    /// - HTML is shown as it is.
+    ///
+    /// This method is only available with the `markdown` feature.
+    pub fn from_markdown_with_style_sheet(
+        input: &str,
+        style_sheet: &MarkdownStyleSheet,
+    ) -> Text<'static> {
+        let options =
+            Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+        let mut renderer = Renderer::new(style_sheet);
+        for event in Parser::new_ext(input, options) {
+            renderer.event(event);
+        }
+        renderer.finish()
+    }
+}
+
+/// A block that adds a prefix to each of its lines, i.e. a list item or a block quote.
+struct Container {
+    /// The prefix of the first line, which is taken when the first line starts.
+    first: Option<Span<'static>>,
+    /// The prefix of the following lines.
+    rest: Span<'static>,
+    /// The prefix of empty lines, which is only shown for block quotes.
+    empty: Option<Span<'static>>,
+}
+
+/// A table whose cells are collected until the end of the table, so that the widths of the columns
+/// are known.
+struct Table {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<Line<'static>>>,
+    header_rows: usize,
+}
+
+/// A link or image whose URL is shown after its text.
+struct Link {
+    url: String,
+    text: String,
+}
+
+struct Renderer<'s> {
+    style_sheet: &'s MarkdownStyleSheet,
+    lines: Vec<Line<'static>>,
+    styles: Vec<Style>,
+    containers: Vec<Container>,
+    /// The number of the next item of each open list, or `None` for unordered lists.
+    lists: Vec<Option<u64>>,
+    /// The text and style of the current code or HTML block.
+    code_block: Option<(String, Style)>,
+    links: Vec<Link>,
+    table: Option<Table>,
+    /// The indices of the lines that contain horizontal rules.
+    rules: Vec<usize>,
+    /// Whether the next text starts a new line.
+    line_start: bool,
+    /// Whether an empty line is needed before the next block.
+    block_separator: bool,
+    /// The number of prefix spans of the current line, which text is not merged into.
+    prefix_len: usize,
+}
+
+impl<'s> Renderer<'s> {
+    const fn new(style_sheet: &'s MarkdownStyleSheet) -> Self {
+        Self {
+            style_sheet,
+            lines: Vec::new(),
+            styles: Vec::new(),
+            containers: Vec::new(),
+            lists: Vec::new(),
+            code_block: None,
+            links: Vec::new(),
+            table: None,
+            rules: Vec::new(),
+            line_start: true,
+            block_separator: false,
+            prefix_len: 0,
+        }
+    }
+
+    fn style(&self) -> Style {
+        self.styles.last().copied().unwrap_or_default()
+    }
+
+    fn push_style(&mut self, style: Style) {
+        self.styles.push(self.style().patch(style));
+    }
+
+    fn pop_style(&mut self) {
+        self.styles.pop();
+    }
+
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => {
+                if let Some((code, _)) = &mut self.code_block {
+                    code.push_str(&text);
+                } else {
+                    self.push_text(&text, self.style());
+                }
+            }
+            Event::Code(code) | Event::InlineMath(code) | Event::DisplayMath(code) => {
+                self.push_text(&code, self.style().patch(self.style_sheet.code));
+            }
+            Event::FootnoteReference(label) => {
+                self.push_text(&format!("[^{label}]"), self.style());
+            }
+            Event::SoftBreak => self.push_text(" ", self.style()),
+            Event::HardBreak => self.line_start = true,
+            Event::Rule => {
+                self.start_block();
+                self.new_line();
+                self.rules.push(self.lines.len() - 1);
+                self.end_block();
+            }
+            Event::TaskListMarker(checked) => {
+                let marker = if checked { "[x] " } else { "[ ] " };
+                self.push_text(marker, self.style().patch(self.style_sheet.list_marker));
+            }
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Paragraph => self.start_block(),
+            Tag::Heading { level, .. } => {
+                self.start_block();
+                self.push_style(self.style_sheet.headings[level as usize - 1]);
+            }
+            Tag::BlockQuote(_) => {
+                self.start_block();
+                let style = self.style().patch(self.style_sheet.block_quote);
+                self.containers.push(Container {
+                    first: None,
+                    rest: Span::styled("│ ", style),
+                    empty: Some(Span::styled("│", style)),
+                });
+                self.styles.push(style);
+            }
+            Tag::CodeBlock(_) => {
+                self.start_block();
+                let style = self.style().patch(self.style_sheet.code_block);
+                self.code_block = Some((String::new(), style));
+            }
+            Tag::HtmlBlock => {
+                self.start_block();
+                self.code_block = Some((String::new(), self.style()));
+            }
+            Tag::List(start) => {
+                self.start_block();
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.start_block();
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => String::from("• "),
+                };
+                let indent = " ".repeat(Span::raw(marker.as_str()).width());
+                let style = self.style().patch(self.style_sheet.list_marker);
+                self.containers.push(Container {
+                    first: Some(Span::styled(marker, style)),
+                    rest: Span::raw(indent),
+                    empty: None,
+                });
+            }
+            Tag::Emphasis => self.push_style(self.style_sheet.emphasis),
+            Tag::Strong => self.push_style(self.style_sheet.strong),
+            Tag::Strikethrough => self.push_style(self.style_sheet.strikethrough),
+            Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => {
+                self.push_style(self.style_sheet.link);
+                self.links.push(Link {
+                    url: dest_url.into_string(),
+                    text: String::new(),
+                });
+            }
+            Tag::Table(alignments) => {
+                self.start_block();
+                self.table = Some(Table {
+                    alignments,
+                    rows: Vec::new(),
+                    header_rows: 0,
+                });
+            }
+            Tag::TableHead => {
+                self.push_style(self.style_sheet.table_header);
+                if let Some(table) = &mut self.table {
+                    table.rows.push(Vec::new());
+                }
+            }
+            Tag::TableRow => {
+                if let Some(table) = &mut self.table {
+                    table.rows.push(Vec::new());
+                }
+            }
+            Tag::TableCell => {
+                if let Some(row) = self.table.as_mut().and_then(|table| table.rows.last_mut()) {
+                    row.push(Line::default());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.end_block(),
+            TagEnd::Heading(_) => {
+                self.pop_style();
+                self.end_block();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.containers.pop();
+                self.pop_style();
+                self.end_block();
+            }
+            TagEnd::CodeBlock | TagEnd::HtmlBlock => {
+                if let Some((code, style)) = self.code_block.take() {
+                    for line in code.lines() {
+                        self.new_line();
+                        self.push_text(line, style);
+                    }
+                }
+                self.end_block();
+            }
+            TagEnd::List(_) => {
+                self.lists.pop();
+                self.line_start = true;
+                // a nested list is not separated from the next item of the outer list
+                if self.lists.is_empty() {
+                    self.block_separator = true;
+                }
+            }
+            TagEnd::Item => {
+                // an empty item still shows its marker
+                if self
+                    .containers
+                    .last()
+                    .is_some_and(|container| container.first.is_some())
+                {
+                    self.new_line();
+                }
+                self.containers.pop();
+                self.line_start = true;
+            }
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => self.pop_style(),
+            TagEnd::Link | TagEnd::Image => {
+                self.pop_style();
+                if let Some(link) = self.links.pop() {
+                    let url = link.url.strip_prefix("mailto:").unwrap_or(&link.url);
+                    if !url.is_empty() && url != link.text {
+                        let style = self.style().patch(self.style_sheet.link_url);
+                        self.push_text(&format!(" ({})", link.url), style);
+                    }
+                }
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.push_table(table);
+                }
+                self.end_block();
+            }
+            TagEnd::TableHead => {
+                self.pop_style();
+                if let Some(table) = &mut self.table {
+                    table.header_rows = table.rows.len();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Separates a new block from the previous one with an empty line.
+    fn start_block(&mut self) {
+        if mem::take(&mut self.block_separator) {
+            let prefix: Vec<_> = self
+                .containers
+                .iter()
+                .filter_map(|container| container.empty.clone())
+                .collect();
+            self.lines.push(Line::from(prefix));
+        }
+        self.line_start = true;
+    }
+
+    const fn end_block(&mut self) {
+        self.line_start = true;
+        self.block_separator = true;
+    }
+
+    /// Starts a new line with the prefixes of the open containers.
+    fn new_line(&mut self) {
+        let prefix: Vec<_> = self
+            .containers
+            .iter_mut()
+            .map(|container| {
+                container
+                    .first
+                    .take()
+                    .unwrap_or_else(|| container.rest.clone())
+            })
+            .collect();
+        self.prefix_len = prefix.len();
+        self.lines.push(Line::from(prefix));
+        self.line_start = false;
+    }
+
+    fn push_text(&mut self, text: &str, style: Style) {
+        if text.is_empty() {
+            return;
+        }
+        for link in &mut self.links {
+            link.text.push_str(text);
+        }
+        let (line, prefix_len) = if let Some(table) = &mut self.table {
+            (table.rows.last_mut().and_then(|row| row.last_mut()), 0)
+        } else {
+            if self.line_start {
+                self.new_line();
+            }
+            (self.lines.last_mut(), self.prefix_len)
+        };
+        let Some(line) = line else {
+            return;
+        };
+        let mergeable = line.spans.len() > prefix_len;
+        match line.spans.last_mut() {
+            Some(span) if span.style == style && mergeable => {
+                span.content.to_mut().push_str(text);
+            }
+            _ => line.spans.push(Span::styled(String::from(text), style)),
+        }
+    }
+
+    fn push_table(&mut self, table: Table) {
+        let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut widths = vec![0; columns];
+        for row in &table.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = cell.width().max(*width);
+            }
+        }
+        let border = self.style().patch(self.style_sheet.table_border);
+        for (index, row) in table.rows.into_iter().enumerate() {
+            let mut spans = Vec::new();
+            let mut cells = row.into_iter();
+            for (column, &width) in widths.iter().enumerate() {
+                if column > 0 {
+                    spans.push(Span::styled(" │ ", border));
+                }
+                let cell = cells.next().unwrap_or_default();
+                let padding = width - cell.width();
+                let (before, after) = match table.alignments.get(column) {
+                    Some(Alignment::Right) => (padding, 0),
+                    Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+                    _ => (0, padding),
+                };
+                // don't add trailing whitespace to the last column
+                let after = if column + 1 == columns { 0 } else { after };
+                if before > 0 {
+                    spans.push(Span::raw(" ".repeat(before)));
+                }
+                spans.extend(cell.spans);
+                if after > 0 {
+                    spans.push(Span::raw(" ".repeat(after)));
+                }
+            }
+            self.new_line();
+            if let Some(line) = self.lines.last_mut() {
+                line.spans.extend(spans);
+            }
+            if index + 1 == table.header_rows {
+                self.new_line();
+                let separator = widths
+                    .iter()
+                    .map(|&width| "─".repeat(width))
+                    .collect::<Vec<_>>()
+                    .join("─┼─");
+                self.push_text(&separator, border);
+            }
+        }
+    }
+
+    fn finish(mut self) -> Text<'static> {
+        let width = self
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !self.rules.contains(index))
+            .map(|(_, line)| line.width())
+            .max()
+            .unwrap_or(0)
+            .clamp(MIN_RULE_WIDTH, MAX_RULE_WIDTH);
+        let style = self.style_sheet.horizontal_rule;
+        for &index in &self.rules {
+            let Some(line) = self.lines.get_mut(index) else {
+                continue;
+            };
+            let rule_width = width.saturating_sub(line.width()).max(MIN_RULE_WIDTH);
+            line.spans
+                .push(Span::styled(Cow::Owned("─".repeat(rule_width)), style));
+        }
+        Text::from(self.lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Stylize;
+
+    /// Returns the content of the lines of the text without their styles.
+    fn plain(text: &Text) -> Vec<String> {
+        text.lines.iter().map(ToString::to_string).collect()
+    }
+
+    #[rstest]
+    #[case::empty("", vec![])]
+    #[case::paragraphs("one\ntwo  \nthree\n\nfour", vec![Line::from("one two"), Line::from("three"), Line::default(), Line::from("four")])]
+    #[case::headings("# one\n## two\n###### six", vec![
+        Line::from("one".bold().underlined()),
+        Line::default(),
+        Line::from("two".bold()),
+        Line::default(),
+        Line::from("six".italic()),
+    ])]
+    #[case::inline("*a* **b** ~~c~~ `d` ***e***", vec![Line::from(vec![
+        "a".italic(),
+        " ".into(),
+        "b".bold(),
+        " ".into(),
+        "c".crossed_out(),
+        " ".into(),
+        "d".yellow(),
+        " ".into(),
+        "e".italic().bold(),
+    ])])]
+    #[case::code_block("text\n\n```rust\nfn main() {\n    run();\n}\n```\nafter", vec![
+        Line::from("text"),
+        Line::default(),
+        Line::from("fn main() {".yellow()),
+        Line::from("    run();".yellow()),
+        Line::from("}".yellow()),
+        Line::default(),
+        Line::from("after"),
+    ])]
+    #[case::links("[ratatui](https://ratatui.rs) <https://ratatui.rs> ![logo](logo.png)", vec![Line::from(vec![
+        "ratatui".blue().underlined(),
+        " (https://ratatui.rs)".dark_gray(),
+        " ".into(),
+        "https://ratatui.rs".blue().underlined(),
+        " ".into(),
+        "logo".blue().underlined(),
+        " (logo.png)".dark_gray(),
+    ])])]
+    #[case::html("a <b>bold</b> move", vec![Line::from("a <b>bold</b> move")])]
+    fn render(#[case] input: &str, #[case] expected: Vec<Line<'static>>) {
+        assert_eq!(Text::from_markdown(input), Text::from(expected));
+    }
+
+    #[test]
+    fn lists() {
+        let input = indoc! {"
+            - one
+            - two
+              1. nested
+              2. list
+            - [x] done
+
+            3. three
+
+               second paragraph
+            4. four
+        "};
+        assert_eq!(
+            plain(&Text::from_markdown(input)),
+            [
+                "• one",
+                "• two",
+                "  1. nested",
+                "  2. list",
+                "• [x] done",
+                "",
+                "3. three",
+                "",
+                "   second paragraph",
+                "",
+                "4. four",
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_list_item() {
+        assert_eq!(plain(&Text::from_markdown("-\n- a")), ["• ", "• a"]);
+    }
+
+    #[test]
+    fn block_quote() {
+        let input = indoc! {"
+            > quoted *text*
+            >
+            > - item
+        "};
+        let bar = Span::from("│ ").gray().italic();
+        assert_eq!(
+            Text::from_markdown(input),
+            Text::from(vec![
+                Line::from(vec![bar.clone(), "quoted text".gray().italic()]),
+                Line::from("│".gray().italic()),
+                Line::from(vec![bar, "• ".gray().italic(), "item".gray().italic()]),
+            ])
+        );
+    }
+
+    #[test]
+    fn table() {
+        let input = indoc! {"
+            | Name | Size | Kind |
+            |------|-----:|:----:|
+            | a    | 1    | file |
+            | long name | 1024 | dir |
+        "};
+        let border = |s: &'static str| Span::from(s).dark_gray();
+        assert_eq!(
+            Text::from_markdown(input),
+            Text::from(vec![
+                Line::from(vec![
+                    "Name".bold(),
+                    "     ".into(),
+                    border(" │ "),
+                    "Size".bold(),
+                    border(" │ "),
+                    "Kind".bold(),
+                ]),
+                Line::from(border("──────────┼──────┼─────")),
+                Line::from(vec![
+                    "a".into(),
+                    "        ".into(),
+                    border(" │ "),
+                    "   ".into(),
+                    "1".into(),
+                    border(" │ "),
+                    "file".into(),
+                ]),
+                Line::from(vec![
+                    "long name".into(),
+                    border(" │ "),
+                    "1024".into(),
+                    border(" │ "),
+                    "dir".into(),
+                ]),
+            ])
+        );
+    }
+
+    #[test]
+    fn horizontal_rule() {
+        assert_eq!(
+            Text::from_markdown("a\n\n---\n\nwider text"),
+            Text::from(vec![
+                Line::from("a"),
+                Line::default(),
+                Line::from("──────────".dark_gray()),
+                Line::default(),
+                Line::from("wider text"),
+            ])
+        );
+        assert_eq!(
+            Text::from_markdown("***"),
+            Text::from(Line::from("───".dark_gray()))
+        );
+    }
+
+    #[test]
+    fn style_sheet() {
+        let style_sheet = MarkdownStyleSheet {
+            headings: [Style::new().red(); 6],
+            emphasis: Style::new().underlined(),
+            ..MarkdownStyleSheet::default()
+        };
+        assert_eq!(
+            Text::from_markdown_with_style_sheet("# a *b*", &style_sheet),
+            Text::from(Line::from(vec!["a ".red(), "b".red().underlined()]))
+        );
+    }
+}
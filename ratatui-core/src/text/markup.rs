@@ -0,0 +1,454 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use thiserror::Error;
+
+use crate::style::{ParseStyleError, ParseStyleErrorKind, Style};
+use crate::text::{Line, Span, Text};
+
+/// An error that occurred while parsing markup with [`Text::from_markup`].
+///
+/// The error contains the [kind](MarkupErrorKind) of error and the byte offset in the input at
+/// which it occurred, so that it can be reported to whoever wrote the markup (e.g. in a
+/// configuration file).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
+#[error("{kind} at position {position}")]
+pub struct MarkupError {
+    /// The kind of error.
+    pub kind: MarkupErrorKind,
+    /// The byte offset in the input at which the error occurred.
+    pub position: usize,
+}
+
+/// The kind of a [`MarkupError`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
+pub enum MarkupErrorKind {
+    /// A `[` that starts a tag has no matching `]`.
+    #[error("unclosed tag")]
+    UnclosedTag,
+    /// A tag contains no style, e.g. `[]`.
+    #[error("empty tag")]
+    EmptyTag,
+    /// A word in a tag is neither a color nor a modifier.
+    #[error("unknown style `{0}`")]
+    UnknownStyle(String),
+    /// `on` is not followed by a color.
+    #[error("expected a background color after `on`")]
+    MissingColor,
+    /// `not` is not followed by a modifier.
+    #[error("expected a modifier after `not`")]
+    MissingModifier,
+    /// A closing tag was found while no tag is open.
+    #[error("unexpected closing tag")]
+    UnexpectedClosingTag,
+    /// A named closing tag doesn't match the most recently opened tag.
+    #[error("closing tag `[/{found}]` doesn't match the open tag `[{expected}]`")]
+    MismatchedClosingTag {
+        /// The style of the most recently opened tag.
+        expected: String,
+        /// The style of the closing tag.
+        found: String,
+    },
+    /// A `\` is followed by a character that doesn't need to be escaped, or by nothing.
+    #[error("invalid escape sequence")]
+    InvalidEscape,
+}
+
+impl MarkupError {
+    const fn new(kind: MarkupErrorKind, position: usize) -> Self {
+        Self { kind, position }
+    }
+}
+
+impl Text<'_> {
+    /// Parses markup into a styled `Text`.
+    ///
+    /// Markup is plain text in which styles are applied with tags in square brackets. This is a
+    /// compact way to describe styled text, e.g. for message templates stored in configuration
+    /// files:
+    ///
+    /// - `[style]` starts a styled section, and `[/]` ends the most recently started section.
+    ///   Sections can be nested, in which case the inner style is [patched](Style::patch) onto the
+    ///   outer style. Sections that are still open at the end of the input end there.
+    /// - `[/style]` also ends the most recently started section, but returns an error if the style
+    ///   doesn't match the style of the section, which helps catching mistakes in long templates.
+    /// - A style is a list of words separated by spaces, in the format parsed by
+    ///   [`Style::from_str`](core::str::FromStr::from_str):
+    ///   - a color sets the foreground color, e.g. `red`, `light-blue`, `#ff8000` or `208` (see
+    ///     [`Color`] for all the supported formats),
+    ///   - `on` followed by a color sets the background color, e.g. `on blue`,
+    ///   - a modifier adds the modifier, e.g. `bold`, `italic` or `underlined`. The abbreviations
+    ///     `b`, `i`, `u` and `s` (for `crossed_out`) are also supported,
+    ///   - `not` followed by a modifier removes the modifier, e.g. `not bold`,
+    ///   - `underline:` (or `ul:`) followed by a color sets the underline color, e.g. `ul:red`
+    ///     (requires the `underline-color` feature).
+    /// - `\[` is a literal `[` and `\\` is a literal `\`. `\]` is also accepted as a literal `]`,
+    ///   although `]` only has a special meaning inside tags.
+    /// - Newlines separate lines.
+    ///
+    /// On failure, the returned [`MarkupError`] contains the byte offset of the error in the input.
+    /// [`Text::to_markup`] does the reverse conversion.
+    ///
+    /// This method is only available with the `markup` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Style, Stylize};
+    /// use ratatui_core::text::{Line, MarkupErrorKind, Span, Text};
+    ///
+    /// let text = Text::from_markup("[bold red]Error:[/] file [u]{path}[/] not found")?;
+    /// assert_eq!(
+    ///     text,
+    ///     Text::from(Line::from(vec![
+    ///         "Error:".bold().red(),
+    ///         " file ".into(),
+    ///         "{path}".underlined(),
+    ///         " not found".into(),
+    ///     ]))
+    /// );
+    ///
+    /// let error = Text::from_markup("[bold blink]text").unwrap_err();
+    /// assert_eq!(error.kind, MarkupErrorKind::UnknownStyle("blink".into()));
+    /// assert_eq!(error.position, 6);
+    /// # Ok::<(), ratatui_core::text::MarkupError>(())
+    /// ```
+    pub fn from_markup(input: &str) -> Result<Text<'static>, MarkupError> {
+        Parser::default().parse(input)
+    }
+
+    /// Converts the text to markup that can be parsed back with [`Text::from_markup`].
+    ///
+    /// Each styled span is written in its own tag, and the characters that have a special meaning
+    /// in markup are escaped. The style and alignment of the text and its lines are not included.
+    ///
+    /// This method is only available with the `markup` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::{Line, Text};
+    ///
+    /// let text = Text::from(Line::from(vec![
+    ///     "Error:".bold().red(),
+    ///     " [1] failed".into(),
+    /// ]));
+    /// assert_eq!(text.to_markup(), r"[red bold]Error:[/] \[1] failed");
+    /// assert_eq!(Text::from_markup(&text.to_markup()), Ok(text));
+    /// ```
+    pub fn to_markup(&self) -> String {
+        let lines: Vec<String> = self.lines.iter().map(Line::to_markup).collect();
+        lines.join("\n")
+    }
+}
+
+impl Line<'_> {
+    /// Converts the line to markup that can be parsed back with [`Text::from_markup`].
+    ///
+    /// See [`Text::to_markup`] for more details.
+    ///
+    /// This method is only available with the `markup` feature.
+    pub fn to_markup(&self) -> String {
+        let mut markup = String::new();
+        for span in &self.spans {
+            let tag = span.style.to_string();
+            if tag.is_empty() {
+                escape_into(&mut markup, &span.content);
+            } else {
+                markup.push('[');
+                markup.push_str(&tag);
+                markup.push(']');
+                escape_into(&mut markup, &span.content);
+                markup.push_str("[/]");
+            }
+        }
+        markup
+    }
+}
+
+#[derive(Debug, Default)]
+struct Parser<'a> {
+    lines: Vec<Line<'static>>,
+    spans: Vec<Span<'static>>,
+    content: String,
+    /// The open tags, with their content and style.
+    open: Vec<(&'a str, Style)>,
+}
+
+impl<'a> Parser<'a> {
+    fn parse(mut self, input: &'a str) -> Result<Text<'static>, MarkupError> {
+        if input.is_empty() {
+            return Ok(Text::default());
+        }
+        let mut chars = input.char_indices().peekable();
+        while let Some((position, c)) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some((_, escaped @ ('[' | ']' | '\\'))) => self.content.push(escaped),
+                    _ => return Err(MarkupError::new(MarkupErrorKind::InvalidEscape, position)),
+                },
+                '[' => {
+                    let start = position + 1;
+                    let rest = input.get(start..).unwrap_or_default();
+                    let Some(len) = rest.find(']') else {
+                        return Err(MarkupError::new(MarkupErrorKind::UnclosedTag, position));
+                    };
+                    let tag = rest.get(..len).unwrap_or_default();
+                    self.tag(tag, start)?;
+                    // skip the content of the tag and the closing bracket
+                    while chars.next_if(|&(i, _)| i <= start + len).is_some() {}
+                }
+                '\r' if chars.peek().is_some_and(|&(_, c)| c == '\n') => {}
+                '\n' => {
+                    self.flush();
+                    self.lines
+                        .push(Line::from(core::mem::take(&mut self.spans)));
+                }
+                c => self.content.push(c),
+            }
+        }
+        self.flush();
+        self.lines.push(Line::from(self.spans));
+        Ok(Text::from(self.lines))
+    }
+
+    /// Handles the tag whose content starts at `position`.
+    fn tag(&mut self, tag: &'a str, position: usize) -> Result<(), MarkupError> {
+        if let Some(name) = tag.strip_prefix('/') {
+            let Some((open, _)) = self.open.last() else {
+                let kind = MarkupErrorKind::UnexpectedClosingTag;
+                return Err(MarkupError::new(kind, position - 1));
+            };
+            let name = name.trim();
+            if !name.is_empty() && name != open.trim() {
+                let kind = MarkupErrorKind::MismatchedClosingTag {
+                    expected: open.trim().to_string(),
+                    found: name.to_string(),
+                };
+                return Err(MarkupError::new(kind, position - 1));
+            }
+            self.flush();
+            self.open.pop();
+        } else {
+            let style = parse_style(tag, position)?;
+            self.flush();
+            self.open.push((tag, style));
+        }
+        Ok(())
+    }
+
+    /// Adds the pending content as a span with the style of the open tags.
+    fn flush(&mut self) {
+        if self.content.is_empty() {
+            return;
+        }
+        let content = core::mem::take(&mut self.content);
+        let style = self
+            .open
+            .iter()
+            .fold(Style::new(), |style, (_, patch)| style.patch(*patch));
+        match self.spans.last_mut() {
+            Some(last) if last.style == style => last.content.to_mut().push_str(&content),
+            _ => self.spans.push(Span::styled(content, style)),
+        }
+    }
+}
+
+/// Parses the content of a tag that starts at `position` in the input.
+fn parse_style(tag: &str, position: usize) -> Result<Style, MarkupError> {
+    if tag.trim().is_empty() {
+        return Err(MarkupError::new(MarkupErrorKind::EmptyTag, position - 1));
+    }
+    tag.parse().map_err(|error: ParseStyleError| {
+        let kind = match error.kind {
+            ParseStyleErrorKind::UnknownStyle(word) => MarkupErrorKind::UnknownStyle(word),
+            ParseStyleErrorKind::MissingColor => MarkupErrorKind::MissingColor,
+            ParseStyleErrorKind::MissingModifier => MarkupErrorKind::MissingModifier,
+        };
+        MarkupError::new(kind, position + error.position)
+    })
+}
+
+fn escape_into(markup: &mut String, content: &str) {
+    for c in content.chars() {
+        if matches!(c, '[' | '\\') {
+            markup.push('\\');
+        }
+        markup.push(c);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::{Color, Stylize};
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(Text::from_markup(""), Ok(Text::default()));
+        assert_eq!(
+            Text::from_markup("hello\nworld"),
+            Ok(Text::from(vec![Line::from("hello"), Line::from("world")]))
+        );
+        assert_eq!(
+            Text::from_markup("a]b\r\n"),
+            Ok(Text::from(vec![Line::from("a]b"), Line::default()]))
+        );
+    }
+
+    #[test]
+    fn nested_styles() {
+        let text = Text::from_markup("[red]a[bold on blue]b[not bold]c[/][/]d[/]e").unwrap();
+        assert_eq!(
+            text,
+            Text::from(Line::from(vec![
+                "a".red(),
+                "b".red().bold().on_blue(),
+                "c".red().on_blue().not_bold(),
+                "d".red(),
+                "e".into(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn styles_continue_across_lines() {
+        let text = Text::from_markup("[italic]a\nb[/]c").unwrap();
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("a".italic()),
+                Line::from(vec!["b".italic(), "c".into()]),
+            ])
+        );
+    }
+
+    #[test]
+    fn unclosed_tags_end_with_input() {
+        assert_eq!(
+            Text::from_markup("[b]bold"),
+            Ok(Text::from(Line::from("bold".bold())))
+        );
+    }
+
+    #[test]
+    fn empty_sections_are_merged() {
+        assert_eq!(
+            Text::from_markup("a[b][/]b"),
+            Ok(Text::from(Line::from("ab")))
+        );
+    }
+
+    #[rstest]
+    #[case::named("[Light-Blue]", Style::new().light_blue())]
+    #[case::hex("[#ff8000]", Style::new().fg(Color::Rgb(255, 128, 0)))]
+    #[case::indexed("[208]", Style::new().fg(Color::Indexed(208)))]
+    #[case::background("[on red]", Style::new().on_red())]
+    #[case::abbreviations("[b i u s]", Style::new().bold().italic().underlined().crossed_out())]
+    #[case::modifiers(
+        "[dim slow-blink rapid_blink reversed hidden]",
+        Style::new().dim().slow_blink().rapid_blink().reversed().hidden()
+    )]
+    #[case::remove_modifier("[not italic]", Style::new().not_italic())]
+    #[case::extra_spaces("[ bold  red ]", Style::new().bold().red())]
+    fn styles(#[case] markup: &str, #[case] style: Style) {
+        let input = alloc::format!("{markup}x");
+        assert_eq!(
+            Text::from_markup(&input),
+            Ok(Text::from(Line::from(Span::styled("x", style))))
+        );
+    }
+
+    #[cfg(feature = "underline-color")]
+    #[test]
+    fn underline_color() {
+        assert_eq!(
+            Text::from_markup("[u ul:red]x"),
+            Ok(Text::from(Line::from(Span::styled(
+                "x",
+                Style::new().underlined().underline_color(Color::Red)
+            ))))
+        );
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(
+            Text::from_markup(r"\[not a tag] \\ [b]\[x\][/]"),
+            Ok(Text::from(Line::from(vec![
+                r"[not a tag] \ ".into(),
+                "[x]".bold()
+            ])))
+        );
+    }
+
+    #[test]
+    fn named_closing_tag() {
+        assert_eq!(
+            Text::from_markup("[bold red]a[/bold red]b"),
+            Ok(Text::from(Line::from(vec!["a".bold().red(), "b".into()])))
+        );
+    }
+
+    #[rstest]
+    #[case::unclosed_tag("ab[bold", MarkupErrorKind::UnclosedTag, 2)]
+    #[case::empty_tag("ab[ ]", MarkupErrorKind::EmptyTag, 2)]
+    #[case::unknown_style("[bold blink]", MarkupErrorKind::UnknownStyle("blink".into()), 6)]
+    #[case::unknown_background("[on sky]", MarkupErrorKind::UnknownStyle("sky".into()), 4)]
+    #[case::missing_color("[red on]", MarkupErrorKind::MissingColor, 5)]
+    #[case::missing_modifier("[not]", MarkupErrorKind::MissingModifier, 1)]
+    #[case::unknown_modifier("[not red]", MarkupErrorKind::UnknownStyle("red".into()), 5)]
+    #[case::unexpected_close("a[/]", MarkupErrorKind::UnexpectedClosingTag, 1)]
+    #[case::mismatched_close(
+        "[b][red]x[/b]",
+        MarkupErrorKind::MismatchedClosingTag { expected: "red".into(), found: "b".into() },
+        9
+    )]
+    #[case::invalid_escape(r"a\n", MarkupErrorKind::InvalidEscape, 1)]
+    #[case::trailing_escape("a\\", MarkupErrorKind::InvalidEscape, 1)]
+    fn errors(#[case] input: &str, #[case] kind: MarkupErrorKind, #[case] position: usize) {
+        assert_eq!(
+            Text::from_markup(input),
+            Err(MarkupError { kind, position })
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        let error = Text::from_markup("[/]").unwrap_err();
+        assert_eq!(error.to_string(), "unexpected closing tag at position 0");
+        let error = Text::from_markup("[b]x[/i]").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "closing tag `[/i]` doesn't match the open tag `[b]` at position 4"
+        );
+    }
+
+    #[test]
+    fn to_markup() {
+        let text = Text::from(vec![
+            Line::from(vec![
+                "plain ".into(),
+                "styled".red().on_black().bold().not_italic(),
+            ]),
+            Line::from(Span::styled(r"[x] \ y", Color::Rgb(1, 2, 3))),
+        ]);
+        assert_eq!(
+            text.to_markup(),
+            "plain [red on black bold not italic]styled[/]\n[#010203]\\[x] \\\\ y[/]"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let markup = "[bold red]Error:[/] file [u]{path}[/] \\[1]\n[on 42 not dim]next[/] line";
+        let text = Text::from_markup(markup).unwrap();
+        assert_eq!(Text::from_markup(&text.to_markup()), Ok(text));
+    }
+}
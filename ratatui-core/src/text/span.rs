@@ -8,7 +8,7 @@ use unicode_width::UnicodeWidthStr;
 use crate::buffer::Buffer;
 use crate::layout::Rect;
 use crate::style::{Style, Styled};
-use crate::text::{Line, StyledGrapheme};
+use crate::text::{Line, StyledGrapheme, TabStops, width};
 use crate::widgets::Widget;
 
 /// Represents a part of a line that is contiguous and where all characters share the same style.
@@ -268,6 +268,11 @@ impl<'a> Span<'a> {
     }
 
     /// Returns the unicode width of the content held by this span.
+    ///
+    /// Tab characters are expanded to the global [`TabStops`], counting from the start of the
+    /// span. Other graphemes are measured with the global [`WidthPolicy`].
+    ///
+    /// [`WidthPolicy`]: crate::text::WidthPolicy
     pub fn width(&self) -> usize {
         UnicodeWidthStr::width(self)
     }
@@ -377,12 +382,22 @@ impl<'a> Span<'a> {
 }
 
 impl UnicodeWidthStr for Span<'_> {
+    /// Returns the width of the content, with tab characters expanded to the default
+    /// [`TabStops`] and other graphemes measured with the global [`WidthPolicy`].
+    ///
+    /// [`WidthPolicy`]: crate::text::WidthPolicy
     fn width(&self) -> usize {
-        self.content.width()
+        if !self.content.contains('\t') {
+            return width::str_width(&self.content);
+        }
+        TabStops::global().end_column(&self.content, 0, width::str_width)
     }
 
     fn width_cjk(&self) -> usize {
-        self.content.width_cjk()
+        if !self.content.contains('\t') {
+            return self.content.width_cjk();
+        }
+        TabStops::global().end_column(&self.content, 0, UnicodeWidthStr::width_cjk)
     }
 }
 
@@ -429,7 +444,7 @@ impl Widget for &Span<'_> {
         }
         let Rect { mut x, y, .. } = area;
         for (i, grapheme) in self.styled_graphemes(Style::default()).enumerate() {
-            let symbol_width = grapheme.symbol.width();
+            let symbol_width = width::str_width(grapheme.symbol);
             let next_x = x.saturating_add(symbol_width as u16);
             if next_x > area.right() {
                 break;
@@ -0,0 +1,362 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::text::{Line, Span, Text, width};
+
+/// The positions of the tab stops used to expand tab characters (`\t`).
+///
+/// A tab character moves the cursor to the next tab stop, so it is displayed as one or more
+/// spaces. Tab stops are counted in columns from the start of the line, which keeps columns of
+/// source code and tab-separated data aligned.
+///
+/// Tabs are expanded consistently with the [global](TabStops::global) tab stops (by default every
+/// 8 columns) by [`Span::width`], [`Line::width`], [`Buffer::set_stringn`], [`Buffer::set_line`]
+/// and so by all the widgets. Use [`TabStops::set_global`] to change the tab stops of the whole
+/// application, [`Line::expand_tabs`] or [`Text::expand_tabs`] to expand tabs with other tab stops
+/// before rendering, or configure the tab stops of a widget that supports it (e.g. `Paragraph`).
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::text::{Line, TabStops};
+///
+/// let line = Line::from("a\tbc\td");
+/// assert_eq!(line.width(), 17);
+/// assert_eq!(
+///     line.expand_tabs(&TabStops::every(4)).to_string(),
+///     "a   bc  d"
+/// );
+/// assert_eq!(
+///     line.expand_tabs(&TabStops::at([2, 10])).to_string(),
+///     "a bc      d"
+/// );
+/// ```
+///
+/// [`Buffer::set_stringn`]: crate::buffer::Buffer::set_stringn
+/// [`Buffer::set_line`]: crate::buffer::Buffer::set_line
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TabStops {
+    /// Explicit tab stops, in increasing order
+    stops: Vec<u16>,
+    /// The distance between the tab stops after the last explicit stop
+    width: u16,
+}
+
+impl Default for TabStops {
+    /// Returns tab stops every [`TabStops::DEFAULT_WIDTH`] columns.
+    fn default() -> Self {
+        Self::every(Self::DEFAULT_WIDTH)
+    }
+}
+
+impl TabStops {
+    /// The default distance between tab stops, as used by most terminals.
+    pub const DEFAULT_WIDTH: u16 = 8;
+
+    /// Creates tab stops every `width` columns.
+    ///
+    /// A width of 0 removes tab characters.
+    pub const fn every(width: u16) -> Self {
+        Self {
+            stops: Vec::new(),
+            width,
+        }
+    }
+
+    /// Creates tab stops at the given columns.
+    ///
+    /// The columns don't need to be sorted. After the last stop, a tab character is displayed as a
+    /// single space, unless [`TabStops::then_every`] is used.
+    pub fn at<I: IntoIterator<Item = u16>>(stops: I) -> Self {
+        let mut stops: Vec<u16> = stops.into_iter().collect();
+        stops.sort_unstable();
+        stops.dedup();
+        Self { stops, width: 1 }
+    }
+
+    /// Sets the distance between the tab stops that follow the last explicit stop.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::TabStops;
+    ///
+    /// let tab_stops = TabStops::at([2, 10]).then_every(4);
+    /// assert_eq!(tab_stops.next_stop(10), 14);
+    /// assert_eq!(tab_stops.next_stop(15), 18);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn then_every(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Returns the column of the tab stop that follows `column`.
+    ///
+    /// This is the column at which the text that follows a tab character written at `column`
+    /// starts. If tabs are removed (see [`TabStops::every`]), `column` is returned.
+    pub fn next_stop(&self, column: usize) -> usize {
+        if let Some(&stop) = self.stops.iter().find(|&&stop| usize::from(stop) > column) {
+            return usize::from(stop);
+        }
+        if self.width == 0 {
+            return column;
+        }
+        let width = usize::from(self.width);
+        let base = self.stops.last().map_or(0, |&stop| usize::from(stop));
+        base + ((column - base) / width + 1) * width
+    }
+
+    /// Returns the process-wide tab stops.
+    ///
+    /// These are the tab stops set with [`TabStops::set_global`], or the default tab stops (every
+    /// [`TabStops::DEFAULT_WIDTH`] columns) if none were set.
+    #[cfg(feature = "std")]
+    pub fn global() -> Self {
+        global::get()
+    }
+
+    /// Returns the process-wide tab stops, which are always the default tab stops without the
+    /// `std` feature.
+    #[cfg(not(feature = "std"))]
+    pub const fn global() -> Self {
+        Self::every(Self::DEFAULT_WIDTH)
+    }
+
+    /// Sets these tab stops as the process-wide tab stops.
+    ///
+    /// The global tab stops are used to expand tabs everywhere tab stops are not given explicitly,
+    /// e.g. by [`Line::width`], [`Buffer::set_line`] and the widgets that render text, such as
+    /// `Table` and `List`. They should be set once at startup, before anything is rendered.
+    ///
+    /// This method is only available with the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,standalone_crate
+    /// use ratatui_core::text::{Line, TabStops};
+    ///
+    /// TabStops::every(4).set_global();
+    /// assert_eq!(Line::from("a\tb").width(), 5);
+    /// ```
+    ///
+    /// [`Buffer::set_line`]: crate::buffer::Buffer::set_line
+    #[cfg(feature = "std")]
+    pub fn set_global(self) {
+        global::set(self);
+    }
+
+    /// Returns the column at which `content` ends when it starts at `column`.
+    pub(crate) fn end_column(
+        &self,
+        content: &str,
+        column: usize,
+        width: fn(&str) -> usize,
+    ) -> usize {
+        if !content.contains('\t') {
+            return column + width(content);
+        }
+        let mut segments = content.split('\t');
+        let first = segments.next().unwrap_or_default();
+        segments.fold(column + width(first), |column, segment| {
+            self.next_stop(column) + width(segment)
+        })
+    }
+}
+
+/// The global tab stops, which can only be changed with the `std` feature.
+#[cfg(feature = "std")]
+mod global {
+    use core::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{PoisonError, RwLock};
+
+    use super::TabStops;
+
+    /// Whether the global tab stops differ from the default ones, which avoids taking the lock in
+    /// the common case
+    static CUSTOM: AtomicBool = AtomicBool::new(false);
+
+    static ACTIVE: RwLock<TabStops> = RwLock::new(TabStops::every(TabStops::DEFAULT_WIDTH));
+
+    pub fn get() -> TabStops {
+        if !CUSTOM.load(Ordering::Acquire) {
+            return TabStops::default();
+        }
+        ACTIVE
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    pub fn set(tab_stops: TabStops) {
+        let custom = tab_stops != TabStops::default();
+        *ACTIVE.write().unwrap_or_else(PoisonError::into_inner) = tab_stops;
+        CUSTOM.store(custom, Ordering::Release);
+    }
+}
+
+impl Line<'_> {
+    /// Returns a copy of this line where tab characters are replaced by spaces.
+    ///
+    /// Each tab character is replaced by as many spaces as needed to reach the next tab stop,
+    /// counting columns from the start of the line. The spaces take the style of the span that
+    /// contained the tab. Spans without tabs are not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::{Line, TabStops};
+    ///
+    /// let line = Line::from(vec!["name\t".into(), "value\tunit".into()]);
+    /// assert_eq!(
+    ///     line.expand_tabs(&TabStops::every(4)).to_string(),
+    ///     "name    value   unit"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn expand_tabs(&self, tab_stops: &TabStops) -> Self {
+        let mut column = 0;
+        let spans = self
+            .spans
+            .iter()
+            .map(|span| {
+                let start = column;
+                column = tab_stops.end_column(&span.content, start, width::str_width);
+                if span.content.contains('\t') {
+                    let content = expand_str(&span.content, start, tab_stops);
+                    Span::styled(content, span.style)
+                } else {
+                    span.clone()
+                }
+            })
+            .collect();
+        Self {
+            spans,
+            style: self.style,
+            alignment: self.alignment,
+        }
+    }
+
+    /// Returns true if any span of this line contains a tab character.
+    pub(crate) fn has_tabs(&self) -> bool {
+        self.spans.iter().any(|span| span.content.contains('\t'))
+    }
+}
+
+impl Text<'_> {
+    /// Returns a copy of this text where tab characters are replaced by spaces.
+    ///
+    /// Each line is expanded separately with [`Line::expand_tabs`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::{TabStops, Text};
+    ///
+    /// let text = Text::from("a\tb\nccccc\td");
+    /// assert_eq!(
+    ///     text.expand_tabs(&TabStops::every(4)).to_string(),
+    ///     "a   b\nccccc   d"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn expand_tabs(&self, tab_stops: &TabStops) -> Self {
+        Self {
+            lines: self
+                .lines
+                .iter()
+                .map(|line| line.expand_tabs(tab_stops))
+                .collect(),
+            style: self.style,
+            alignment: self.alignment,
+        }
+    }
+}
+
+/// Replaces the tabs in `content`, which starts at `column`, with spaces.
+fn expand_str<'a>(content: &str, mut column: usize, tab_stops: &TabStops) -> Cow<'a, str> {
+    let mut expanded = String::with_capacity(content.len());
+    for (index, segment) in content.split('\t').enumerate() {
+        if index > 0 {
+            let next = tab_stops.next_stop(column);
+            expanded.extend(core::iter::repeat_n(' ', next - column));
+            column = next;
+        }
+        expanded.push_str(segment);
+        column += width::str_width(segment);
+    }
+    Cow::Owned(expanded)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::buffer::Buffer;
+    use crate::layout::Rect;
+    use crate::style::Stylize;
+    use crate::widgets::Widget;
+
+    #[rstest]
+    #[case::every_8(TabStops::default(), [(0, 8), (7, 8), (8, 16), (9, 16)])]
+    #[case::every_4(TabStops::every(4), [(0, 4), (3, 4), (4, 8), (10, 12)])]
+    #[case::removed(TabStops::every(0), [(0, 0), (3, 3), (4, 4), (10, 10)])]
+    #[case::at(TabStops::at([10, 2]), [(0, 2), (2, 10), (9, 10), (10, 11)])]
+    #[case::then_every(TabStops::at([2, 10]).then_every(4), [(1, 2), (5, 10), (10, 14), (15, 18)])]
+    fn next_stop(#[case] tab_stops: TabStops, #[case] cases: [(usize, usize); 4]) {
+        for (column, expected) in cases {
+            assert_eq!(tab_stops.next_stop(column), expected, "column {column}");
+        }
+    }
+
+    #[test]
+    fn width_expands_tabs() {
+        assert_eq!(Span::raw("\t").width(), 8);
+        assert_eq!(Span::raw("ab\tc").width(), 9);
+        assert_eq!(Span::raw("日本\t").width(), 8);
+        // tab stops are counted from the start of the line, not the start of each span
+        assert_eq!(Line::from(vec!["abc".into(), "\td".into()]).width(), 9);
+        assert_eq!(Text::from("a\tb\nc").width(), 9);
+    }
+
+    #[test]
+    fn render_expands_tabs() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+        let text = Text::from_iter([
+            Line::from(vec!["ab".into(), "\tc".into()]),
+            Line::from("abcdefghi\tj").right_aligned(),
+        ]);
+        text.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["ab      c   ", "fghi       j"]));
+    }
+
+    #[test]
+    fn expand_tabs_keeps_styles() {
+        let line = Line::from(vec!["ab".red(), "\tc".green(), "d\t".blue()]).centered();
+        let expanded = line.expand_tabs(&TabStops::every(4));
+        assert_eq!(
+            expanded,
+            Line::from(vec!["ab".red(), "  c".green(), "d  ".blue()]).centered()
+        );
+    }
+
+    #[test]
+    fn expand_tabs_with_wide_characters() {
+        let line = Line::from("日本\tx");
+        assert_eq!(line.expand_tabs(&TabStops::every(3)).to_string(), "日本  x");
+    }
+
+    #[test]
+    fn expand_tabs_without_tabs_keeps_borrowed_content() {
+        let line = Line::from("no tabs");
+        let expanded = line.expand_tabs(&TabStops::default());
+        assert!(matches!(expanded.spans[0].content, Cow::Borrowed(_)));
+        assert_eq!(expanded, line);
+    }
+}
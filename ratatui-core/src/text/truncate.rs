@@ -0,0 +1,425 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::style::Style;
+use crate::text::{Line, Span, TabStops, Text, width};
+
+/// Which part of a line is removed when it is truncated.
+///
+/// This is used with [`Truncation`] and [`Line::truncate`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TruncationMode {
+    /// Removes the end of the line and places the marker at the end (default).
+    ///
+    /// `"Hello, world!"` becomes `"Hello, w…"`.
+    #[default]
+    End,
+    /// Removes the start of the line and places the marker at the start.
+    ///
+    /// `"Hello, world!"` becomes `"…, world!"`. This is useful for paths, where the last part is
+    /// usually the most relevant.
+    Start,
+    /// Removes the middle of the line and places the marker in the middle.
+    ///
+    /// `"Hello, world!"` becomes `"Hell…rld!"`.
+    Middle,
+}
+
+/// Describes how a line that is too wide is truncated.
+///
+/// A truncation combines a [`TruncationMode`], which decides which part of the content is removed,
+/// with a marker that is displayed in place of the removed content. The default marker is
+/// [`Truncation::ELLIPSIS`] (`…`).
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::text::{Line, Truncation};
+///
+/// let line = Line::from("Hello, world!");
+/// assert_eq!(line.truncate(9, Truncation::end()).to_string(), "Hello, w…");
+/// assert_eq!(
+///     line.truncate(9, Truncation::start()).to_string(),
+///     "…, world!"
+/// );
+/// assert_eq!(
+///     line.truncate(9, Truncation::middle().marker(".."))
+///         .to_string(),
+///     "Hell..ld!"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Truncation {
+    mode: TruncationMode,
+    marker: &'static str,
+}
+
+impl Default for Truncation {
+    fn default() -> Self {
+        Self::new(TruncationMode::End)
+    }
+}
+
+impl From<TruncationMode> for Truncation {
+    fn from(mode: TruncationMode) -> Self {
+        Self::new(mode)
+    }
+}
+
+impl Truncation {
+    /// The default marker, a horizontal ellipsis (`…`).
+    pub const ELLIPSIS: &'static str = "…";
+
+    /// Creates a new truncation with the given mode and the default [`Truncation::ELLIPSIS`]
+    /// marker.
+    pub const fn new(mode: TruncationMode) -> Self {
+        Self {
+            mode,
+            marker: Self::ELLIPSIS,
+        }
+    }
+
+    /// Creates a truncation that removes the end of the line.
+    pub const fn end() -> Self {
+        Self::new(TruncationMode::End)
+    }
+
+    /// Creates a truncation that removes the start of the line.
+    pub const fn start() -> Self {
+        Self::new(TruncationMode::Start)
+    }
+
+    /// Creates a truncation that removes the middle of the line.
+    pub const fn middle() -> Self {
+        Self::new(TruncationMode::Middle)
+    }
+
+    /// Sets the marker displayed in place of the removed content.
+    ///
+    /// An empty marker cuts the content without any indication. If the marker is wider than the
+    /// available width, the content is cut without a marker.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn marker(mut self, marker: &'static str) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Returns the truncation mode.
+    pub const fn mode(&self) -> TruncationMode {
+        self.mode
+    }
+
+    /// Returns the marker displayed in place of the removed content.
+    pub const fn marker_symbol(&self) -> &'static str {
+        self.marker
+    }
+}
+
+impl Line<'_> {
+    /// Returns a copy of this line that fits in `width` columns.
+    ///
+    /// If the line is wider than `width`, part of its content is replaced by a marker according
+    /// to the given [`Truncation`] (or [`TruncationMode`]). Lines that already fit are returned
+    /// unchanged. Truncation never splits a grapheme, so a wide character that does not fit is
+    /// removed entirely and the result may be narrower than `width`. The tabs of a line that is
+    /// truncated are expanded to the global [`TabStops`] first.
+    ///
+    /// The marker is added as a separate span, which takes the style of the span in which the cut
+    /// happens. The style and alignment of the line are preserved. Borrowed content stays borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::{Line, TruncationMode};
+    ///
+    /// let line = Line::from(vec!["src/".blue(), "widgets/block.rs".into()]);
+    /// assert_eq!(
+    ///     line.truncate(10, TruncationMode::Start),
+    ///     Line::from(vec!["…".into(), "/block.rs".into()])
+    /// );
+    /// assert_eq!(
+    ///     line.truncate(6, TruncationMode::End),
+    ///     Line::from(vec!["src/".blue(), "w".into(), "…".into()])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn truncate(&self, width: u16, truncation: impl Into<Truncation>) -> Self {
+        let truncation = truncation.into();
+        if self.width() <= usize::from(width) {
+            return self.clone();
+        }
+        if self.has_tabs() {
+            // expand the tabs so that the graphemes are measured at the columns they are displayed
+            return self
+                .expand_tabs(&TabStops::global())
+                .truncate(width, truncation);
+        }
+        let width = usize::from(width);
+        let mut marker = truncation.marker;
+        if width::str_width(marker) > width {
+            marker = "";
+        }
+        let available = width - width::str_width(marker);
+
+        let spans = match truncation.mode {
+            TruncationMode::End => {
+                let (mut spans, style) = take_prefix(&self.spans, available);
+                push_marker(&mut spans, marker, style);
+                spans
+            }
+            TruncationMode::Start => {
+                let (suffix, style) = take_suffix(&self.spans, available);
+                let mut spans = Vec::with_capacity(suffix.len() + 1);
+                push_marker(&mut spans, marker, style);
+                spans.extend(suffix);
+                spans
+            }
+            TruncationMode::Middle => {
+                // the left side gets the extra column when the available width is odd
+                let (mut spans, style) = take_prefix(&self.spans, available.div_ceil(2));
+                let (suffix, _) = take_suffix(&self.spans, available / 2);
+                push_marker(&mut spans, marker, style);
+                spans.extend(suffix);
+                spans
+            }
+        };
+        Self {
+            spans,
+            style: self.style,
+            alignment: self.alignment,
+        }
+    }
+}
+
+impl Text<'_> {
+    /// Returns a copy of this text where every line fits in `width` columns.
+    ///
+    /// Each line is truncated separately with [`Line::truncate`]. The style and alignment of the
+    /// text are preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::{Text, TruncationMode};
+    ///
+    /// let text = Text::from("short\na much longer line");
+    /// assert_eq!(
+    ///     text.truncate(8, TruncationMode::End).to_string(),
+    ///     "short\na much …"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn truncate(&self, width: u16, truncation: impl Into<Truncation>) -> Self {
+        let truncation = truncation.into();
+        Self {
+            lines: self
+                .lines
+                .iter()
+                .map(|line| line.truncate(width, truncation))
+                .collect(),
+            style: self.style,
+            alignment: self.alignment,
+        }
+    }
+}
+
+fn push_marker(spans: &mut Vec<Span<'_>>, marker: &'static str, style: Style) {
+    if !marker.is_empty() {
+        spans.push(Span::styled(marker, style));
+    }
+}
+
+/// Returns the spans that fit in the first `max_width` columns, and the style of the span in which
+/// the cut happens.
+pub(crate) fn take_prefix<'a>(spans: &[Span<'a>], max_width: usize) -> (Vec<Span<'a>>, Style) {
+    let mut result = Vec::new();
+    let mut remaining = max_width;
+    for span in spans {
+        let span_width = span.width();
+        if span_width <= remaining {
+            remaining -= span_width;
+            result.push(span.clone());
+            continue;
+        }
+        let mut end = 0;
+        for (index, grapheme) in span.content.grapheme_indices(true) {
+            let grapheme_width = width::str_width(grapheme);
+            if grapheme_width > remaining {
+                break;
+            }
+            remaining -= grapheme_width;
+            end = index + grapheme.len();
+        }
+        if end > 0 {
+            result.push(Span::styled(slice(&span.content, 0, end), span.style));
+        }
+        return (result, span.style);
+    }
+    let style = spans.last().map_or_else(Style::new, |span| span.style);
+    (result, style)
+}
+
+/// Returns the spans that fit in the last `max_width` columns, and the style of the span in which
+/// the cut happens.
+pub(crate) fn take_suffix<'a>(spans: &[Span<'a>], max_width: usize) -> (Vec<Span<'a>>, Style) {
+    let mut result = Vec::new();
+    let mut remaining = max_width;
+    let mut cut_style = spans.first().map_or_else(Style::new, |span| span.style);
+    for span in spans.iter().rev() {
+        let span_width = span.width();
+        if span_width <= remaining {
+            remaining -= span_width;
+            result.push(span.clone());
+            continue;
+        }
+        let mut start = span.content.len();
+        for (index, grapheme) in span.content.grapheme_indices(true).rev() {
+            let grapheme_width = width::str_width(grapheme);
+            if grapheme_width > remaining {
+                break;
+            }
+            remaining -= grapheme_width;
+            start = index;
+        }
+        if start < span.content.len() {
+            let end = span.content.len();
+            result.push(Span::styled(slice(&span.content, start, end), span.style));
+        }
+        cut_style = span.style;
+        break;
+    }
+    result.reverse();
+    (result, cut_style)
+}
+
+/// Slices a `Cow<str>`, keeping borrowed content borrowed.
+///
+/// `start` and `end` must be on character boundaries.
+pub(crate) fn slice<'a>(content: &Cow<'a, str>, start: usize, end: usize) -> Cow<'a, str> {
+    match content {
+        Cow::Borrowed(content) => Cow::Borrowed(content.get(start..end).unwrap_or_default()),
+        Cow::Owned(content) => Cow::Owned(content.get(start..end).unwrap_or_default().into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::layout::Alignment;
+    use crate::style::Stylize;
+
+    #[rstest]
+    #[case::fits(TruncationMode::End, 13, "Hello, world!")]
+    #[case::end(TruncationMode::End, 9, "Hello, w…")]
+    #[case::start(TruncationMode::Start, 9, "…, world!")]
+    #[case::middle(TruncationMode::Middle, 9, "Hell…rld!")]
+    #[case::middle_even(TruncationMode::Middle, 8, "Hell…ld!")]
+    #[case::only_marker(TruncationMode::End, 1, "…")]
+    #[case::zero(TruncationMode::Middle, 0, "")]
+    fn truncate(#[case] mode: TruncationMode, #[case] width: u16, #[case] expected: &str) {
+        let line = Line::from("Hello, world!");
+        assert_eq!(line.truncate(width, mode).to_string(), expected);
+    }
+
+    #[rstest]
+    #[case::end(Truncation::end(), 6, "abc...")]
+    #[case::start(Truncation::start(), 6, "...hij")]
+    #[case::middle(Truncation::middle(), 7, "ab...ij")]
+    #[case::marker_too_wide(Truncation::end(), 2, "ab")]
+    fn truncate_custom_marker(
+        #[case] truncation: Truncation,
+        #[case] width: u16,
+        #[case] expected: &str,
+    ) {
+        let line = Line::from("abcdefghij");
+        assert_eq!(
+            line.truncate(width, truncation.marker("...")).to_string(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::end(TruncationMode::End, 4, "日…")]
+    #[case::end_odd(TruncationMode::End, 5, "日本…")]
+    #[case::start(TruncationMode::Start, 4, "…語")]
+    #[case::middle(TruncationMode::Middle, 5, "日…語")]
+    fn truncate_wide_graphemes(
+        #[case] mode: TruncationMode,
+        #[case] width: u16,
+        #[case] expected: &str,
+    ) {
+        let line = Line::from("日本語");
+        assert_eq!(line.truncate(width, mode).to_string(), expected);
+    }
+
+    #[test]
+    fn truncate_does_not_split_graphemes() {
+        let line = Line::from("e\u{301}e\u{301}e\u{301}");
+        assert_eq!(
+            line.truncate(2, TruncationMode::End).to_string(),
+            "e\u{301}…"
+        );
+    }
+
+    #[test]
+    fn truncate_expands_tabs() {
+        let line = Line::from("a\tbcdefghij");
+        let truncated = line.truncate(10, TruncationMode::End);
+        assert_eq!(truncated.to_string(), "a       b…");
+        assert_eq!(truncated.width(), 10);
+        let truncated = line.truncate(10, TruncationMode::Start);
+        assert_eq!(truncated.to_string(), "…bcdefghij");
+        // lines that fit keep their tabs
+        assert_eq!(line.truncate(17, TruncationMode::End), line);
+    }
+
+    #[test]
+    fn truncate_marker_takes_style_of_cut_span() {
+        let line = Line::from(vec!["abc".red(), "def".green(), "ghi".blue()]);
+        assert_eq!(
+            line.truncate(5, TruncationMode::End),
+            Line::from(vec!["abc".red(), "d".green(), "…".green()])
+        );
+        assert_eq!(
+            line.truncate(4, TruncationMode::Start),
+            Line::from(vec!["…".green(), "ghi".blue()])
+        );
+        assert_eq!(
+            line.truncate(7, TruncationMode::Middle),
+            Line::from(vec!["abc".red(), "…".green(), "ghi".blue()])
+        );
+    }
+
+    #[test]
+    fn truncate_keeps_line_style_and_alignment() {
+        let line = Line::from("Hello, world!").yellow().centered();
+        let truncated = line.truncate(6, TruncationMode::End);
+        assert_eq!(truncated.style, line.style);
+        assert_eq!(truncated.alignment, Some(Alignment::Center));
+    }
+
+    #[test]
+    fn truncate_text() {
+        let text = Text::from("abc\nabcdef").red().right_aligned();
+        let truncated = text.truncate(4, TruncationMode::Start);
+        assert_eq!(truncated.to_string(), "abc\n…def");
+        assert_eq!(truncated.style, text.style);
+        assert_eq!(truncated.alignment, text.alignment);
+    }
+
+    #[test]
+    fn truncate_keeps_borrowed_content() {
+        let line = Line::from("Hello, world!");
+        let truncated = line.truncate(6, TruncationMode::End);
+        assert!(matches!(truncated.spans[0].content, Cow::Borrowed("Hello")));
+    }
+}
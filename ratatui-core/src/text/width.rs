@@ -0,0 +1,231 @@
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// The process-wide width policy, encoded with [`WidthPolicy::to_bits`].
+static GLOBAL_POLICY: AtomicU8 = AtomicU8::new(0);
+
+/// The variation selector that requests the emoji presentation of the preceding character.
+const VS16: char = '\u{FE0F}';
+
+/// How many columns characters with an [East Asian Width] of "Ambiguous" take.
+///
+/// Ambiguous characters (e.g. `±`, `§`, `①`, Greek and Cyrillic letters) are displayed as one
+/// column by most terminals, but as two columns by terminals configured for CJK locales.
+///
+/// [East Asian Width]: https://www.unicode.org/reports/tr11/
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AmbiguousWidth {
+    /// Ambiguous characters take one column (default).
+    #[default]
+    Narrow,
+    /// Ambiguous characters take two columns, as in CJK terminals.
+    Wide,
+}
+
+/// How many columns emoji presentation sequences take.
+///
+/// An emoji presentation sequence is a character followed by the variation selector U+FE0F
+/// (VS16), e.g. `"❤\u{FE0F}"`. Unicode specifies that these are displayed as two columns, but some
+/// terminals ignore the variation selector and display the character with its text presentation
+/// width.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum EmojiPresentation {
+    /// Emoji presentation sequences take two columns, as specified by Unicode (default).
+    #[default]
+    Wide,
+    /// The variation selector is ignored, so emoji presentation sequences take as many columns as
+    /// the character without the selector.
+    Narrow,
+}
+
+/// The policy used to compute the display width of graphemes.
+///
+/// Terminals disagree on the width of some characters, and when ratatui's idea of the width of a
+/// grapheme doesn't match the terminal's, the rest of the line is displayed shifted. The width
+/// policy lets applications match the behavior of the terminal they run in.
+///
+/// The policy is process-wide: it is set with [`WidthPolicy::set_global`] and consulted by
+/// [`Span::width`], [`Line::width`], [`StyledGrapheme::width`], [`Buffer::set_stringn`] and
+/// [`Buffer::diff`], and so by the wrapping and truncation of text. It should be set once at
+/// startup, before anything is rendered.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::text::{AmbiguousWidth, EmojiPresentation, WidthPolicy};
+///
+/// let policy = WidthPolicy::new().ambiguous(AmbiguousWidth::Wide);
+/// assert_eq!(policy.str_width("①②"), 4);
+/// assert_eq!(WidthPolicy::default().str_width("①②"), 2);
+///
+/// let policy = WidthPolicy::new().emoji_presentation(EmojiPresentation::Narrow);
+/// assert_eq!(policy.str_width("❤\u{FE0F}"), 1);
+/// assert_eq!(WidthPolicy::default().str_width("❤\u{FE0F}"), 2);
+/// ```
+///
+/// [`Span::width`]: crate::text::Span::width
+/// [`Line::width`]: crate::text::Line::width
+/// [`StyledGrapheme::width`]: crate::text::StyledGrapheme::width
+/// [`Buffer::set_stringn`]: crate::buffer::Buffer::set_stringn
+/// [`Buffer::diff`]: crate::buffer::Buffer::diff
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct WidthPolicy {
+    ambiguous: AmbiguousWidth,
+    emoji_presentation: EmojiPresentation,
+}
+
+impl WidthPolicy {
+    /// Creates the default width policy, which follows Unicode for a non-CJK context.
+    pub const fn new() -> Self {
+        Self {
+            ambiguous: AmbiguousWidth::Narrow,
+            emoji_presentation: EmojiPresentation::Wide,
+        }
+    }
+
+    /// Sets the width of East Asian Ambiguous characters.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn ambiguous(mut self, ambiguous: AmbiguousWidth) -> Self {
+        self.ambiguous = ambiguous;
+        self
+    }
+
+    /// Sets the width of emoji presentation sequences.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn emoji_presentation(mut self, emoji_presentation: EmojiPresentation) -> Self {
+        self.emoji_presentation = emoji_presentation;
+        self
+    }
+
+    /// Returns the width of East Asian Ambiguous characters.
+    pub const fn ambiguous_width(self) -> AmbiguousWidth {
+        self.ambiguous
+    }
+
+    /// Returns the width of emoji presentation sequences.
+    pub const fn emoji_presentation_width(self) -> EmojiPresentation {
+        self.emoji_presentation
+    }
+
+    /// Returns the process-wide width policy.
+    pub fn global() -> Self {
+        Self::from_bits(GLOBAL_POLICY.load(Ordering::Relaxed))
+    }
+
+    /// Sets this policy as the process-wide width policy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,standalone_crate
+    /// use ratatui_core::text::{AmbiguousWidth, Span, WidthPolicy};
+    ///
+    /// // configure ratatui for a terminal that uses a CJK locale
+    /// WidthPolicy::new().ambiguous(AmbiguousWidth::Wide).set_global();
+    /// assert_eq!(Span::raw("①②").width(), 4);
+    /// ```
+    pub fn set_global(self) {
+        GLOBAL_POLICY.store(self.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Returns the display width of a string according to this policy.
+    pub fn str_width(self, content: &str) -> usize {
+        let width = match self.ambiguous {
+            AmbiguousWidth::Narrow => UnicodeWidthStr::width,
+            AmbiguousWidth::Wide => UnicodeWidthStr::width_cjk,
+        };
+        if self.emoji_presentation == EmojiPresentation::Wide || !content.contains(VS16) {
+            return width(content);
+        }
+        content
+            .graphemes(true)
+            .map(|grapheme| {
+                if grapheme.contains(VS16) {
+                    let (base, _) = grapheme.split_once(VS16).unwrap_or((grapheme, ""));
+                    width(base)
+                } else {
+                    width(grapheme)
+                }
+            })
+            .sum()
+    }
+
+    const fn to_bits(self) -> u8 {
+        let ambiguous = match self.ambiguous {
+            AmbiguousWidth::Narrow => 0,
+            AmbiguousWidth::Wide => 1,
+        };
+        let emoji_presentation = match self.emoji_presentation {
+            EmojiPresentation::Wide => 0,
+            EmojiPresentation::Narrow => 2,
+        };
+        ambiguous | emoji_presentation
+    }
+
+    const fn from_bits(bits: u8) -> Self {
+        Self {
+            ambiguous: if bits & 1 == 0 {
+                AmbiguousWidth::Narrow
+            } else {
+                AmbiguousWidth::Wide
+            },
+            emoji_presentation: if bits & 2 == 0 {
+                EmojiPresentation::Wide
+            } else {
+                EmojiPresentation::Narrow
+            },
+        }
+    }
+}
+
+/// Returns the display width of a string according to the global [`WidthPolicy`].
+pub(crate) fn str_width(content: &str) -> usize {
+    WidthPolicy::global().str_width(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::ascii(WidthPolicy::new(), "abc", 3)]
+    #[case::cjk(WidthPolicy::new(), "日本", 4)]
+    #[case::ambiguous_narrow(WidthPolicy::new(), "±§①", 3)]
+    #[case::ambiguous_wide(WidthPolicy::new().ambiguous(AmbiguousWidth::Wide), "±§①", 6)]
+    #[case::vs16_wide(WidthPolicy::new(), "a❤\u{FE0F}b", 4)]
+    #[case::vs16_narrow(
+        WidthPolicy::new().emoji_presentation(EmojiPresentation::Narrow),
+        "a❤\u{FE0F}b",
+        3
+    )]
+    #[case::vs16_narrow_wide_base(
+        WidthPolicy::new().emoji_presentation(EmojiPresentation::Narrow),
+        "😀\u{FE0F}",
+        2
+    )]
+    #[case::flag(WidthPolicy::new(), "🇯🇵", 2)]
+    fn str_width(#[case] policy: WidthPolicy, #[case] content: &str, #[case] expected: usize) {
+        assert_eq!(policy.str_width(content), expected);
+    }
+
+    #[rstest]
+    #[case(AmbiguousWidth::Narrow, EmojiPresentation::Wide)]
+    #[case(AmbiguousWidth::Wide, EmojiPresentation::Wide)]
+    #[case(AmbiguousWidth::Narrow, EmojiPresentation::Narrow)]
+    #[case(AmbiguousWidth::Wide, EmojiPresentation::Narrow)]
+    fn bits_round_trip(#[case] ambiguous: AmbiguousWidth, #[case] emoji: EmojiPresentation) {
+        let policy = WidthPolicy::new()
+            .ambiguous(ambiguous)
+            .emoji_presentation(emoji);
+        assert_eq!(WidthPolicy::from_bits(policy.to_bits()), policy);
+    }
+
+    #[test]
+    fn default_is_new() {
+        assert_eq!(WidthPolicy::default(), WidthPolicy::new());
+        assert_eq!(WidthPolicy::default().to_bits(), 0);
+    }
+}
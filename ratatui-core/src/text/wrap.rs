@@ -0,0 +1,615 @@
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::layout::Alignment;
+use crate::style::Style;
+use crate::text::{Line, Span, StyledGrapheme, TabStops, Text};
+
+/// Where lines can be broken when wrapping text.
+///
+/// This is used with [`WrapOptions::break_mode`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum BreakMode {
+    /// Breaks lines between words (default).
+    ///
+    /// Words that are longer than the available width are broken at the width.
+    #[default]
+    WordBoundary,
+    /// Breaks lines at the available width, even in the middle of a word.
+    ///
+    /// This is useful for content without meaningful word boundaries, such as hashes, URLs or
+    /// base64 encoded data.
+    Anywhere,
+}
+
+/// Options for wrapping text with [`Text::wrap`] and [`WordWrapper`].
+///
+/// By default, lines are broken between words, and leading whitespace is not trimmed.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::text::{BreakMode, WrapOptions};
+///
+/// let options = WrapOptions::new()
+///     .trim(true)
+///     .break_mode(BreakMode::WordBoundary)
+///     .hanging_indent(2);
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct WrapOptions {
+    trim: bool,
+    break_mode: BreakMode,
+    preserve_indentation: bool,
+    hanging_indent: u16,
+}
+
+impl WrapOptions {
+    /// Creates the default wrap options.
+    pub const fn new() -> Self {
+        Self {
+            trim: false,
+            break_mode: BreakMode::WordBoundary,
+            preserve_indentation: false,
+            hanging_indent: 0,
+        }
+    }
+
+    /// Sets whether leading whitespace is removed from wrapped lines.
+    ///
+    /// When the whitespace between two words doesn't fit at the end of a line, it is always
+    /// removed. With `trim` enabled, the leading whitespace of a line is also removed when the
+    /// first word doesn't fit on the line together with it.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Sets where lines can be broken.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn break_mode(mut self, break_mode: BreakMode) -> Self {
+        self.break_mode = break_mode;
+        self
+    }
+
+    /// Sets whether continuation lines are indented like the line that they continue.
+    ///
+    /// When enabled, the leading whitespace of each line is repeated at the start of the lines
+    /// that it wraps into, which keeps e.g. indented list items readable. The indentation is
+    /// ignored when it leaves no space for content.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn preserve_indentation(mut self, preserve_indentation: bool) -> Self {
+        self.preserve_indentation = preserve_indentation;
+        self
+    }
+
+    /// Sets the number of columns by which continuation lines are indented.
+    ///
+    /// The hanging indent is added after the preserved indentation (see
+    /// [`WrapOptions::preserve_indentation`]). It is ignored when it leaves no space for content.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn hanging_indent(mut self, hanging_indent: u16) -> Self {
+        self.hanging_indent = hanging_indent;
+        self
+    }
+}
+
+impl Text<'_> {
+    /// Wraps the lines of the text to the given width.
+    ///
+    /// Each line is broken into as many lines as needed so that none is wider than `width`,
+    /// according to the given [`WrapOptions`]. The wrapped lines keep the style and alignment of
+    /// the line that they were wrapped from, and the text keeps its style and alignment. Lines
+    /// with [`Alignment::Justify`] are justified, except for the last line that each line wraps
+    /// into.
+    ///
+    /// This is the same wrapping that the `Paragraph` widget uses, so it can be used to measure or
+    /// lay out wrapped text consistently in other widgets. A width of 0 results in an empty text.
+    /// Tab characters are expanded to the global [`TabStops`] before wrapping.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::{Text, WrapOptions};
+    ///
+    /// let text = Text::from("The quick brown fox jumps over the lazy dog");
+    /// let wrapped = text.wrap(16, WrapOptions::new().trim(true));
+    /// assert_eq!(
+    ///     wrapped,
+    ///     Text::from_iter(["The quick brown", "fox jumps over", "the lazy dog"])
+    /// );
+    ///
+    /// let text = Text::from("- a list item that is long enough to wrap");
+    /// let wrapped = text.wrap(20, WrapOptions::new().trim(true).hanging_indent(2));
+    /// assert_eq!(
+    ///     wrapped,
+    ///     Text::from_iter(["- a list item that", "  is long enough to", "  wrap"])
+    /// );
+    /// ```
+    pub fn wrap(&self, width: u16, options: WrapOptions) -> Text<'static> {
+        let mut lines = Vec::new();
+        for line in &self.lines {
+            let expanded;
+            let line = if line.has_tabs() {
+                expanded = line.expand_tabs(&TabStops::global());
+                &expanded
+            } else {
+                line
+            };
+            let graphemes = line
+                .spans
+                .iter()
+                .flat_map(|span| span.styled_graphemes(Style::default()));
+            let alignment = line.alignment.or(self.alignment).unwrap_or_default();
+            let mut wrapper =
+                WordWrapper::new([(graphemes, alignment)].into_iter(), width, options);
+            while let Some(wrapped) = wrapper.next_line() {
+                lines.push(Line {
+                    style: line.style,
+                    alignment: line.alignment,
+                    spans: graphemes_to_spans(wrapped.graphemes),
+                });
+            }
+        }
+        Text {
+            alignment: self.alignment,
+            style: self.style,
+            lines,
+        }
+    }
+}
+
+/// Joins graphemes with the same style into spans.
+fn graphemes_to_spans(graphemes: &[StyledGrapheme<'_>]) -> Vec<Span<'static>> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    for grapheme in graphemes {
+        match spans.last_mut() {
+            Some(span) if span.style == grapheme.style => {
+                span.content.to_mut().push_str(grapheme.symbol);
+            }
+            _ => spans.push(Span::styled(String::from(grapheme.symbol), grapheme.style)),
+        }
+    }
+    spans
+}
+
+/// A line that has been wrapped to a certain width.
+///
+/// This is returned by [`WordWrapper::next_line`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct WrappedLine<'lend, 'text> {
+    /// One line reflowed to the correct width
+    pub graphemes: &'lend [StyledGrapheme<'text>],
+    /// The width of the line
+    pub width: u16,
+    /// The alignment of the line
+    pub alignment: Alignment,
+}
+
+/// A state machine that wraps lines on word boundaries.
+///
+/// This is the lower level API behind [`Text::wrap`] for widgets that render the wrapped lines
+/// directly, without building a new [`Text`]. The lines to wrap are given as an iterator over the
+/// styled graphemes and the alignment of each line, and the wrapped lines are computed lazily, one
+/// at a time, with [`WordWrapper::next_line`].
+///
+/// The wrapper cannot implement `Iterator`, as it yields slices of its internal buffer.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::layout::Alignment;
+/// use ratatui_core::style::Style;
+/// use ratatui_core::text::{Line, WordWrapper, WrapOptions};
+///
+/// let line = Line::from("Hello, world!");
+/// let lines = [(line.styled_graphemes(Style::default()), Alignment::Left)];
+/// let mut wrapper = WordWrapper::new(lines.into_iter(), 8, WrapOptions::new());
+/// assert_eq!(wrapper.next_line().map(|line| line.width), Some(6));
+/// assert_eq!(wrapper.next_line().map(|line| line.width), Some(6));
+/// assert_eq!(wrapper.next_line(), None);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct WordWrapper<'a, O, I>
+where
+    // Outer iterator providing the individual lines
+    O: Iterator<Item = (I, Alignment)>,
+    // Inner iterator providing the styled symbols of a line Each line consists of an alignment and
+    // a series of symbols
+    I: Iterator<Item = StyledGrapheme<'a>>,
+{
+    /// The given, unprocessed lines
+    input_lines: O,
+    max_line_width: u16,
+    wrapped_lines: VecDeque<Vec<StyledGrapheme<'a>>>,
+    current_alignment: Alignment,
+    current_line: Vec<StyledGrapheme<'a>>,
+    options: WrapOptions,
+
+    // These are cached allocations that hold no state across next_line invocations
+    pending_word: Vec<StyledGrapheme<'a>>,
+    pending_whitespace: VecDeque<StyledGrapheme<'a>>,
+    pending_line_pool: Vec<Vec<StyledGrapheme<'a>>>,
+    /// The preserved indentation of the line that is being wrapped
+    first_indent: Vec<StyledGrapheme<'a>>,
+    /// The indentation of the continuation lines of the line that is being wrapped
+    indent: Vec<StyledGrapheme<'a>>,
+}
+
+impl<'a, O, I> WordWrapper<'a, O, I>
+where
+    O: Iterator<Item = (I, Alignment)>,
+    I: Iterator<Item = StyledGrapheme<'a>>,
+{
+    /// Create a new `WordWrapper` with the given lines, maximum line width and options.
+    pub const fn new(lines: O, max_line_width: u16, options: WrapOptions) -> Self {
+        Self {
+            input_lines: lines,
+            max_line_width,
+            wrapped_lines: VecDeque::new(),
+            current_alignment: Alignment::Left,
+            current_line: vec![],
+            options,
+
+            pending_word: Vec::new(),
+            pending_line_pool: Vec::new(),
+            pending_whitespace: VecDeque::new(),
+            first_indent: Vec::new(),
+            indent: Vec::new(),
+        }
+    }
+
+    /// Returns the next wrapped line, or `None` when all the lines have been wrapped.
+    pub fn next_line<'lend>(&'lend mut self) -> Option<WrappedLine<'lend, 'a>> {
+        if self.max_line_width == 0 {
+            return None;
+        }
+
+        loop {
+            // emit next cached line if present
+            if let Some(mut line) = self.wrapped_lines.pop_front() {
+                // the last line of a paragraph is not justified
+                if self.current_alignment == Alignment::Justify && !self.wrapped_lines.is_empty() {
+                    justify(&mut line, self.max_line_width);
+                }
+                let line_width = line.iter().map(|grapheme| grapheme.width() as u16).sum();
+
+                self.replace_current_line(line);
+                return Some(WrappedLine {
+                    graphemes: &self.current_line,
+                    width: line_width,
+                    alignment: self.current_alignment,
+                });
+            }
+
+            // otherwise, process pending wrapped lines from input
+            let (line_symbols, line_alignment) = self.input_lines.next()?;
+            self.current_alignment = line_alignment;
+            self.first_indent.clear();
+            if self.options.preserve_indentation {
+                // the indentation is removed before wrapping and added back to each wrapped line,
+                // unless the line is blank or the indentation leaves no space for content
+                let mut line_symbols: Vec<_> = line_symbols.collect();
+                let indent_len = line_symbols
+                    .iter()
+                    .take_while(|grapheme| grapheme.is_whitespace())
+                    .count();
+                let indent_width =
+                    graphemes_width(line_symbols.get(..indent_len).unwrap_or_default());
+                if indent_len < line_symbols.len()
+                    && indent_width.saturating_add(self.options.hanging_indent)
+                        < self.max_line_width
+                {
+                    self.first_indent.extend(line_symbols.drain(..indent_len));
+                }
+                self.process_input(line_symbols);
+            } else {
+                self.process_input(line_symbols);
+            }
+        }
+    }
+
+    /// Split an input line (`line_symbols`) into wrapped lines
+    /// and cache them to be emitted later
+    fn process_input(&mut self, line_symbols: impl IntoIterator<Item = StyledGrapheme<'a>>) {
+        let trim = self.options.trim;
+        let anywhere = self.options.break_mode == BreakMode::Anywhere;
+
+        let continuation_width = self.prepare_indent();
+        let mut max_width = self.max_line_width - graphemes_width(&self.first_indent);
+
+        let mut pending_line = self.pending_line_pool.pop().unwrap_or_default();
+        let mut line_width = 0;
+        let mut word_width = 0;
+        let mut whitespace_width = 0;
+        let mut non_whitespace_previous = false;
+
+        self.pending_word.clear();
+        self.pending_whitespace.clear();
+        pending_line.clear();
+
+        for grapheme in line_symbols {
+            let is_whitespace = grapheme.is_whitespace();
+            let symbol_width = grapheme.width() as u16;
+
+            // ignore symbols wider than line limit
+            if symbol_width > max_width {
+                continue;
+            }
+
+            // when breaking anywhere, each grapheme is a word on its own
+            let word_found = if anywhere {
+                !self.pending_word.is_empty()
+            } else {
+                non_whitespace_previous && is_whitespace
+            };
+            // current word would overflow after removing whitespace
+            let trimmed_overflow =
+                pending_line.is_empty() && trim && word_width + symbol_width > max_width;
+            // separated whitespace would overflow on its own
+            let whitespace_overflow =
+                pending_line.is_empty() && trim && whitespace_width + symbol_width > max_width;
+            // current full word (including whitespace) would overflow
+            let untrimmed_overflow = pending_line.is_empty()
+                && !trim
+                && word_width + whitespace_width + symbol_width > max_width;
+
+            // append finished segment to current line
+            if word_found || trimmed_overflow || whitespace_overflow || untrimmed_overflow {
+                if !pending_line.is_empty() || !trim {
+                    pending_line.extend(self.pending_whitespace.drain(..));
+                    line_width += whitespace_width;
+                }
+
+                pending_line.append(&mut self.pending_word);
+                line_width += word_width;
+
+                self.pending_whitespace.clear();
+                whitespace_width = 0;
+                word_width = 0;
+            }
+
+            // pending line fills up limit
+            let line_full = line_width >= max_width;
+            // pending word would overflow line limit
+            let pending_word_overflow =
+                symbol_width > 0 && line_width + whitespace_width + word_width >= max_width;
+
+            // add finished wrapped line to remaining lines
+            if line_full || pending_word_overflow {
+                let mut remaining_width = u16::saturating_sub(max_width, line_width);
+
+                self.push_wrapped_line(mem::take(&mut pending_line));
+                line_width = 0;
+                max_width = continuation_width;
+
+                // remove whitespace up to the end of line
+                while let Some(grapheme) = self.pending_whitespace.front() {
+                    let width = grapheme.width() as u16;
+
+                    if width > remaining_width {
+                        break;
+                    }
+
+                    whitespace_width -= width;
+                    remaining_width -= width;
+                    self.pending_whitespace.pop_front();
+                }
+
+                // don't count first whitespace toward next word
+                if is_whitespace && self.pending_whitespace.is_empty() {
+                    continue;
+                }
+            }
+
+            // append symbol to a pending buffer
+            if is_whitespace {
+                whitespace_width += symbol_width;
+                self.pending_whitespace.push_back(grapheme);
+            } else {
+                word_width += symbol_width;
+                self.pending_word.push(grapheme);
+            }
+
+            non_whitespace_previous = !is_whitespace;
+        }
+
+        // append remaining text parts
+        if pending_line.is_empty()
+            && self.pending_word.is_empty()
+            && !self.pending_whitespace.is_empty()
+            && trim
+        {
+            self.wrapped_lines.push_back(vec![]);
+        }
+        if !pending_line.is_empty() || !trim {
+            pending_line.extend(self.pending_whitespace.drain(..));
+        }
+        pending_line.append(&mut self.pending_word);
+
+        #[expect(clippy::else_if_without_else)]
+        if !pending_line.is_empty() {
+            self.push_wrapped_line(pending_line);
+        } else if pending_line.capacity() > 0 {
+            self.pending_line_pool.push(pending_line);
+        }
+        if self.wrapped_lines.is_empty() {
+            self.wrapped_lines.push_back(vec![]);
+        }
+    }
+
+    /// Computes the indentation of continuation lines, and returns the width that is left for their
+    /// content.
+    ///
+    /// The indentation is dropped if it leaves no space for content.
+    fn prepare_indent(&mut self) -> u16 {
+        self.indent.clear();
+        self.indent.extend(self.first_indent.iter().cloned());
+        self.indent.extend(
+            (0..self.options.hanging_indent).map(|_| StyledGrapheme::new(" ", Style::default())),
+        );
+        let indent_width = graphemes_width(&self.indent);
+        if indent_width < self.max_line_width {
+            self.max_line_width - indent_width
+        } else {
+            self.indent.clear();
+            self.max_line_width
+        }
+    }
+
+    /// Adds a wrapped line, indented with the preserved indentation if it is the first line, or
+    /// with the indentation of continuation lines otherwise.
+    fn push_wrapped_line(&mut self, mut line: Vec<StyledGrapheme<'a>>) {
+        let indent = if self.wrapped_lines.is_empty() {
+            &self.first_indent
+        } else {
+            &self.indent
+        };
+        if !line.is_empty() && !indent.is_empty() {
+            line.splice(0..0, indent.iter().cloned());
+        }
+        self.wrapped_lines.push_back(line);
+    }
+
+    fn replace_current_line(&mut self, line: Vec<StyledGrapheme<'a>>) {
+        let cache = mem::replace(&mut self.current_line, line);
+        if cache.capacity() > 0 {
+            self.pending_line_pool.push(cache);
+        }
+    }
+}
+
+/// Returns the total width of the graphemes.
+fn graphemes_width(graphemes: &[StyledGrapheme<'_>]) -> u16 {
+    graphemes
+        .iter()
+        .map(|grapheme| grapheme.width() as u16)
+        .sum()
+}
+
+/// Distributes the space that is left in a line between its words so that it fills `width`.
+///
+/// Trailing whitespace is removed, and leading whitespace (indentation) is kept as it is. Each gap
+/// between words is widened with spaces in the style of the gap's first whitespace grapheme, with
+/// the gaps on the left getting one more space than the others if the space doesn't divide evenly.
+fn justify(line: &mut Vec<StyledGrapheme<'_>>, width: u16) {
+    let Some(end) = line.iter().rposition(|grapheme| !grapheme.is_whitespace()) else {
+        return;
+    };
+    line.truncate(end + 1);
+    let gaps = line
+        .windows(2)
+        .filter(|pair| matches!(pair, [a, b] if !a.is_whitespace() && b.is_whitespace()))
+        .count();
+    let line_width: u16 = line.iter().map(|grapheme| grapheme.width() as u16).sum();
+    let extra = width.saturating_sub(line_width) as usize;
+    if gaps == 0 || extra == 0 {
+        return;
+    }
+
+    let mut justified = Vec::with_capacity(line.len() + extra);
+    let mut gap = 0;
+    let mut previous_whitespace = true;
+    for grapheme in line.drain(..) {
+        let is_whitespace = grapheme.is_whitespace();
+        if is_whitespace && !previous_whitespace {
+            let spaces = extra / gaps + usize::from(gap < extra % gaps);
+            justified.extend((0..spaces).map(|_| StyledGrapheme::new(" ", grapheme.style)));
+            gap += 1;
+        }
+        previous_whitespace = is_whitespace;
+        justified.push(grapheme);
+    }
+    *line = justified;
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Stylize;
+
+    #[rstest]
+    #[case::word_boundary(WrapOptions::new(), vec!["abc def", "ghijklmnop", "q"])]
+    #[case::trim(WrapOptions::new().trim(true), vec!["abc def", "ghijklmnop", "q"])]
+    #[case::anywhere(WrapOptions::new().break_mode(BreakMode::Anywhere), vec!["abc def gh", "ijklmnop q"])]
+    #[case::hanging_indent(WrapOptions::new().hanging_indent(3), vec!["abc def", "   ghijklm", "   nop q"])]
+    fn wrap(#[case] options: WrapOptions, #[case] expected: Vec<&'static str>) {
+        let text = Text::from("abc def ghijklmnop q");
+        assert_eq!(text.wrap(10, options), Text::from_iter(expected));
+    }
+
+    #[test]
+    fn wrap_preserve_indentation() {
+        let text = Text::from_iter(["  - first item wraps", "no indent here at all"]);
+        let options = WrapOptions::new().preserve_indentation(true);
+        assert_eq!(
+            text.wrap(12, options),
+            Text::from_iter(["  - first", "  item wraps", "no indent", "here at all"])
+        );
+        assert_eq!(
+            text.wrap(12, options.hanging_indent(2)),
+            Text::from_iter([
+                "  - first",
+                "    item",
+                "    wraps",
+                "no indent",
+                "  here at",
+                "  all",
+            ])
+        );
+    }
+
+    #[test]
+    fn wrap_indentation_wider_than_width() {
+        let text = Text::from("ab cd ef");
+        let options = WrapOptions::new().hanging_indent(5);
+        assert_eq!(text.wrap(5, options), Text::from_iter(["ab cd", "ef"]));
+    }
+
+    #[test]
+    fn wrap_keeps_styles_and_alignment() {
+        let text = Text::from_iter([
+            Line::from(vec!["abc ".red(), "def".bold()])
+                .on_blue()
+                .right_aligned(),
+            Line::from("ghi"),
+        ])
+        .centered()
+        .italic();
+        assert_eq!(
+            text.wrap(5, WrapOptions::new()),
+            Text::from_iter([
+                Line::from("abc".red()).on_blue().right_aligned(),
+                Line::from("def".bold()).on_blue().right_aligned(),
+                Line::from("ghi"),
+            ])
+            .centered()
+            .italic()
+        );
+    }
+
+    #[test]
+    fn wrap_justify() {
+        let text = Text::from("the quick brown fox jumps").alignment(Alignment::Justify);
+        assert_eq!(
+            text.wrap(12, WrapOptions::new()),
+            Text::from_iter(["the    quick", "brown    fox", "jumps"])
+                .alignment(Alignment::Justify)
+        );
+    }
+
+    #[test]
+    fn wrap_zero_width() {
+        assert_eq!(
+            Text::from("abc").wrap(0, WrapOptions::new()),
+            Text::default()
+        );
+    }
+}
@@ -0,0 +1,56 @@
+//! Tests for the global width policy.
+//!
+//! The policy is process-wide, so these tests live in their own test binary and run in a single
+//! test function to avoid affecting other tests.
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::Style;
+use ratatui_core::text::{
+    AmbiguousWidth, EmojiPresentation, Line, Span, TabStops, TruncationMode, WidthPolicy,
+};
+
+#[test]
+fn global_width_policy() {
+    assert_eq!(WidthPolicy::global(), WidthPolicy::default());
+    assert_eq!(Span::raw("±①").width(), 2);
+    assert_eq!(Span::raw("❤\u{FE0F}").width(), 2);
+
+    WidthPolicy::new()
+        .ambiguous(AmbiguousWidth::Wide)
+        .set_global();
+    assert_eq!(Span::raw("±①").width(), 4);
+    assert_eq!(Line::from(vec!["a".into(), "±\t①".into()]).width(), 10);
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+    buffer.set_string(0, 0, "±①x", Style::new());
+    assert_eq!(buffer[(0, 0)].symbol(), "±");
+    assert_eq!(buffer[(2, 0)].symbol(), "①");
+    assert_eq!(buffer[(4, 0)].symbol(), "x");
+
+    // the text helpers measure graphemes with the policy, consistently with `Line::width`
+    let truncated = Line::from("①②③").truncate(4, TruncationMode::End);
+    assert_eq!(truncated, Line::from(vec!["①".into(), "…".into()]));
+    assert_eq!(truncated.width(), 4);
+    assert_eq!(Line::from("①②③").slice_columns(2..4), Line::from("②"));
+    let matches = Line::from("①②x").highlight("x", Style::new());
+    assert_eq!((matches[0].column, matches[0].width), (4, 1));
+    let expanded = Line::from("①\tx").expand_tabs(&TabStops::every(4));
+    assert_eq!(expanded, Line::from("①  x"));
+
+    WidthPolicy::new()
+        .emoji_presentation(EmojiPresentation::Narrow)
+        .set_global();
+    assert_eq!(Span::raw("❤\u{FE0F}").width(), 1);
+    let previous = Buffer::with_lines(["ab"]);
+    let mut next = Buffer::empty(previous.area);
+    next.set_string(0, 0, "❤\u{FE0F}b", Style::new());
+    assert_eq!(next[(0, 0)].symbol(), "❤\u{FE0F}");
+    assert_eq!(next[(1, 0)].symbol(), "b");
+    // the emoji takes a single cell, so the following cell is not skipped or cleared
+    let updates = previous.diff(&next);
+    assert_eq!(updates.len(), 1);
+    assert_eq!((updates[0].0, updates[0].1), (0, 0));
+
+    WidthPolicy::default().set_global();
+    assert_eq!(Span::raw("±①").width(), 2);
+}
@@ -0,0 +1,183 @@
+//! Parsing of `styled_line!` format strings.
+
+/// A piece of a format string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Piece {
+    /// Literal text, with `{{` and `}}` already unescaped.
+    Text(String),
+    /// A `{...}` placeholder.
+    Placeholder(Placeholder),
+}
+
+/// A `{argument:style:spec}` placeholder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placeholder {
+    /// The argument that is formatted.
+    pub argument: Argument,
+    /// The style names, e.g. `["green", "bold"]` for `{status:green.bold}`.
+    pub styles: Vec<String>,
+    /// The format spec that is passed to `format!`, e.g. `>5` for `{count:dim:>5}`.
+    pub spec: String,
+    /// The placeholder as it was written, used in error messages.
+    pub source: String,
+}
+
+/// The argument referred to by a placeholder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Argument {
+    /// `{}`, the next positional argument.
+    Next,
+    /// `{0}`, a positional argument.
+    Index(usize),
+    /// `{name}`, a named argument or a variable captured from the surrounding scope.
+    Name(String),
+}
+
+/// Splits a format string into text and placeholders.
+pub fn parse(format: &str) -> Result<Vec<Piece>, String> {
+    let mut pieces = Vec::new();
+    let mut text = String::new();
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                text.push('}');
+            }
+            '{' => {
+                let mut source = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => source.push(c),
+                        None => return Err(format!("unterminated placeholder `{{{source}`")),
+                    }
+                }
+                if !text.is_empty() {
+                    pieces.push(Piece::Text(core::mem::take(&mut text)));
+                }
+                pieces.push(Piece::Placeholder(parse_placeholder(&source)?));
+            }
+            '}' => {
+                return Err("unmatched `}` in format string, use `}}` to print a `}`".to_string());
+            }
+            c => text.push(c),
+        }
+    }
+    if !text.is_empty() {
+        pieces.push(Piece::Text(text));
+    }
+    Ok(pieces)
+}
+
+fn parse_placeholder(source: &str) -> Result<Placeholder, String> {
+    let mut parts = source.splitn(3, ':');
+    let argument = parts.next().unwrap_or_default().trim();
+    let styles = parts.next().unwrap_or_default();
+    let spec = parts.next().unwrap_or_default().to_string();
+    let argument = if argument.is_empty() {
+        Argument::Next
+    } else if let Ok(index) = argument.parse() {
+        Argument::Index(index)
+    } else if is_identifier(argument) {
+        Argument::Name(argument.to_string())
+    } else {
+        return Err(format!("invalid argument `{argument}` in `{{{source}}}`"));
+    };
+    let styles = styles
+        .split('.')
+        .map(str::trim)
+        .filter(|style| !style.is_empty())
+        .map(ToString::to_string)
+        .collect();
+    Ok(Placeholder {
+        argument,
+        styles,
+        spec,
+        source: format!("{{{source}}}"),
+    })
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c == '_' || c.is_alphabetic())
+        && chars.all(|c| c == '_' || c.is_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placeholder(argument: Argument, styles: &[&str], spec: &str, source: &str) -> Piece {
+        Piece::Placeholder(Placeholder {
+            argument,
+            styles: styles.iter().map(ToString::to_string).collect(),
+            spec: spec.to_string(),
+            source: source.to_string(),
+        })
+    }
+
+    #[test]
+    fn parse_text_and_placeholders() {
+        assert_eq!(
+            parse("Status: {status:green.bold} ({count:dim:>3})"),
+            Ok(vec![
+                Piece::Text("Status: ".to_string()),
+                placeholder(
+                    Argument::Name("status".to_string()),
+                    &["green", "bold"],
+                    "",
+                    "{status:green.bold}"
+                ),
+                Piece::Text(" (".to_string()),
+                placeholder(
+                    Argument::Name("count".to_string()),
+                    &["dim"],
+                    ">3",
+                    "{count:dim:>3}"
+                ),
+                Piece::Text(")".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_positional_arguments() {
+        assert_eq!(
+            parse("{}{1:red}{::04}"),
+            Ok(vec![
+                placeholder(Argument::Next, &[], "", "{}"),
+                placeholder(Argument::Index(1), &["red"], "", "{1:red}"),
+                placeholder(Argument::Next, &[], "04", "{::04}"),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_escaped_braces() {
+        assert_eq!(
+            parse("{{literal}}"),
+            Ok(vec![Piece::Text("{literal}".to_string())])
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse("{status"),
+            Err("unterminated placeholder `{status`".to_string())
+        );
+        assert_eq!(
+            parse("status}"),
+            Err("unmatched `}` in format string, use `}}` to print a `}`".to_string())
+        );
+        assert_eq!(
+            parse("{a-b:red}"),
+            Err("invalid argument `a-b` in `{a-b:red}`".to_string())
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! Procedural macros used by [`ratatui-macros`].
+//!
+//! This crate is an implementation detail of [`ratatui-macros`] and should not be used directly.
+//! The macros it defines expect the path of the `ratatui_macros` crate as their first argument,
+//! which is supplied by the wrapper macros in [`ratatui-macros`].
+//!
+//! [`ratatui-macros`]: https://docs.rs/ratatui-macros
+
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, LitStr, Token, parse_macro_input};
+
+use crate::format::{Argument, Piece, Placeholder};
+
+mod format;
+mod style;
+
+/// Implementation of `ratatui_macros::styled_line!`.
+///
+/// The input is the path of the `ratatui_macros` crate, a semicolon, and the arguments of
+/// `styled_line!`.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __styled_line(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as StyledLineInput);
+    input
+        .expand()
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+struct StyledLineInput {
+    /// The path of the `ratatui_macros` crate
+    krate: TokenStream,
+    format: LitStr,
+    arguments: Vec<FormatArgument>,
+}
+
+struct FormatArgument {
+    name: Option<Ident>,
+    expr: Expr,
+}
+
+impl Parse for StyledLineInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut krate = TokenStream::new();
+        while !input.peek(Token![;]) {
+            krate.extend([input.parse::<TokenTree>()?]);
+        }
+        input.parse::<Token![;]>()?;
+        let format = input.parse()?;
+        let mut arguments = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let name = if input.peek(syn::Ident) && input.peek2(Token![=]) {
+                let name = input.parse()?;
+                input.parse::<Token![=]>()?;
+                Some(name)
+            } else {
+                None
+            };
+            let expr = input.parse()?;
+            arguments.push(FormatArgument { name, expr });
+        }
+        Ok(Self {
+            krate,
+            format,
+            arguments,
+        })
+    }
+}
+
+/// The spans of the line being built.
+struct Spans<'a> {
+    krate: &'a TokenStream,
+    /// The expressions of the spans
+    exprs: Vec<TokenStream>,
+    /// The format string of the unstyled pieces that follow the last styled placeholder
+    format: String,
+    /// The arguments of `format`
+    arguments: Vec<TokenStream>,
+    /// The text of the unstyled pieces, if they don't contain placeholders
+    text: Option<String>,
+}
+
+impl<'a> Spans<'a> {
+    const fn new(krate: &'a TokenStream) -> Self {
+        Self {
+            krate,
+            exprs: Vec::new(),
+            format: String::new(),
+            arguments: Vec::new(),
+            text: Some(String::new()),
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        self.format
+            .push_str(&text.replace('{', "{{").replace('}', "}}"));
+        if let Some(unformatted) = &mut self.text {
+            unformatted.push_str(text);
+        }
+    }
+
+    fn push_unstyled(&mut self, argument: TokenStream, spec: &str) {
+        self.format.push_str(&placeholder_format(spec));
+        self.arguments.push(argument);
+        self.text = None;
+    }
+
+    fn push_styled(&mut self, argument: &TokenStream, spec: &str, style: &TokenStream) {
+        self.flush();
+        let krate = self.krate;
+        let format = placeholder_format(spec);
+        self.exprs.push(quote! {
+            #krate::ratatui_core::text::Span::styled(#krate::format!(#format, #argument), #style)
+        });
+    }
+
+    /// Pushes the unstyled pieces as a single raw span.
+    fn flush(&mut self) {
+        let krate = self.krate;
+        let format = core::mem::take(&mut self.format);
+        let arguments = core::mem::take(&mut self.arguments);
+        match self.text.replace(String::new()) {
+            Some(text) if text.is_empty() => {}
+            Some(text) => self
+                .exprs
+                .push(quote!(#krate::ratatui_core::text::Span::raw(#text))),
+            None => self.exprs.push(quote! {
+                #krate::ratatui_core::text::Span::raw(#krate::format!(#format, #(#arguments),*))
+            }),
+        }
+    }
+
+    fn into_line(mut self) -> TokenStream {
+        self.flush();
+        let krate = self.krate;
+        let spans = self.exprs;
+        quote!(#krate::ratatui_core::text::Line::from(#krate::vec![#(#spans),*]))
+    }
+}
+
+impl StyledLineInput {
+    fn expand(&self) -> syn::Result<TokenStream> {
+        let error = |message: String| syn::Error::new(self.format.span(), message);
+        let pieces = format::parse(&self.format.value()).map_err(error)?;
+        let bindings: Vec<Ident> = (0..self.arguments.len())
+            .map(|index| format_ident!("__arg{index}"))
+            .collect();
+        let mut used = vec![false; self.arguments.len()];
+        let mut next = 0;
+        let mut spans = Spans::new(&self.krate);
+        for piece in pieces {
+            let Placeholder {
+                argument,
+                styles,
+                spec,
+                source,
+            } = match piece {
+                Piece::Text(text) => {
+                    spans.push_text(&text);
+                    continue;
+                }
+                Piece::Placeholder(placeholder) => placeholder,
+            };
+            let index = match argument {
+                Argument::Next => {
+                    next += 1;
+                    Some(next - 1)
+                }
+                Argument::Index(index) => Some(index),
+                Argument::Name(ref name) => self
+                    .arguments
+                    .iter()
+                    .position(|argument| argument.name.as_ref().is_some_and(|n| n == name)),
+            };
+            let argument = match (index, argument) {
+                (Some(index), _) if index < self.arguments.len() => {
+                    used[index] = true;
+                    let binding = &bindings[index];
+                    quote!(#binding)
+                }
+                (Some(index), _) => {
+                    return Err(error(format!(
+                        "invalid reference to argument {index} in `{source}` ({} arguments were \
+                         given)",
+                        self.arguments.len()
+                    )));
+                }
+                (None, Argument::Name(name)) => {
+                    // capture the variable from the scope of the macro call
+                    let name = Ident::new(&name, self.format.span());
+                    quote!(#name)
+                }
+                (None, _) => unreachable!("only named arguments are looked up by name"),
+            };
+            if styles.is_empty() {
+                spans.push_unstyled(argument, &spec);
+            } else {
+                let style = style::style(&self.krate, &styles).map_err(|message| {
+                    error(format!(
+                        "{message} in `{source}`, expected a color (e.g. `green`), `on_` followed \
+                         by a color (e.g. `on_blue`) or a modifier (e.g. `bold`)"
+                    ))
+                })?;
+                spans.push_styled(&argument, &spec, &style);
+            }
+        }
+        if let Some(unused) = used.iter().position(|used| !used) {
+            return Err(syn::Error::new_spanned(
+                &self.arguments[unused].expr,
+                "argument never used",
+            ));
+        }
+        let line = spans.into_line();
+        if self.arguments.is_empty() {
+            return Ok(line);
+        }
+        let exprs = self.arguments.iter().map(|argument| &argument.expr);
+        Ok(quote! {
+            match (#(&(#exprs),)*) {
+                (#(#bindings,)*) => #line,
+            }
+        })
+    }
+}
+
+/// Returns the `format!` placeholder for a format spec, e.g. `{:>3}` for `>3`.
+fn placeholder_format(spec: &str) -> String {
+    if spec.is_empty() {
+        "{}".to_string()
+    } else {
+        format!("{{:{spec}}}")
+    }
+}
@@ -0,0 +1,110 @@
+//! Conversion of `styled_line!` style specifiers into `Style` expressions.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+/// The color names, as used by the `Stylize` methods, and the matching `Color` variants.
+const COLORS: [(&str, &str); 16] = [
+    ("black", "Black"),
+    ("red", "Red"),
+    ("green", "Green"),
+    ("yellow", "Yellow"),
+    ("blue", "Blue"),
+    ("magenta", "Magenta"),
+    ("cyan", "Cyan"),
+    ("gray", "Gray"),
+    ("dark_gray", "DarkGray"),
+    ("light_red", "LightRed"),
+    ("light_green", "LightGreen"),
+    ("light_yellow", "LightYellow"),
+    ("light_blue", "LightBlue"),
+    ("light_magenta", "LightMagenta"),
+    ("light_cyan", "LightCyan"),
+    ("white", "White"),
+];
+
+/// The modifier names, as used by the `Stylize` methods, and the matching `Modifier` constants.
+const MODIFIERS: [(&str, &str); 9] = [
+    ("bold", "BOLD"),
+    ("dim", "DIM"),
+    ("italic", "ITALIC"),
+    ("underlined", "UNDERLINED"),
+    ("slow_blink", "SLOW_BLINK"),
+    ("rapid_blink", "RAPID_BLINK"),
+    ("reversed", "REVERSED"),
+    ("hidden", "HIDDEN"),
+    ("crossed_out", "CROSSED_OUT"),
+];
+
+/// Returns an expression that builds the `Style` for the given style names.
+///
+/// Each name is a color (e.g. `green`), `on_` followed by a color (e.g. `on_blue`) or a modifier
+/// (e.g. `bold`), like the methods of the `Stylize` trait.
+pub fn style(krate: &TokenStream, names: &[String]) -> Result<TokenStream, String> {
+    let mut style = quote!(#krate::ratatui_core::style::Style::new());
+    for name in names {
+        if let Some(color) = color(name) {
+            style = quote!(#style.fg(#krate::ratatui_core::style::Color::#color));
+        } else if let Some(color) = name.strip_prefix("on_").and_then(color) {
+            style = quote!(#style.bg(#krate::ratatui_core::style::Color::#color));
+        } else if let Some(modifier) = lookup(&MODIFIERS, name) {
+            style = quote!(#style.add_modifier(#krate::ratatui_core::style::Modifier::#modifier));
+        } else {
+            return Err(format!("unknown style `{name}`"));
+        }
+    }
+    Ok(style)
+}
+
+fn color(name: &str) -> Option<Ident> {
+    lookup(&COLORS, name)
+}
+
+fn lookup(table: &[(&str, &str)], name: &str) -> Option<Ident> {
+    table
+        .iter()
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| Ident::new(value, Span::call_site()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn style_string(names: &[&str]) -> Result<String, String> {
+        let names: Vec<String> = names.iter().map(ToString::to_string).collect();
+        style(&quote!(krate), &names).map(|style| style.to_string().replace(' ', ""))
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            style_string(&[]),
+            Ok("krate::ratatui_core::style::Style::new()".to_string())
+        );
+    }
+
+    #[test]
+    fn colors_and_modifiers() {
+        assert_eq!(
+            style_string(&["green", "on_dark_gray", "bold"]),
+            Ok("krate::ratatui_core::style::Style::new()\
+                .fg(krate::ratatui_core::style::Color::Green)\
+                .bg(krate::ratatui_core::style::Color::DarkGray)\
+                .add_modifier(krate::ratatui_core::style::Modifier::BOLD)"
+                .to_string())
+        );
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(
+            style_string(&["green", "blod"]),
+            Err("unknown style `blod`".to_string())
+        );
+        assert_eq!(
+            style_string(&["on_bold"]),
+            Err("unknown style `on_bold`".to_string())
+        );
+    }
+}
@@ -22,7 +22,9 @@
 //! Then, import the macros in your Rust file:
 //!
 //! ```rust
-//! use ratatui_macros::{constraint, constraints, horizontal, line, row, span, text, vertical};
+//! use ratatui_macros::{
+//!     constraint, constraints, horizontal, line, row, span, styled_line, text, vertical,
+//! };
 //! ```
 //!
 //! # Text Macros
@@ -52,6 +54,17 @@
 //! let line = line!["bye"; 2];
 //! ```
 //!
+//! The [`styled_line!`] macro creates a [`Line`] from a format string, where each placeholder can
+//! specify the style of its [`Span`] with the names of the [`Stylize`] methods. The style names
+//! are checked at compile time.
+//!
+//! ```rust
+//! # use ratatui_macros::styled_line;
+//! let status = "ok";
+//! let count = 3;
+//! let line = styled_line!("Status: {status:green.bold} ({count:dim})");
+//! ```
+//!
 //! The [`text!`] macro creates a [`Text`] that contains a sequence of [`Line`]. It is similar to
 //! the [`vec!`] macro. Each element is converted to a [`Line`] using [`Into::into`].
 //!
@@ -158,6 +171,7 @@
 //! [`Constraint`]: ratatui_core::layout::Constraint
 //! [`Layout`]: ratatui_core::layout::Layout
 //! [`Span`]: ratatui_core::text::Span
+//! [`Stylize`]: ratatui_core::style::Stylize
 //! [`Line`]: ratatui_core::text::Line
 //! [`Text`]: ratatui_core::text::Text
 //! [`Row`]: ratatui_widgets::table::Row
@@ -173,7 +187,10 @@ mod layout;
 mod line;
 mod row;
 mod span;
+mod styled_line;
 mod text;
 
 // Re-export the core crate to use the types in macros
 pub use ratatui_core;
+#[doc(hidden)]
+pub use ratatui_macros_impl::__styled_line;
@@ -0,0 +1,172 @@
+/// A macro for creating a [`Line`] from a format string with inline styles.
+///
+/// `styled_line!` is similar to the [`format!`] macro, but it returns a [`Line`] instead of a
+/// `String`, and each placeholder can specify the style of its [`Span`] after a colon:
+/// `{status:green.bold}` formats `status` in a green and bold span. This avoids building a
+/// `vec![...]` of spans to style a few values in a line.
+///
+/// A style specifier is a list of names separated by dots, each of which is one of:
+///
+/// - a color: `black`, `red`, `green`, `yellow`, `blue`, `magenta`, `cyan`, `gray`, `dark_gray`,
+///   `light_red`, `light_green`, `light_yellow`, `light_blue`, `light_magenta`, `light_cyan` or
+///   `white`, which sets the foreground color
+/// - `on_` followed by a color (e.g. `on_blue`), which sets the background color
+/// - a modifier: `bold`, `dim`, `italic`, `underlined`, `slow_blink`, `rapid_blink`, `reversed`,
+///   `hidden` or `crossed_out`
+///
+/// These are the names of the corresponding [`Stylize`] methods. The style specifier is checked at
+/// compile time, so unknown names are compile errors.
+///
+/// A [format spec] can follow the style specifier after a second colon, e.g. `{count:dim:>3}`. Use
+/// an empty style specifier for a format spec without a style, e.g. `{count::>3}`.
+///
+/// The text and the unstyled placeholders between styled placeholders are combined into raw
+/// spans. Placeholders refer to arguments in the same way as in [`format!`]: positional arguments
+/// (`{}` and `{0}`), named arguments (`{name}` with `name = value`) and variables captured from the
+/// surrounding scope (`{name}`).
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui_core::style::Stylize;
+/// use ratatui_core::text::Line;
+/// use ratatui_macros::styled_line;
+///
+/// let status = "ok";
+/// let count = 3;
+/// let line = styled_line!("Status: {status:green.bold} ({count:dim})");
+/// assert_eq!(
+///     line,
+///     Line::from(vec![
+///         "Status: ".into(),
+///         "ok".green().bold(),
+///         " (".into(),
+///         "3".dim(),
+///         ")".into(),
+///     ])
+/// );
+///
+/// // positional and named arguments, and format specs
+/// let line = styled_line!("{:on_red} {name::>5}", "error", name = "disk");
+/// assert_eq!(line, Line::from(vec!["error".on_red(), "  disk".into()]));
+/// ```
+///
+/// Unknown style names fail to compile:
+///
+/// ```compile_fail
+/// # use ratatui_macros::styled_line;
+/// let status = "ok";
+/// let line = styled_line!("Status: {status:grene}");
+/// ```
+///
+/// [`Line`]: ratatui_core::text::Line
+/// [`Span`]: ratatui_core::text::Span
+/// [`Stylize`]: ratatui_core::style::Stylize
+/// [`format!`]: alloc::format!
+/// [format spec]: alloc::fmt#formatting-parameters
+#[macro_export]
+macro_rules! styled_line {
+    ($($arg:tt)+) => {
+        $crate::__styled_line!($crate; $($arg)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use ratatui_core::style::{Style, Stylize};
+    use ratatui_core::text::{Line, Span};
+
+    #[test]
+    fn text_only() {
+        let line = styled_line!("hello {{world}}");
+        assert_eq!(line, Line::from(vec![Span::raw("hello {world}")]));
+    }
+
+    #[test]
+    fn empty() {
+        let line = styled_line!("");
+        assert_eq!(line, Line::default());
+    }
+
+    #[test]
+    fn captured_variables() {
+        let status = "ok";
+        let count = 3;
+        let line = styled_line!("Status: {status:green.bold} ({count:dim})");
+        assert_eq!(
+            line,
+            Line::from(vec![
+                "Status: ".into(),
+                "ok".green().bold(),
+                " (".into(),
+                "3".dim(),
+                ")".into(),
+            ])
+        );
+    }
+
+    #[test]
+    fn unstyled_placeholders_are_combined() {
+        let name = "world";
+        let line = styled_line!("hello {name}, {}!", 42);
+        assert_eq!(line, Line::from(vec![Span::raw("hello world, 42!")]));
+    }
+
+    #[test]
+    fn positional_and_named_arguments() {
+        let line = styled_line!("{1:red} {0:blue} {x:on_yellow}", "a", "b", x = 1 + 2);
+        assert_eq!(
+            line,
+            Line::from(vec![
+                "b".red(),
+                " ".into(),
+                "a".blue(),
+                " ".into(),
+                "3".on_yellow(),
+            ])
+        );
+    }
+
+    #[test]
+    fn arguments_are_evaluated_once() {
+        let mut calls = 0;
+        let mut next = || {
+            calls += 1;
+            calls
+        };
+        let line = styled_line!("{0:red}{0}", next());
+        assert_eq!(line, Line::from(vec!["1".red(), "1".into()]));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn format_specs() {
+        let value = 1.5;
+        let line = styled_line!("[{value:bold:>6.2}] [{value::<5}]");
+        assert_eq!(
+            line,
+            Line::from(vec!["[".into(), "  1.50".bold(), "] [1.5  ]".into()])
+        );
+    }
+
+    #[test]
+    fn all_styles() {
+        let x = "x";
+        let line = styled_line!(
+            "{x:light_magenta.on_dark_gray.italic.underlined.slow_blink.rapid_blink.reversed.hidden.crossed_out}"
+        );
+        let style = Style::new()
+            .light_magenta()
+            .on_dark_gray()
+            .italic()
+            .underlined()
+            .slow_blink()
+            .rapid_blink()
+            .reversed()
+            .hidden()
+            .crossed_out();
+        assert_eq!(line, Line::from(vec![Span::styled("x", style)]));
+    }
+}
@@ -0,0 +1,18 @@
+use ratatui_macros::styled_line;
+
+fn main() {
+    let status = "ok";
+    let count = 3;
+
+    let _ = styled_line!("Status: {status:grene}");
+
+    let _ = styled_line!("Status: {status:green.blod} ({count:dim})");
+
+    let _ = styled_line!("Status: {status:on_bold}");
+
+    let _ = styled_line!("{status", status);
+
+    let _ = styled_line!("{:red}", status, count);
+
+    let _ = styled_line!("{1:red}", status);
+}
@@ -9,11 +9,11 @@ use alloc::vec::Vec;
 
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::{Alignment, Rect};
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::layout::{Alignment, Position, Rect};
+use ratatui_core::style::{Gradient, Style, Styled, Theme, roles};
 use ratatui_core::symbols::border;
 use ratatui_core::symbols::merge::MergeStrategy;
-use ratatui_core::text::Line;
+use ratatui_core::text::{Line, Truncation};
 use ratatui_core::widgets::Widget;
 use strum::{Display, EnumString};
 
@@ -59,6 +59,7 @@ mod padding;
 /// - [`Block::title_alignment`] - Sets default alignment for all titles
 /// - [`Block::title_style`] - Sets the style for all titles
 /// - [`Block::title_position`] - Sets default position for titles
+/// - [`Block::title_truncation`] - Sets how titles that don't fit are truncated
 ///
 /// # Styling and Layout
 ///
@@ -66,6 +67,9 @@ mod padding;
 /// - [`Block::padding`] - Adds internal padding within the borders
 /// - [`Block::inner`] - Calculates the inner area available for content
 ///
+/// The styles of the `block`, `block.border` and `block.title` roles of the active [`Theme`] are
+/// used as defaults, and the styles set on the block are applied on top of them.
+///
 /// # Title Behavior
 ///
 /// You can add multiple titles to a block, and they will be rendered with spaces separating titles
@@ -220,10 +224,14 @@ pub struct Block<'a> {
     titles_alignment: Alignment,
     /// The default position of the titles that don't have one
     titles_position: TitlePosition,
+    /// How titles that don't fit in the block are truncated
+    titles_truncation: Option<Truncation>,
     /// Visible borders
     borders: Borders,
     /// Border style
     border_style: Style,
+    /// The gradient of the foreground color of the borders
+    border_gradient: Option<Gradient>,
     /// The symbols used to render the border. The default is plain lines but one can choose to
     /// have rounded or doubled lines instead or a custom set of symbols
     border_set: border::Set<'a>,
@@ -268,8 +276,10 @@ impl<'a> Block<'a> {
             titles_style: Style::new(),
             titles_alignment: Alignment::Left,
             titles_position: TitlePosition::Top,
+            titles_truncation: None,
             borders: Borders::NONE,
             border_style: Style::new(),
+            border_gradient: None,
             border_set: BorderType::Plain.to_border_set(),
             style: Style::new(),
             padding: Padding::ZERO,
@@ -490,6 +500,32 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Sets how titles that don't fit in the block are truncated.
+    ///
+    /// By default, titles that are too wide are cut off at the edge of the block. With a
+    /// [`Truncation`], part of the title is replaced by a marker (`…` by default) instead, so that
+    /// it degrades gracefully in narrow blocks. See [`Line::truncate`] for more details.
+    ///
+    /// `truncation` accepts a [`Truncation`] or a [`TruncationMode`].
+    ///
+    /// [`TruncationMode`]: ratatui_core::text::TruncationMode
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::text::TruncationMode;
+    /// use ratatui::widgets::Block;
+    ///
+    /// Block::bordered()
+    ///     .title("/home/user/projects/ratatui")
+    ///     .title_truncation(TruncationMode::Start);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn title_truncation<T: Into<Truncation>>(mut self, truncation: T) -> Self {
+        self.titles_truncation = Some(truncation.into());
+        self
+    }
+
     /// Defines the style of the borders.
     ///
     /// This style is applied only to the areas covered by borders, and is applied to the block
@@ -516,6 +552,29 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Colors the borders with a [`Gradient`].
+    ///
+    /// The foreground color of each border cell is the color of the gradient at the position of
+    /// the cell in the block, following the [direction](Gradient::direction) of the gradient. The
+    /// gradient is applied on top of the [`Block::border_style`].
+    ///
+    /// # Example
+    ///
+    /// This example shows a `Block` with borders going from red at the top left corner to blue at
+    /// the bottom right corner.
+    /// ```
+    /// use ratatui::style::{Color, Gradient, GradientDirection};
+    /// use ratatui::widgets::Block;
+    ///
+    /// let gradient = Gradient::new([Color::Red, Color::Blue]).direction(GradientDirection::Diagonal);
+    /// Block::bordered().border_gradient(gradient);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn border_gradient(mut self, gradient: Gradient) -> Self {
+        self.border_gradient = Some(gradient);
+        self
+    }
+
     /// Defines the style of the entire block.
     ///
     /// This is the most generic [`Style`] a block can receive, it will be merged with any other
@@ -780,19 +839,21 @@ impl Widget for &Block<'_> {
         if area.is_empty() {
             return;
         }
-        buf.set_style(area, self.style);
-        self.render_borders(area, buf);
-        self.render_titles(area, buf);
+        buf.set_style(area, Theme::active_style(roles::BLOCK).patch(self.style));
+        let border_style = Theme::active_role_style(roles::BLOCK_BORDER).patch(self.border_style);
+        self.render_borders(area, buf, border_style);
+        let titles_style = Theme::active_role_style(roles::BLOCK_TITLE).patch(self.titles_style);
+        self.render_titles(area, buf, titles_style);
     }
 }
 
 impl Block<'_> {
-    fn render_borders(&self, area: Rect, buf: &mut Buffer) {
-        self.render_sides(area, buf);
-        self.render_corners(area, buf);
+    fn render_borders(&self, area: Rect, buf: &mut Buffer, style: Style) {
+        self.render_sides(area, buf, style);
+        self.render_corners(area, buf, style);
     }
 
-    fn render_sides(&self, area: Rect, buf: &mut Buffer) {
+    fn render_sides(&self, area: Rect, buf: &mut Buffer, style: Style) {
         let left = area.left();
         let top = area.top();
         // area.right() and area.bottom() are outside the rect, subtract 1 to get the last row/col
@@ -839,16 +900,14 @@ impl Block<'_> {
             if self.borders.contains(border) {
                 for x in x_range {
                     for y in y_range.clone() {
-                        buf[(x, y)]
-                            .merge_symbol(symbol, self.merge_borders)
-                            .set_style(self.border_style);
+                        self.render_border_cell(area, buf, Position::new(x, y), symbol, style);
                     }
                 }
             }
         }
     }
 
-    fn render_corners(&self, area: Rect, buf: &mut Buffer) {
+    fn render_corners(&self, area: Rect, buf: &mut Buffer, style: Style) {
         let corners = [
             (
                 Borders::RIGHT | Borders::BOTTOM,
@@ -878,22 +937,43 @@ impl Block<'_> {
 
         for (border, x, y, symbol) in corners {
             if self.borders.contains(border) {
-                buf[(x, y)]
-                    .merge_symbol(symbol, self.merge_borders)
-                    .set_style(self.border_style);
+                self.render_border_cell(area, buf, Position::new(x, y), symbol, style);
             }
         }
     }
-    fn render_titles(&self, area: Rect, buf: &mut Buffer) {
-        self.render_title_position(TitlePosition::Top, area, buf);
-        self.render_title_position(TitlePosition::Bottom, area, buf);
+
+    fn render_border_cell(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        position: Position,
+        symbol: &str,
+        style: Style,
+    ) {
+        let cell = buf[position]
+            .merge_symbol(symbol, self.merge_borders)
+            .set_style(style);
+        if let Some(gradient) = &self.border_gradient {
+            cell.set_fg(gradient.color_in(area, position));
+        }
+    }
+
+    fn render_titles(&self, area: Rect, buf: &mut Buffer, style: Style) {
+        self.render_title_position(TitlePosition::Top, area, buf, style);
+        self.render_title_position(TitlePosition::Bottom, area, buf, style);
     }
 
-    fn render_title_position(&self, position: TitlePosition, area: Rect, buf: &mut Buffer) {
+    fn render_title_position(
+        &self,
+        position: TitlePosition,
+        area: Rect,
+        buf: &mut Buffer,
+        style: Style,
+    ) {
         // NOTE: the order in which these functions are called defines the overlapping behavior
-        self.render_left_titles(position, area, buf);
-        self.render_center_titles(position, area, buf);
-        self.render_right_titles(position, area, buf);
+        self.render_left_titles(position, area, buf, style);
+        self.render_center_titles(position, area, buf, style);
+        self.render_right_titles(position, area, buf, style);
     }
 
     /// Render titles aligned to the right of the block
@@ -903,7 +983,13 @@ impl Block<'_> {
     /// the left side of that leftmost that is cut off. This is due to the line being truncated
     /// incorrectly. See <https://github.com/ratatui/ratatui/issues/932>
     #[expect(clippy::similar_names)]
-    fn render_right_titles(&self, position: TitlePosition, area: Rect, buf: &mut Buffer) {
+    fn render_right_titles(
+        &self,
+        position: TitlePosition,
+        area: Rect,
+        buf: &mut Buffer,
+        style: Style,
+    ) {
         let titles = self.filtered_titles(position, Alignment::Right);
         let mut titles_area = self.titles_area(area, position);
 
@@ -921,8 +1007,7 @@ impl Block<'_> {
                 width: title_width.min(titles_area.width),
                 ..titles_area
             };
-            buf.set_style(title_area, self.titles_style);
-            title.render(title_area, buf);
+            self.render_title(title, title_area, buf, style);
 
             // bump the width of the titles area to the left
             titles_area.width = titles_area
@@ -933,7 +1018,13 @@ impl Block<'_> {
     }
 
     /// Render titles in the center of the block
-    fn render_center_titles(&self, position: TitlePosition, area: Rect, buf: &mut Buffer) {
+    fn render_center_titles(
+        &self,
+        position: TitlePosition,
+        area: Rect,
+        buf: &mut Buffer,
+        style: Style,
+    ) {
         let area = self.titles_area(area, position);
         let titles = self
             .filtered_titles(position, Alignment::Center)
@@ -946,18 +1037,20 @@ impl Block<'_> {
             .saturating_sub(1);
 
         if total_width <= area.width {
-            self.render_centered_titles_without_truncation(titles, total_width, area, buf);
+            Self::render_centered_titles_without_truncation(titles, total_width, area, buf, style);
+        } else if self.titles_truncation.is_some() {
+            self.render_centered_titles_with_marker(titles, area, buf, style);
         } else {
-            self.render_centered_titles_with_truncation(titles, total_width, area, buf);
+            Self::render_centered_titles_with_truncation(titles, total_width, area, buf, style);
         }
     }
 
     fn render_centered_titles_without_truncation(
-        &self,
         titles: Vec<&Line<'_>>,
         total_width: u16,
         area: Rect,
         buf: &mut Buffer,
+        style: Style,
     ) {
         // titles fit in the area, center them
         let x = area.left() + area.width.saturating_sub(total_width) / 2;
@@ -965,7 +1058,7 @@ impl Block<'_> {
         for title in titles {
             let width = title.width() as u16;
             let title_area = Rect { width, ..area };
-            buf.set_style(title_area, self.titles_style);
+            buf.set_style(title_area, style);
             title.render(title_area, buf);
             // Move the rendering cursor to the right, leaving 1 column space.
             area.x = area.x.saturating_add(width + 1);
@@ -974,11 +1067,11 @@ impl Block<'_> {
     }
 
     fn render_centered_titles_with_truncation(
-        &self,
         titles: Vec<&Line<'_>>,
         total_width: u16,
         mut area: Rect,
         buf: &mut Buffer,
+        style: Style,
     ) {
         // titles do not fit in the area, truncate the left side using an offset. The right side
         // is truncated by the area width.
@@ -989,7 +1082,7 @@ impl Block<'_> {
             }
             let width = area.width.min(title.width() as u16).saturating_sub(offset);
             let title_area = Rect { width, ..area };
-            buf.set_style(title_area, self.titles_style);
+            buf.set_style(title_area, style);
             if offset > 0 {
                 // truncate the left side of the title to fit the area
                 title.clone().right_aligned().render(title_area, buf);
@@ -1004,9 +1097,51 @@ impl Block<'_> {
         }
     }
 
+    /// Render centered titles that don't fit in the area from left to right, truncating the titles
+    /// that overflow with the configured [`Truncation`]
+    fn render_centered_titles_with_marker(
+        &self,
+        titles: Vec<&Line<'_>>,
+        mut area: Rect,
+        buf: &mut Buffer,
+        style: Style,
+    ) {
+        for title in titles {
+            if area.is_empty() {
+                break;
+            }
+            let width = area.width.min(title.width() as u16);
+            self.render_title(title, Rect { width, ..area }, buf, style);
+            // Leave 1 column of spacing between titles.
+            area.x = area.x.saturating_add(width + 1);
+            area.width = area.width.saturating_sub(width + 1);
+        }
+    }
+
+    /// Render a single title in the given area, truncating it if it doesn't fit and a
+    /// [`Truncation`] is set
+    fn render_title(&self, title: &Line<'_>, area: Rect, buf: &mut Buffer, style: Style) {
+        buf.set_style(area, style);
+        match self.titles_truncation {
+            Some(truncation) if title.width() > usize::from(area.width) => {
+                title
+                    .truncate(area.width, truncation)
+                    .left_aligned()
+                    .render(area, buf);
+            }
+            _ => title.render(area, buf),
+        }
+    }
+
     /// Render titles aligned to the left of the block
     #[expect(clippy::similar_names)]
-    fn render_left_titles(&self, position: TitlePosition, area: Rect, buf: &mut Buffer) {
+    fn render_left_titles(
+        &self,
+        position: TitlePosition,
+        area: Rect,
+        buf: &mut Buffer,
+        style: Style,
+    ) {
         let titles = self.filtered_titles(position, Alignment::Left);
         let mut titles_area = self.titles_area(area, position);
         for title in titles {
@@ -1018,8 +1153,7 @@ impl Block<'_> {
                 width: title_width.min(titles_area.width),
                 ..titles_area
             };
-            buf.set_style(title_area, self.titles_style);
-            title.render(title_area, buf);
+            self.render_title(title, title_area, buf, style);
 
             // bump the titles area to the right and reduce its width
             titles_area.x = titles_area.x.saturating_add(title_width + 1);
@@ -1125,6 +1259,7 @@ mod tests {
     use itertools::iproduct;
     use ratatui_core::layout::Offset;
     use ratatui_core::style::{Color, Modifier, Stylize};
+    use ratatui_core::text::TruncationMode;
     use rstest::rstest;
     use strum::ParseError;
 
@@ -1334,8 +1469,10 @@ mod tests {
                 titles_style: Style::new(),
                 titles_alignment: Alignment::Left,
                 titles_position: TitlePosition::Top,
+                titles_truncation: None,
                 borders: Borders::NONE,
                 border_style: Style::new(),
+                border_gradient: None,
                 border_set: BorderType::Plain.to_border_set(),
                 style: Style::new(),
                 padding: Padding::ZERO,
@@ -1546,6 +1683,26 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn border_gradient() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0)]);
+        Block::bordered()
+            .border_style(Style::new().on_blue())
+            .border_gradient(gradient)
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┌─┐", "│ │", "└─┘"]);
+        for (x, red) in [0, 100, 200].into_iter().enumerate() {
+            for y in 0..3 {
+                expected[(x as u16, y)]
+                    .set_fg(Color::Rgb(red, 0, 0))
+                    .set_bg(Color::Blue);
+            }
+        }
+        expected[(1, 1)].reset();
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn border_type_to_string() {
         assert_eq!(format!("{}", BorderType::Plain), "Plain");
@@ -2105,6 +2262,52 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["345 R67890"]));
     }
 
+    #[rstest]
+    #[case::end(TruncationMode::End, "L12345 L6…")]
+    #[case::start(TruncationMode::Start, "L12345 …90")]
+    #[case::middle(TruncationMode::Middle, "L12345 L…0")]
+    fn left_titles_truncated_with_marker(#[case] mode: TruncationMode, #[case] expected: &str) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        Block::new()
+            .title("L12345")
+            .title("L67890")
+            .title_truncation(mode)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn center_titles_truncated_with_marker() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        Block::new()
+            .title(Line::from("C12345").centered())
+            .title(Line::from("C67890").centered())
+            .title_truncation(TruncationMode::End)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["C12345 C6…"]));
+    }
+
+    #[test]
+    fn right_titles_truncated_with_marker() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        Block::new()
+            .title(Line::from("R12345").right_aligned())
+            .title(Line::from("R67890").right_aligned())
+            .title_truncation(TruncationMode::Start)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["…45 R67890"]));
+    }
+
+    #[test]
+    fn bordered_title_truncated_with_custom_marker() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Block::bordered()
+            .title("abcdefghij")
+            .title_truncation(Truncation::middle().marker("~"))
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["┌abc~ij┐"]));
+    }
+
     #[test]
     fn center_title_truncates_left_title() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
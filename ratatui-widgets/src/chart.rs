@@ -848,7 +848,7 @@ impl<'a> Chart<'a> {
         if let Some(first_x_label) = self.x_axis.labels.first() {
             let first_label_width = first_x_label.width() as u16;
             let width_left_of_y_axis = match self.x_axis.labels_alignment {
-                Alignment::Left => {
+                Alignment::Left | Alignment::Justify => {
                     // The last character of the label should be below the Y-Axis when it exists,
                     // not on its left
                     let y_axis_offset = u16::from(has_y_axis);
@@ -888,7 +888,7 @@ impl<'a> Chart<'a> {
         );
 
         let label_alignment = match self.x_axis.labels_alignment {
-            Alignment::Left => Alignment::Right,
+            Alignment::Left | Alignment::Justify => Alignment::Right,
             Alignment::Center => Alignment::Center,
             Alignment::Right => Alignment::Left,
         };
@@ -919,7 +919,7 @@ impl<'a> Chart<'a> {
         graph_area: Rect,
     ) -> Rect {
         let (min_x, max_x) = match self.x_axis.labels_alignment {
-            Alignment::Left => (chart_area.left(), graph_area.left()),
+            Alignment::Left | Alignment::Justify => (chart_area.left(), graph_area.left()),
             Alignment::Center => (
                 chart_area.left(),
                 graph_area.left() + max_width_after_y_axis.min(label_width),
@@ -935,7 +935,7 @@ impl<'a> Chart<'a> {
 
     fn render_label(buf: &mut Buffer, label: &Line, label_area: Rect, alignment: Alignment) {
         let label = match alignment {
-            Alignment::Left => label.clone().left_aligned(),
+            Alignment::Left | Alignment::Justify => label.clone().left_aligned(),
             Alignment::Center => label.clone().centered(),
             Alignment::Right => label.clone().right_aligned(),
         };
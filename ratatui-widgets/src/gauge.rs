@@ -2,8 +2,8 @@
 use alloc::format;
 
 use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::Rect;
-use ratatui_core::style::{Color, Style, Styled};
+use ratatui_core::layout::{Position, Rect};
+use ratatui_core::style::{Color, Gradient, Style, Styled, Theme, roles};
 use ratatui_core::symbols;
 use ratatui_core::text::{Line, Span};
 use ratatui_core::widgets::Widget;
@@ -49,6 +49,7 @@ pub struct Gauge<'a> {
     use_unicode: bool,
     style: Style,
     gauge_style: Style,
+    gauge_gradient: Option<Gradient>,
 }
 
 impl<'a> Gauge<'a> {
@@ -139,6 +140,29 @@ impl<'a> Gauge<'a> {
         self
     }
 
+    /// Colors the bar with a [`Gradient`].
+    ///
+    /// The color of each cell of the filled part of the bar is the color of the gradient at the
+    /// position of the cell in the whole bar, following the [direction](Gradient::direction) of
+    /// the gradient. The gradient replaces the foreground color of [`Gauge::gauge_style`], so a
+    /// horizontal gradient reveals more of its colors as the gauge fills up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Gradient};
+    /// use ratatui::widgets::Gauge;
+    ///
+    /// Gauge::default()
+    ///     .gauge_gradient(Gradient::new([Color::Green, Color::Yellow, Color::Red]))
+    ///     .percent(60);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn gauge_gradient(mut self, gradient: Gradient) -> Self {
+        self.gauge_gradient = Some(gradient);
+        self
+    }
+
     /// Sets whether to use unicode characters to display the progress bar.
     ///
     /// This enables the use of
@@ -159,7 +183,7 @@ impl Widget for Gauge<'_> {
 
 impl Widget for &Gauge<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        buf.set_style(area, self.style);
+        buf.set_style(area, Theme::active_style(roles::GAUGE).patch(self.style));
         self.block.as_ref().render(area, buf);
         let inner = self.block.inner_if_some(area);
         self.render_gauge(inner, buf);
@@ -172,7 +196,8 @@ impl Gauge<'_> {
             return;
         }
 
-        buf.set_style(gauge_area, self.gauge_style);
+        let gauge_style = Theme::active_role_style(roles::GAUGE_BAR).patch(self.gauge_style);
+        buf.set_style(gauge_area, gauge_style);
 
         // compute label value and its position
         // label is put at the center of the gauge_area
@@ -189,6 +214,11 @@ impl Gauge<'_> {
         } else {
             gauge_area.left() + filled_width.round() as u16
         };
+        let fg = |x: u16, y: u16| match &self.gauge_gradient {
+            Some(gradient) => gradient.color_in(gauge_area, Position::new(x, y)),
+            None => gauge_style.fg.unwrap_or(Color::Reset),
+        };
+        let bg = gauge_style.bg.unwrap_or(Color::Reset);
         for y in gauge_area.top()..gauge_area.bottom() {
             // render the filled area (left to end)
             for x in gauge_area.left()..end {
@@ -198,17 +228,17 @@ impl Gauge<'_> {
                 if x < label_col || x > label_col + clamped_label_width || y != label_row {
                     buf[(x, y)]
                         .set_symbol(symbols::block::FULL)
-                        .set_fg(self.gauge_style.fg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.bg.unwrap_or(Color::Reset));
+                        .set_fg(fg(x, y))
+                        .set_bg(bg);
                 } else {
-                    buf[(x, y)]
-                        .set_symbol(" ")
-                        .set_fg(self.gauge_style.bg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.fg.unwrap_or(Color::Reset));
+                    buf[(x, y)].set_symbol(" ").set_fg(bg).set_bg(fg(x, y));
                 }
             }
             if self.use_unicode && self.ratio < 1.0 {
                 buf[(end, y)].set_symbol(get_unicode_block(filled_width % 1.0));
+                if self.gauge_gradient.is_some() {
+                    buf[(end, y)].set_fg(fg(end, y));
+                }
             }
         }
         // render the label
@@ -275,6 +305,7 @@ pub struct LineGauge<'a> {
     unfilled_symbol: &'a str,
     filled_style: Style,
     unfilled_style: Style,
+    filled_gradient: Option<Gradient>,
 }
 
 impl Default for LineGauge<'_> {
@@ -288,6 +319,7 @@ impl Default for LineGauge<'_> {
             unfilled_symbol: symbols::line::HORIZONTAL,
             filled_style: Style::default(),
             unfilled_style: Style::default(),
+            filled_gradient: None,
         }
     }
 }
@@ -413,6 +445,28 @@ impl<'a> LineGauge<'a> {
         self.unfilled_style = style.into();
         self
     }
+
+    /// Colors the filled part of the bar with a [`Gradient`].
+    ///
+    /// The foreground color of each filled cell is the color of the gradient at the position of
+    /// the cell in the gauge, following the [direction](Gradient::direction) of the gradient. The
+    /// gradient is applied on top of the [`LineGauge::filled_style`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Gradient};
+    /// use ratatui::widgets::LineGauge;
+    ///
+    /// LineGauge::default()
+    ///     .filled_gradient(Gradient::new([Color::Blue, Color::Magenta]))
+    ///     .ratio(0.4);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn filled_gradient(mut self, gradient: Gradient) -> Self {
+        self.filled_gradient = Some(gradient);
+        self
+    }
 }
 
 impl Widget for LineGauge<'_> {
@@ -423,7 +477,7 @@ impl Widget for LineGauge<'_> {
 
 impl Widget for &LineGauge<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        buf.set_style(area, self.style);
+        buf.set_style(area, Theme::active_style(roles::GAUGE).patch(self.style));
         self.block.as_ref().render(area, buf);
         let gauge_area = self.block.inner_if_some(area);
         if gauge_area.is_empty() {
@@ -439,12 +493,17 @@ impl Widget for &LineGauge<'_> {
             return;
         }
 
+        let filled_style = Theme::active_role_style(roles::GAUGE_BAR).patch(self.filled_style);
         let end = start
             + (f64::from(gauge_area.right().saturating_sub(start)) * self.ratio).floor() as u16;
+        let bar_area = Rect::new(start, row, gauge_area.right() - start, 1);
         for col in start..end {
-            buf[(col, row)]
+            let cell = buf[(col, row)]
                 .set_symbol(self.filled_symbol)
-                .set_style(self.filled_style);
+                .set_style(filled_style);
+            if let Some(gradient) = &self.filled_gradient {
+                cell.set_fg(gradient.color_in(bar_area, Position::new(col, row)));
+            }
         }
         for col in end..gauge_area.right() {
             buf[(col, row)]
@@ -582,11 +641,51 @@ mod tests {
                 filled_symbol: symbols::line::HORIZONTAL,
                 unfilled_symbol: symbols::line::HORIZONTAL,
                 filled_style: Style::default(),
-                unfilled_style: Style::default()
+                unfilled_style: Style::default(),
+                filled_gradient: None,
             }
         );
     }
 
+    #[test]
+    fn render_gauge_gradient() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Gauge::default()
+            .gauge_style(Style::new().on_blue())
+            .gauge_gradient(Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0)]))
+            .label("")
+            .ratio(0.6)
+            .render(buffer.area, &mut buffer);
+        // the colors of the cell of the (empty) label are swapped
+        let mut expected = Buffer::with_lines(["██   "]);
+        expected.set_style(buffer.area, Style::new().on_blue());
+        expected[(0, 0)].set_fg(Color::Rgb(0, 0, 0));
+        expected[(1, 0)].set_fg(Color::Rgb(50, 0, 0));
+        expected[(2, 0)]
+            .set_fg(Color::Blue)
+            .set_bg(Color::Rgb(100, 0, 0));
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_line_gauge_gradient() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 1));
+        LineGauge::default()
+            .filled_style(Style::new().bold())
+            .filled_gradient(Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0)]))
+            .label("x")
+            .ratio(0.5)
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["x ─────"]);
+        for (x, red) in [(2, 0), (3, 50)] {
+            expected[(x, 0)]
+                .set_fg(Color::Rgb(red, 0, 0))
+                .modifier
+                .insert(Modifier::BOLD);
+        }
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn render_in_minimal_buffer_gauge() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
@@ -0,0 +1,263 @@
+//! The [`LayoutDebug`] widget draws the result of a [`Layout`] on top of a frame for debugging.
+use alloc::format;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Constraint, ConstraintExplanation, Direction, Layout, Rect};
+use ratatui_core::style::{Color, Modifier, Style};
+use ratatui_core::text::Line;
+use ratatui_core::widgets::Widget;
+
+use crate::block::Block;
+use crate::borders::BorderType;
+
+/// A widget that visualizes how a [`Layout`] splits an area.
+///
+/// `LayoutDebug` is meant to be rendered on top of an existing frame, after the widgets of the
+/// application, to see where the segments of a layout ended up. For each segment it draws an
+/// outline labeled with the originating [`Constraint`] and the resulting size, and it highlights
+/// the spacers returned by [`Layout::split_with_spacers`]. Segments whose constraint could not be
+/// satisfied (see [`Layout::explain`]) are drawn with a different style so that collapsed panes
+/// are easy to spot.
+///
+/// Only the cells on the outlines and the labels are overwritten, and spacers only have their
+/// style patched, so most of the underlying content stays visible. The overlay can be toggled at
+/// runtime with [`LayoutDebug::enabled`], which makes it possible to leave it in an application
+/// behind a key binding.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::Frame;
+/// use ratatui::layout::{Constraint, Layout};
+/// use ratatui::widgets::{LayoutDebug, Paragraph};
+///
+/// fn draw(frame: &mut Frame, show_layout: bool) {
+///     let layout = Layout::horizontal([Constraint::Length(20), Constraint::Fill(1)]).spacing(1);
+///     let [sidebar, main] = frame.area().layout(&layout);
+///     frame.render_widget(Paragraph::new("sidebar"), sidebar);
+///     frame.render_widget(Paragraph::new("main"), main);
+///
+///     // render the overlay last so that it is drawn on top
+///     frame.render_widget(LayoutDebug::new(&layout).enabled(show_layout), frame.area());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LayoutDebug<'a> {
+    layout: &'a Layout,
+    enabled: bool,
+    show_labels: bool,
+    show_spacers: bool,
+    border_type: BorderType,
+    segment_style: Style,
+    unsatisfied_style: Style,
+    spacer_style: Style,
+}
+
+impl<'a> LayoutDebug<'a> {
+    /// Creates a new `LayoutDebug` overlay for the given layout.
+    ///
+    /// The overlay is enabled and shows labels and spacers by default.
+    pub const fn new(layout: &'a Layout) -> Self {
+        Self {
+            layout,
+            enabled: true,
+            show_labels: true,
+            show_spacers: true,
+            border_type: BorderType::Plain,
+            segment_style: Style::new().fg(Color::Cyan),
+            unsatisfied_style: Style::new().fg(Color::Red).add_modifier(Modifier::BOLD),
+            spacer_style: Style::new().bg(Color::DarkGray),
+        }
+    }
+
+    /// Sets whether the overlay is rendered.
+    ///
+    /// When disabled, rendering the widget does nothing. This makes it easy to toggle the overlay
+    /// at runtime without changing the rendering code.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Sets whether the constraint and size labels are rendered on the segment outlines.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_labels(mut self, show_labels: bool) -> Self {
+        self.show_labels = show_labels;
+        self
+    }
+
+    /// Sets whether the spacers between segments are highlighted.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_spacers(mut self, show_spacers: bool) -> Self {
+        self.show_spacers = show_spacers;
+        self
+    }
+
+    /// Sets the type of border used to outline the segments.
+    ///
+    /// Defaults to [`BorderType::Plain`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn border_type(mut self, border_type: BorderType) -> Self {
+        self.border_type = border_type;
+        self
+    }
+
+    /// Sets the style of the outlines and labels of segments whose constraint was satisfied.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn segment_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.segment_style = style.into();
+        self
+    }
+
+    /// Sets the style of the outlines and labels of segments whose constraint was not satisfied.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn unsatisfied_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.unsatisfied_style = style.into();
+        self
+    }
+
+    /// Sets the style patched onto the spacers between segments.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn spacer_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.spacer_style = style.into();
+        self
+    }
+}
+
+impl Widget for LayoutDebug<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &LayoutDebug<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if !self.enabled {
+            return;
+        }
+        let explanation = self.layout.explain(area);
+        if self.show_spacers {
+            for spacer in explanation.spacers.iter() {
+                self.render_spacer(*spacer, explanation.direction, buf);
+            }
+        }
+        for constraint in &explanation.constraints {
+            self.render_segment(constraint, buf);
+        }
+    }
+}
+
+impl LayoutDebug<'_> {
+    fn render_spacer(&self, spacer: Rect, direction: Direction, buf: &mut Buffer) {
+        let spacer = spacer.intersection(buf.area);
+        if spacer.is_empty() {
+            return;
+        }
+        buf.set_style(spacer, self.spacer_style);
+        if !self.show_labels {
+            return;
+        }
+        let size = match direction {
+            Direction::Horizontal => spacer.width,
+            Direction::Vertical => spacer.height,
+        };
+        let label = Line::from(format!("{size}")).centered();
+        // only label spacers that are large enough to show the whole size
+        if label.width() <= usize::from(spacer.width) {
+            let row = spacer.centered_vertically(Constraint::Length(1));
+            label.render(row, buf);
+        }
+    }
+
+    fn render_segment(&self, constraint: &ConstraintExplanation, buf: &mut Buffer) {
+        let style = if constraint.satisfied {
+            self.segment_style
+        } else {
+            self.unsatisfied_style
+        };
+        let mut block = Block::bordered()
+            .border_type(self.border_type)
+            .border_style(style);
+        if self.show_labels {
+            let label = format!("{}: {}", constraint.constraint, constraint.size);
+            block = block.title(Line::styled(label, style));
+        }
+        block.render(constraint.area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled() {
+        let layout = Layout::horizontal([Constraint::Fill(1); 2]);
+        let mut buf = Buffer::with_lines(["xxxxxxxxxx"; 3]);
+        LayoutDebug::new(&layout)
+            .enabled(false)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["xxxxxxxxxx"; 3]));
+    }
+
+    #[test]
+    fn render_segments() {
+        let layout = Layout::horizontal([Constraint::Length(4), Constraint::Fill(1)]);
+        let mut buf = Buffer::with_lines(["xxxxxxxxxx"; 3]);
+        LayoutDebug::new(&layout)
+            .show_labels(false)
+            .segment_style(Style::new())
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["┌──┐┌────┐", "│xx││xxxx│", "└──┘└────┘"])
+        );
+    }
+
+    #[test]
+    fn render_labels() {
+        // Min has a higher priority than Length, so the Length segment shrinks
+        let layout = Layout::vertical([Constraint::Length(2), Constraint::Min(3)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 4));
+        LayoutDebug::new(&layout).render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([
+            "┌Length(2): 1┐",
+            "┌Min(3): 3───┐",
+            "│            │",
+            "└────────────┘",
+        ]);
+        expected.set_style(
+            Rect::new(0, 0, 14, 1),
+            Style::new().fg(Color::Red).add_modifier(Modifier::BOLD),
+        );
+        expected.set_style(Rect::new(0, 1, 14, 3), Color::Cyan);
+        // the inside of the segments is left untouched
+        expected.set_style(Rect::new(1, 2, 12, 1), Style::reset());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_spacers() {
+        let layout = Layout::horizontal([Constraint::Length(3), Constraint::Length(3)]).spacing(2);
+        let mut buf = Buffer::with_lines(["xxxxxxxxxx"; 3]);
+        LayoutDebug::new(&layout)
+            .segment_style(Style::new())
+            .spacer_style(Style::new())
+            .show_labels(true)
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["┌L┐xx┌L┐xx", "│x│2x│x│2x", "└─┘xx└─┘xx"])
+        );
+    }
+}
@@ -40,11 +40,13 @@
 //! - [`Chart`]: displays multiple datasets as lines or scatter graphs.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`LayoutDebug`]: draws the segments and spacers of a layout on top of a frame for debugging.
 //! - [`LineGauge`]: displays progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
 //! - [`RatatuiMascot`]: displays the Ratatui mascot.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`ScrollView`]: displays a scrollable window into content larger than its area.
 //! - [`Scrollbar`]: displays a scrollbar.
 //! - [`Sparkline`]: displays a single dataset as a sparkline.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
@@ -57,11 +59,13 @@
 //! [`Chart`]: crate::chart::Chart
 //! [`Clear`]: crate::clear::Clear
 //! [`Gauge`]: crate::gauge::Gauge
+//! [`LayoutDebug`]: crate::layout_debug::LayoutDebug
 //! [`LineGauge`]: crate::gauge::LineGauge
 //! [`List`]: crate::list::List
 //! [`RatatuiLogo`]: crate::logo::RatatuiLogo
 //! [`RatatuiMascot`]: crate::mascot::RatatuiMascot
 //! [`Paragraph`]: crate::paragraph::Paragraph
+//! [`ScrollView`]: crate::scroll_view::ScrollView
 //! [`Scrollbar`]: crate::scrollbar::Scrollbar
 //! [`Sparkline`]: crate::sparkline::Sparkline
 //! [`Table`]: crate::table::Table
@@ -117,10 +121,12 @@ pub mod canvas;
 pub mod chart;
 pub mod clear;
 pub mod gauge;
+pub mod layout_debug;
 pub mod list;
 pub mod logo;
 pub mod mascot;
 pub mod paragraph;
+pub mod scroll_view;
 pub mod scrollbar;
 pub mod sparkline;
 pub mod table;
@@ -1,5 +1,5 @@
 use ratatui_core::style::Style;
-use ratatui_core::text::Text;
+use ratatui_core::text::{Text, Truncation};
 
 /// A single item in a [`List`]
 ///
@@ -73,6 +73,7 @@ use ratatui_core::text::Text;
 pub struct ListItem<'a> {
     pub(crate) content: Text<'a>,
     pub(crate) style: Style,
+    pub(crate) truncation: Option<Truncation>,
 }
 
 impl<'a> ListItem<'a> {
@@ -119,6 +120,7 @@ impl<'a> ListItem<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            truncation: None,
         }
     }
 
@@ -160,6 +162,33 @@ impl<'a> ListItem<'a> {
         self
     }
 
+    /// Sets how lines that don't fit in the list are truncated
+    ///
+    /// By default, lines that are wider than the list are cut off at its edge. With a
+    /// [`Truncation`], part of each line is replaced by a marker (`…` by default) instead. See
+    /// [`Line::truncate`] for more details.
+    ///
+    /// `truncation` accepts a [`Truncation`] or a [`TruncationMode`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::text::Truncation;
+    /// use ratatui::widgets::ListItem;
+    ///
+    /// let item = ListItem::new("A very long item").truncation(Truncation::end().marker("..."));
+    /// ```
+    ///
+    /// [`Line::truncate`]: ratatui_core::text::Line::truncate
+    /// [`TruncationMode`]: ratatui_core::text::TruncationMode
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn truncation<T: Into<Truncation>>(mut self, truncation: T) -> Self {
+        self.truncation = Some(truncation.into());
+        self
+    }
+
     /// Returns the item height
     ///
     /// # Examples
@@ -224,7 +253,7 @@ mod tests {
 
     use pretty_assertions::assert_eq;
     use ratatui_core::style::{Color, Modifier, Stylize};
-    use ratatui_core::text::{Line, Span};
+    use ratatui_core::text::{Line, Span, TruncationMode};
 
     use super::*;
 
@@ -324,6 +353,12 @@ mod tests {
         assert_eq!(item.style, Style::default().bg(Color::Red));
     }
 
+    #[test]
+    fn truncation() {
+        let item = ListItem::new("Test item").truncation(TruncationMode::Start);
+        assert_eq!(item.truncation, Some(Truncation::start()));
+    }
+
     #[test]
     fn height() {
         let item = ListItem::new("Test item");
@@ -1,5 +1,6 @@
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
+use ratatui_core::style::{Theme, roles};
 use ratatui_core::text::{Line, ToLine};
 use ratatui_core::widgets::{StatefulWidget, Widget};
 
@@ -31,7 +32,8 @@ impl StatefulWidget for &List<'_> {
     type State = ListState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        buf.set_style(area, self.style);
+        let style = Theme::active_style(roles::LIST).patch(self.style);
+        buf.set_style(area, style);
         self.block.as_ref().render(area, buf);
         let list_area = self.block.inner_if_some(area);
 
@@ -67,6 +69,8 @@ impl StatefulWidget for &List<'_> {
         let empty_symbol = " ".repeat(highlight_symbol_width as usize);
         let empty_symbol = empty_symbol.to_line();
 
+        let highlight_style =
+            Theme::active_role_style(roles::LIST_HIGHLIGHT).patch(self.highlight_style);
         let mut current_height = 0;
         let selection_spacing = self.highlight_spacing.should_add(state.selected.is_some());
         for (i, item) in self
@@ -87,7 +91,7 @@ impl StatefulWidget for &List<'_> {
 
             let row_area = Rect::new(x, y, list_area.width, item.height() as u16);
 
-            let item_style = self.style.patch(item.style);
+            let item_style = style.patch(item.style);
             buf.set_style(row_area, item_style);
 
             let is_selected = state.selected == Some(i);
@@ -101,10 +105,16 @@ impl StatefulWidget for &List<'_> {
             } else {
                 row_area
             };
-            Widget::render(&item.content, item_area, buf);
+            match item.truncation {
+                Some(truncation) => {
+                    let content = item.content.truncate(item_area.width, truncation);
+                    Widget::render(&content, item_area, buf);
+                }
+                None => Widget::render(&item.content, item_area, buf),
+            }
 
             if is_selected {
-                buf.set_style(row_area, self.highlight_style);
+                buf.set_style(row_area, highlight_style);
             }
             if selection_spacing {
                 for j in 0..item.content.height() {
@@ -262,7 +272,7 @@ mod tests {
     use pretty_assertions::assert_eq;
     use ratatui_core::layout::{Alignment, Rect};
     use ratatui_core::style::{Color, Modifier, Style, Stylize};
-    use ratatui_core::text::Line;
+    use ratatui_core::text::{Line, Truncation, TruncationMode};
     use ratatui_core::widgets::{StatefulWidget, Widget};
     use rstest::{fixture, rstest};
 
@@ -893,6 +903,24 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(expected));
     }
 
+    #[test]
+    fn long_lines_with_truncation() {
+        let items = [
+            ListItem::new("Item 0 with a very long line that will be truncated")
+                .truncation(TruncationMode::End),
+            ListItem::new("Item 1 with a very long line that will be truncated")
+                .truncation(Truncation::start().marker("..")),
+            ListItem::new("Item 2"),
+        ];
+        let list = List::new(items).highlight_symbol(">>");
+        let mut state = ListState::default().with_selected(Some(0));
+        let buffer = stateful_widget(list, &mut state, 15, 3);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([">>Item 0 with …", "  ..e truncated", "  Item 2       "])
+        );
+    }
+
     #[test]
     fn selected_item_ensures_selected_item_is_visible_when_offset_is_before_visible_range() {
         let items = [
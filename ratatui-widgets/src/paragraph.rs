@@ -1,11 +1,12 @@
 //! The [`Paragraph`] widget and related types allows displaying a block of text with optional
 //! wrapping, alignment, and block styling.
+use alloc::borrow::Cow;
+
 use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::{Alignment, Position, Rect};
-use ratatui_core::style::{Style, Styled};
-use ratatui_core::text::{Line, StyledGrapheme, Text};
+use ratatui_core::layout::{Alignment, Position, Rect, WritingDirection};
+use ratatui_core::style::{Style, Styled, Theme, roles};
+use ratatui_core::text::{Line, StyledGrapheme, TabStops, Text, WrapOptions};
 use ratatui_core::widgets::Widget;
-use unicode_width::UnicodeWidthStr;
 
 use crate::block::{Block, BlockExt};
 use crate::reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine};
@@ -24,6 +25,8 @@ use crate::reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine};
 ///
 /// The text can be aligned to the left, right, or center. The alignment can be configured with the
 /// [`alignment`] method or with the [`left_aligned`], [`right_aligned`], and [`centered`] methods.
+/// Wrapped text can also be justified with [`Alignment::Justify`], which fills each line except the
+/// last line of each paragraph by distributing the extra space between the words.
 ///
 /// The text can be scrolled to show a specific part of the text. The scroll offset can be set with
 /// the [`scroll`] method.
@@ -80,13 +83,17 @@ pub struct Paragraph<'a> {
     /// Widget style
     style: Style,
     /// How to wrap the text
-    wrap: Option<Wrap>,
+    wrap: Option<WrapOptions>,
     /// The text to display
     text: Text<'a>,
     /// Scroll
     scroll: Position,
     /// Alignment of the text
     alignment: Alignment,
+    /// Tab stops used to expand tab characters, or `None` for the global tab stops
+    tab_stops: Option<TabStops>,
+    /// The writing direction of the text, or `None` to render lines in logical order
+    writing_direction: Option<WritingDirection>,
 }
 
 /// Describes how to wrap text across lines.
@@ -158,6 +165,8 @@ impl<'a> Paragraph<'a> {
             text: text.into(),
             scroll: Position::ORIGIN,
             alignment: Alignment::Left,
+            tab_stops: None,
+            writing_direction: None,
         }
     }
 
@@ -213,7 +222,77 @@ impl<'a> Paragraph<'a> {
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn wrap(mut self, wrap: Wrap) -> Self {
-        self.wrap = Some(wrap);
+        self.wrap = Some(WrapOptions::new().trim(wrap.trim));
+        self
+    }
+
+    /// Sets the wrapping configuration for the widget with [`WrapOptions`].
+    ///
+    /// This supports more options than [`Paragraph::wrap`], such as breaking lines in the middle of
+    /// words, preserving the indentation of continuation lines, and hanging indents. The text is
+    /// wrapped the same way as by [`Text::wrap`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::text::WrapOptions;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let paragraph = Paragraph::new("- a long list item")
+    ///     .wrap_options(WrapOptions::new().trim(true).hanging_indent(2));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap_options(mut self, options: WrapOptions) -> Self {
+        self.wrap = Some(options);
+        self
+    }
+
+    /// Sets the tab stops used to expand tab characters (`\t`) in the text.
+    ///
+    /// Tab characters are replaced by spaces up to the next tab stop before the text is wrapped or
+    /// truncated, so that columns of source code and tab-separated data stay aligned. Tab stops
+    /// are counted from the start of each line. Defaults to the global tab stops (see
+    /// [`TabStops::global`]), which are every 8 columns unless changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::text::TabStops;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let paragraph = Paragraph::new("fn main() {\n\tprintln!();\n}").tab_stops(TabStops::every(4));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn tab_stops(mut self, tab_stops: TabStops) -> Self {
+        self.tab_stops = Some(tab_stops);
+        self
+    }
+
+    /// Sets the writing direction of the text, e.g. for Hebrew or Arabic text.
+    ///
+    /// The alignments of the paragraph and of its lines are expressed for left-to-right text, so
+    /// for [`WritingDirection::RightToLeft`] they are mirrored with
+    /// [`Alignment::for_writing_direction`]: left-aligned text (the default) is aligned to the
+    /// right, and the last line of justified text too.
+    ///
+    /// With the `unicode-bidi` feature, each line is also reordered for display with the Unicode
+    /// Bidirectional Algorithm after being wrapped or truncated (see [`Line::to_visual_order`]),
+    /// using the writing direction as the base direction. This makes mixed-direction text (e.g.
+    /// Hebrew with embedded English words or numbers) display correctly, and can also be used with
+    /// [`WritingDirection::LeftToRight`] for left-to-right text that contains right-to-left words.
+    /// Without this method, lines are rendered in logical order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::layout::WritingDirection;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let paragraph = Paragraph::new("שלום, world").writing_direction(WritingDirection::RightToLeft);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn writing_direction(mut self, writing_direction: WritingDirection) -> Self {
+        self.writing_direction = Some(writing_direction);
         self
     }
 
@@ -239,8 +318,9 @@ impl<'a> Paragraph<'a> {
 
     /// Set the text alignment for the given paragraph
     ///
-    /// The alignment is a variant of the [`Alignment`] enum which can be one of Left, Right, or
-    /// Center. If no alignment is specified, the text in a paragraph will be left-aligned.
+    /// The alignment is a variant of the [`Alignment`] enum which can be one of Left, Right,
+    /// Center, or Justify. If no alignment is specified, the text in a paragraph will be
+    /// left-aligned. Justify only has an effect on wrapped text, see [`Paragraph::wrap`].
     ///
     /// # Example
     ///
@@ -339,8 +419,9 @@ impl<'a> Paragraph<'a> {
             .map(Block::vertical_space)
             .unwrap_or_default();
 
-        let count = if let Some(Wrap { trim }) = self.wrap {
-            let styled = self.text.iter().map(|line| {
+        let count = if let Some(options) = self.wrap {
+            let text = self.expanded_text();
+            let styled = text.iter().map(|line| {
                 let graphemes = line
                     .spans
                     .iter()
@@ -348,7 +429,7 @@ impl<'a> Paragraph<'a> {
                 let alignment = line.alignment.unwrap_or(self.alignment);
                 (graphemes, alignment)
             });
-            let mut line_composer = WordWrapper::new(styled, width, trim);
+            let mut line_composer = WordWrapper::new(styled, width, options);
             let mut count = 0;
             while line_composer.next_line().is_some() {
                 count += 1;
@@ -385,7 +466,12 @@ impl<'a> Paragraph<'a> {
         issue = "https://github.com/ratatui/ratatui/issues/293"
     )]
     pub fn line_width(&self) -> usize {
-        let width = self.text.iter().map(Line::width).max().unwrap_or_default();
+        let width = self
+            .expanded_text()
+            .iter()
+            .map(Line::width)
+            .max()
+            .unwrap_or_default();
         let (left, right) = self
             .block
             .as_ref()
@@ -407,49 +493,73 @@ impl Widget for Paragraph<'_> {
 impl Widget for &Paragraph<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let area = area.intersection(buf.area);
-        buf.set_style(area, self.style);
+        let style = Theme::active_style(roles::PARAGRAPH).patch(self.style);
+        buf.set_style(area, style);
         self.block.as_ref().render(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_paragraph(inner, buf);
+        self.render_paragraph(inner, buf, style);
     }
 }
 
-impl Paragraph<'_> {
-    fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer) {
+impl<'a> Paragraph<'a> {
+    /// Returns the text with tab characters expanded to the tab stops of the paragraph.
+    fn expanded_text(&self) -> Cow<'_, Text<'a>> {
+        if self
+            .text
+            .iter()
+            .any(|line| line.spans.iter().any(|span| span.content.contains('\t')))
+        {
+            let tab_stops = self.tab_stops.clone().unwrap_or_else(TabStops::global);
+            Cow::Owned(self.text.expand_tabs(&tab_stops))
+        } else {
+            Cow::Borrowed(&self.text)
+        }
+    }
+
+    fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer, style: Style) {
         if text_area.is_empty() {
             return;
         }
 
-        buf.set_style(text_area, self.style);
-        let styled = self.text.iter().map(|line| {
-            let graphemes = line.styled_graphemes(self.text.style);
+        buf.set_style(text_area, style);
+        let text = self.expanded_text();
+        let styled = text.iter().map(|line| {
+            let graphemes = line.styled_graphemes(text.style);
             let alignment = line.alignment.unwrap_or(self.alignment);
+            let alignment = self.writing_direction.map_or(alignment, |direction| {
+                alignment.for_writing_direction(direction)
+            });
             (graphemes, alignment)
         });
 
-        if let Some(Wrap { trim }) = self.wrap {
-            let mut line_composer = WordWrapper::new(styled, text_area.width, trim);
+        if let Some(options) = self.wrap {
+            let mut line_composer = WordWrapper::new(styled, text_area.width, options);
             // compute the lines iteratively until we reach the desired scroll offset.
             for _ in 0..self.scroll.y {
                 if line_composer.next_line().is_none() {
                     return;
                 }
             }
-            render_lines(line_composer, text_area, buf);
+            render_lines(line_composer, text_area, buf, self.writing_direction);
         } else {
             // avoid unnecessary work by skipping directly to the relevant line before rendering
             let lines = styled.skip(self.scroll.y as usize);
             let mut line_composer = LineTruncator::new(lines, text_area.width);
             line_composer.set_horizontal_offset(self.scroll.x);
-            render_lines(line_composer, text_area, buf);
+            render_lines(line_composer, text_area, buf, self.writing_direction);
         }
     }
 }
 
-fn render_lines<'a, C: LineComposer<'a>>(mut composer: C, area: Rect, buf: &mut Buffer) {
+fn render_lines<'a, C: LineComposer<'a>>(
+    mut composer: C,
+    area: Rect,
+    buf: &mut Buffer,
+    direction: Option<WritingDirection>,
+) {
     let mut y = 0;
     while let Some(ref wrapped) = composer.next_line() {
-        render_line(wrapped, area, buf, y);
+        render_line(wrapped, area, buf, y, direction);
         y += 1;
         if y >= area.height {
             break;
@@ -457,17 +567,53 @@ fn render_lines<'a, C: LineComposer<'a>>(mut composer: C, area: Rect, buf: &mut
     }
 }
 
-fn render_line(wrapped: &WrappedLine<'_, '_>, area: Rect, buf: &mut Buffer, y: u16) {
-    let mut x = get_line_offset(wrapped.width, area.width, wrapped.alignment);
-    for StyledGrapheme { symbol, style } in wrapped.graphemes {
-        let width = symbol.width();
+fn render_line(
+    wrapped: &WrappedLine<'_, '_>,
+    area: Rect,
+    buf: &mut Buffer,
+    y: u16,
+    direction: Option<WritingDirection>,
+) {
+    // the last line of justified text starts on the right in right-to-left text
+    let alignment = match (wrapped.alignment, direction) {
+        (Alignment::Justify, Some(WritingDirection::RightToLeft)) => Alignment::Right,
+        (alignment, _) => alignment,
+    };
+    let x = get_line_offset(wrapped.width, area.width, alignment);
+    #[cfg(feature = "unicode-bidi")]
+    if let Some(direction) = direction {
+        let line: Line = wrapped
+            .graphemes
+            .iter()
+            .map(|grapheme| ratatui_core::text::Span::styled(grapheme.symbol, grapheme.style))
+            .collect();
+        let visual = line.to_visual_order(direction);
+        render_graphemes(visual.styled_graphemes(Style::new()), area, buf, x, y);
+        return;
+    }
+    render_graphemes(wrapped.graphemes.iter().cloned(), area, buf, x, y);
+}
+
+fn render_graphemes<'a>(
+    graphemes: impl Iterator<Item = StyledGrapheme<'a>>,
+    area: Rect,
+    buf: &mut Buffer,
+    mut x: u16,
+    y: u16,
+) {
+    for grapheme in graphemes {
+        let width = grapheme.width();
         if width == 0 {
             continue;
         }
         // Make sure to overwrite any previous character with a space (rather than a zero-width)
-        let symbol = if symbol.is_empty() { " " } else { symbol };
+        let symbol = if grapheme.symbol.is_empty() {
+            " "
+        } else {
+            grapheme.symbol
+        };
         let position = Position::new(area.left() + x, area.top() + y);
-        buf[position].set_symbol(symbol).set_style(*style);
+        buf[position].set_symbol(symbol).set_style(grapheme.style);
         x += u16::try_from(width).unwrap_or(u16::MAX);
     }
 }
@@ -476,7 +622,7 @@ const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Align
     match alignment {
         Alignment::Center => (text_area_width / 2).saturating_sub(line_width / 2),
         Alignment::Right => text_area_width.saturating_sub(line_width),
-        Alignment::Left => 0,
+        Alignment::Left | Alignment::Justify => 0,
     }
 }
 
@@ -770,6 +916,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_render_paragraph_with_wrap_options() {
+        let text = "- a list item that wraps\n  - nested item that wraps";
+        let paragraph = Paragraph::new(text).wrap_options(
+            WrapOptions::new()
+                .trim(true)
+                .preserve_indentation(true)
+                .hanging_indent(2),
+        );
+        test_case(
+            &paragraph,
+            &Buffer::with_lines([
+                "- a list item ",
+                "  that wraps  ",
+                "  - nested    ",
+                "    item that ",
+                "    wraps     ",
+            ]),
+        );
+        assert_eq!(paragraph.line_count(14), 5);
+    }
+
+    #[test]
+    fn test_render_paragraph_with_tabs() {
+        let text = "a\tb\tc\nlong\td";
+        let paragraph = Paragraph::new(text);
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["a       b      ", "long    d      "]),
+        );
+        let paragraph = paragraph.tab_stops(TabStops::every(4));
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["a   b   c      ", "long    d      "]),
+        );
+        let paragraph = paragraph.tab_stops(TabStops::at([2, 6]));
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["a b   c        ", "long  d        "]),
+        );
+        assert_eq!(paragraph.line_width(), 7);
+    }
+
+    #[test]
+    fn test_render_wrapped_paragraph_with_tabs() {
+        let paragraph = Paragraph::new("ab\tcd\tef")
+            .tab_stops(TabStops::every(4))
+            .wrap(Wrap { trim: true });
+        test_case(&paragraph, &Buffer::with_lines(["ab  cd  ", "ef      "]));
+        assert_eq!(paragraph.line_count(8), 2);
+    }
+
     #[test]
     fn test_render_wrapped_paragraph_with_whitespace_only_line() {
         let text: Text = ["A", "  ", "B", "  a", "C"]
@@ -883,6 +1081,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_render_paragraph_with_justify_alignment() {
+        let text = "Hello, brave new world!\nThe end.";
+        let truncated_paragraph = Paragraph::new(text).alignment(Alignment::Justify);
+        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: true });
+
+        test_case(
+            &truncated_paragraph,
+            &Buffer::with_lines(["Hello, brave ", "The end.     "]),
+        );
+        test_case(
+            &wrapped_paragraph,
+            &Buffer::with_lines(["Hello,  brave", "new world!   ", "The end.     "]),
+        );
+    }
+
     #[test]
     fn test_render_paragraph_with_scroll_offset() {
         let text = "This is a\ncool\nmultiline\nparagraph.";
@@ -1236,4 +1450,59 @@ mod tests {
         // This should not panic, even if the buffer has zero size.
         paragraph.render(buffer.area, &mut buffer);
     }
+
+    #[rstest]
+    #[case::left(Alignment::Left, "   ab")]
+    #[case::center(Alignment::Center, " ab  ")]
+    #[case::right(Alignment::Right, "ab   ")]
+    #[case::justify(Alignment::Justify, "   ab")]
+    fn right_to_left_mirrors_alignment(#[case] alignment: Alignment, #[case] expected: &str) {
+        let paragraph = Paragraph::new("ab")
+            .alignment(alignment)
+            .writing_direction(WritingDirection::RightToLeft);
+        test_case(&paragraph, &Buffer::with_lines([expected]));
+
+        let paragraph = Paragraph::new(Line::from("ab").alignment(alignment))
+            .writing_direction(WritingDirection::RightToLeft);
+        test_case(&paragraph, &Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn right_to_left_justified_last_line() {
+        let paragraph = Paragraph::new("aa bb cc")
+            .alignment(Alignment::Justify)
+            .wrap(Wrap { trim: true })
+            .writing_direction(WritingDirection::RightToLeft);
+        test_case(&paragraph, &Buffer::with_lines(["aa  bb", "    cc"]));
+    }
+
+    #[cfg(feature = "unicode-bidi")]
+    #[test]
+    fn right_to_left_reorders_mixed_text() {
+        let line = Line::from(vec!["שלום".bold(), " (world)".into()]);
+        let paragraph = Paragraph::new(line).writing_direction(WritingDirection::RightToLeft);
+        let mut expected = Buffer::with_lines(["  (world) םולש"]);
+        expected.set_style(Rect::new(10, 0, 4, 1), Style::new().bold());
+        test_case(&paragraph, &expected);
+    }
+
+    #[cfg(feature = "unicode-bidi")]
+    #[test]
+    fn right_to_left_reorders_wrapped_lines() {
+        let paragraph = Paragraph::new("שלום עולם hello")
+            .wrap(Wrap { trim: true })
+            .writing_direction(WritingDirection::RightToLeft);
+        test_case(
+            &paragraph,
+            &Buffer::with_lines([" םלוע םולש", "     hello"]),
+        );
+    }
+
+    #[cfg(feature = "unicode-bidi")]
+    #[test]
+    fn left_to_right_reorders_embedded_right_to_left_text() {
+        let paragraph =
+            Paragraph::new("say שלום now").writing_direction(WritingDirection::LeftToRight);
+        test_case(&paragraph, &Buffer::with_lines(["say םולש now  "]));
+    }
 }
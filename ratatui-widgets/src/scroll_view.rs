@@ -0,0 +1,639 @@
+//! The [`ScrollView`] widget renders content that is larger than the area it is displayed in.
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Position, Rect, Size};
+use ratatui_core::text::WidthPolicy;
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use strum::{Display, EnumString};
+
+use crate::scrollbar::{Scrollbar, ScrollbarOrientation, ScrollbarState};
+
+/// A container that displays a scrollable window into content larger than its area.
+///
+/// Widgets render into exactly the [`Rect`] they are given, so a form or a long composite view that
+/// doesn't fit on the screen can't be scrolled directly. A `ScrollView` solves this by giving its
+/// children a virtual canvas of an arbitrary [`Size`], backed by its own [`Buffer`]. Widgets are
+/// rendered into the canvas with [`ScrollView::render_widget`] and
+/// [`ScrollView::render_stateful_widget`] using areas relative to the canvas. When the scroll view
+/// itself is rendered, the part of the canvas selected by the offset of the [`ScrollViewState`] is
+/// copied into the frame.
+///
+/// Vertical and horizontal [`Scrollbar`]s are drawn on the right and bottom edges of the area when
+/// the content doesn't fit (see [`ScrollbarVisibility`]). The scrollbars can be customized with
+/// [`ScrollView::vertical_scrollbar`] and [`ScrollView::horizontal_scrollbar`].
+///
+/// The canvas is allocated when the scroll view is created, so its size should be kept reasonable.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::Frame;
+/// use ratatui::layout::{Rect, Size};
+/// use ratatui::widgets::{Paragraph, ScrollView, ScrollViewState};
+///
+/// fn draw(frame: &mut Frame, state: &mut ScrollViewState) {
+///     let mut scroll_view = ScrollView::new(Size::new(40, 100));
+///     for row in 0..100 {
+///         let line = Paragraph::new(format!("Line {row}"));
+///         scroll_view.render_widget(line, Rect::new(0, row, 40, 1));
+///     }
+///     frame.render_stateful_widget(scroll_view, frame.area(), state);
+/// }
+///
+/// // in the event handler
+/// # let mut state = ScrollViewState::default();
+/// state.scroll_down();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScrollView<'a> {
+    buf: Buffer,
+    vertical_scrollbar: Scrollbar<'a>,
+    horizontal_scrollbar: Scrollbar<'a>,
+    vertical_scrollbar_visibility: ScrollbarVisibility,
+    horizontal_scrollbar_visibility: ScrollbarVisibility,
+}
+
+/// The state of a [`ScrollView`], i.e. the position of the visible window within the content.
+///
+/// The offset is clamped when the scroll view is rendered so that the window never goes past the
+/// end of the content. The sizes of the content and of the window are stored when rendering, which
+/// allows the page scrolling methods to move by a whole window and the scrollbar states to be
+/// queried for rendering custom scrollbars.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollViewState {
+    /// The position of the top left corner of the visible window within the content.
+    offset: Position,
+    /// The size of the content, from the last render.
+    size: Option<Size>,
+    /// The size of the visible window, from the last render.
+    page_size: Option<Size>,
+}
+
+/// When to show the scrollbars of a [`ScrollView`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrollbarVisibility {
+    /// Show the scrollbar only when the content doesn't fit in the area (default).
+    #[default]
+    Automatic,
+    /// Always show the scrollbar, even when the content fits in the area.
+    Always,
+    /// Never show the scrollbar.
+    Never,
+}
+
+impl<'a> ScrollView<'a> {
+    /// Creates a new scroll view with a canvas of the given size.
+    ///
+    /// Both scrollbars are shown automatically when the content doesn't fit in the area.
+    pub fn new(size: Size) -> Self {
+        Self {
+            buf: Buffer::empty(Rect::from(size)),
+            vertical_scrollbar: Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            horizontal_scrollbar: Scrollbar::new(ScrollbarOrientation::HorizontalBottom),
+            vertical_scrollbar_visibility: ScrollbarVisibility::Automatic,
+            horizontal_scrollbar_visibility: ScrollbarVisibility::Automatic,
+        }
+    }
+
+    /// Returns the size of the canvas.
+    pub const fn size(&self) -> Size {
+        self.buf.area.as_size()
+    }
+
+    /// Returns the area of the canvas, which starts at `(0, 0)`.
+    ///
+    /// This is useful to split the canvas with a [`Layout`](ratatui_core::layout::Layout).
+    pub const fn area(&self) -> Rect {
+        self.buf.area
+    }
+
+    /// Returns a reference to the buffer backing the canvas.
+    pub const fn buf(&self) -> &Buffer {
+        &self.buf
+    }
+
+    /// Returns a mutable reference to the buffer backing the canvas.
+    ///
+    /// This can be used to render widgets that are not owned or to draw directly into the canvas.
+    pub const fn buf_mut(&mut self) -> &mut Buffer {
+        &mut self.buf
+    }
+
+    /// Renders a widget into the canvas.
+    ///
+    /// The area is relative to the canvas. Parts of the area outside of the canvas are not
+    /// rendered.
+    pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
+        widget.render(area.intersection(self.buf.area), &mut self.buf);
+    }
+
+    /// Renders a stateful widget into the canvas.
+    ///
+    /// The area is relative to the canvas. Parts of the area outside of the canvas are not
+    /// rendered.
+    pub fn render_stateful_widget<W: StatefulWidget>(
+        &mut self,
+        widget: W,
+        area: Rect,
+        state: &mut W::State,
+    ) {
+        widget.render(area.intersection(self.buf.area), &mut self.buf, state);
+    }
+
+    /// Sets the scrollbar drawn on the right edge of the area.
+    ///
+    /// The orientation of the scrollbar is always set to [`ScrollbarOrientation::VerticalRight`],
+    /// but its symbols and styles are kept.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn vertical_scrollbar(mut self, scrollbar: Scrollbar<'a>) -> Self {
+        self.vertical_scrollbar = scrollbar.with_orientation(ScrollbarOrientation::VerticalRight);
+        self
+    }
+
+    /// Sets the scrollbar drawn on the bottom edge of the area.
+    ///
+    /// The orientation of the scrollbar is always set to
+    /// [`ScrollbarOrientation::HorizontalBottom`], but its symbols and styles are kept.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn horizontal_scrollbar(mut self, scrollbar: Scrollbar<'a>) -> Self {
+        self.horizontal_scrollbar =
+            scrollbar.with_orientation(ScrollbarOrientation::HorizontalBottom);
+        self
+    }
+
+    /// Sets when the vertical scrollbar is shown.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn vertical_scrollbar_visibility(mut self, visibility: ScrollbarVisibility) -> Self {
+        self.vertical_scrollbar_visibility = visibility;
+        self
+    }
+
+    /// Sets when the horizontal scrollbar is shown.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn horizontal_scrollbar_visibility(
+        mut self,
+        visibility: ScrollbarVisibility,
+    ) -> Self {
+        self.horizontal_scrollbar_visibility = visibility;
+        self
+    }
+
+    /// Sets when both scrollbars are shown.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scrollbars_visibility(mut self, visibility: ScrollbarVisibility) -> Self {
+        self.vertical_scrollbar_visibility = visibility;
+        self.horizontal_scrollbar_visibility = visibility;
+        self
+    }
+}
+
+impl StatefulWidget for ScrollView<'_> {
+    type State = ScrollViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &ScrollView<'_> {
+    type State = ScrollViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        let size = self.size();
+        let (show_vertical, show_horizontal) = self.visible_scrollbars(area, size);
+        let viewport = Rect {
+            width: area.width.saturating_sub(u16::from(show_vertical)),
+            height: area.height.saturating_sub(u16::from(show_horizontal)),
+            ..area
+        };
+
+        state.size = Some(size);
+        state.page_size = Some(viewport.as_size());
+        state.clamp_offset();
+
+        self.render_visible_area(viewport, buf, state.offset);
+        if show_vertical {
+            let scrollbar_area = Rect {
+                height: viewport.height,
+                ..area
+            };
+            let mut scrollbar_state = state.vertical_scrollbar_state();
+            self.vertical_scrollbar
+                .clone()
+                .render(scrollbar_area, buf, &mut scrollbar_state);
+        }
+        if show_horizontal {
+            let scrollbar_area = Rect {
+                width: viewport.width,
+                ..area
+            };
+            let mut scrollbar_state = state.horizontal_scrollbar_state();
+            self.horizontal_scrollbar
+                .clone()
+                .render(scrollbar_area, buf, &mut scrollbar_state);
+        }
+    }
+}
+
+impl ScrollView<'_> {
+    /// Returns whether the vertical and horizontal scrollbars are shown.
+    ///
+    /// Showing one scrollbar reduces the space available for the content in the other direction,
+    /// which can make the other scrollbar necessary.
+    fn visible_scrollbars(&self, area: Rect, size: Size) -> (bool, bool) {
+        let needed = |visibility, content: u16, available: u16| match visibility {
+            ScrollbarVisibility::Automatic => content > available,
+            ScrollbarVisibility::Always => true,
+            ScrollbarVisibility::Never => false,
+        };
+        let mut vertical = needed(self.vertical_scrollbar_visibility, size.height, area.height);
+        let horizontal = needed(
+            self.horizontal_scrollbar_visibility,
+            size.width,
+            area.width.saturating_sub(u16::from(vertical)),
+        );
+        if horizontal && !vertical {
+            vertical = needed(
+                self.vertical_scrollbar_visibility,
+                size.height,
+                area.height.saturating_sub(1),
+            );
+        }
+        (vertical, horizontal)
+    }
+
+    /// Copies the part of the canvas that starts at `offset` into the viewport.
+    ///
+    /// Wide graphemes cut by the edges of the viewport are replaced with blank cells in their
+    /// style, as the terminal would otherwise draw them over the cells next to the viewport.
+    fn render_visible_area(&self, viewport: Rect, buf: &mut Buffer, offset: Position) {
+        let width_policy = WidthPolicy::global();
+        for position in viewport.positions() {
+            let source = Position::new(
+                offset.x.saturating_add(position.x - viewport.x),
+                offset.y.saturating_add(position.y - viewport.y),
+            );
+            let (Some(cell), Some(target)) = (self.buf.cell(source), buf.cell_mut(position)) else {
+                continue;
+            };
+            let columns_left = usize::from(viewport.right() - position.x);
+            if width_policy.str_width(cell.symbol()) > columns_left {
+                target.reset();
+                target.set_style(cell.style());
+            } else {
+                target.clone_from(cell);
+            }
+        }
+
+        // the first column shows the trailing half of a wide grapheme that starts before it
+        let Some(previous_x) = offset.x.checked_sub(1) else {
+            return;
+        };
+        for y in viewport.rows().map(|row| row.y) {
+            let source = Position::new(previous_x, offset.y.saturating_add(y - viewport.y));
+            let position = Position::new(viewport.x, y);
+            if let (Some(cell), Some(target)) = (self.buf.cell(source), buf.cell_mut(position)) {
+                if width_policy.str_width(cell.symbol()) > 1 {
+                    target.reset();
+                    target.set_style(cell.style());
+                }
+            }
+        }
+    }
+}
+
+impl ScrollViewState {
+    /// Creates a new state with the window at the top left corner of the content.
+    pub const fn new() -> Self {
+        Self {
+            offset: Position::ORIGIN,
+            size: None,
+            page_size: None,
+        }
+    }
+
+    /// Creates a new state with the window at the given offset.
+    pub const fn with_offset(offset: Position) -> Self {
+        Self {
+            offset,
+            size: None,
+            page_size: None,
+        }
+    }
+
+    /// Returns the position of the top left corner of the visible window within the content.
+    pub const fn offset(&self) -> Position {
+        self.offset
+    }
+
+    /// Sets the position of the top left corner of the visible window within the content.
+    pub fn set_offset(&mut self, offset: Position) {
+        self.offset = offset;
+        self.clamp_offset();
+    }
+
+    /// Moves the window up by one row.
+    pub const fn scroll_up(&mut self) {
+        self.offset.y = self.offset.y.saturating_sub(1);
+    }
+
+    /// Moves the window down by one row.
+    pub fn scroll_down(&mut self) {
+        self.offset.y = self.offset.y.saturating_add(1);
+        self.clamp_offset();
+    }
+
+    /// Moves the window left by one column.
+    pub const fn scroll_left(&mut self) {
+        self.offset.x = self.offset.x.saturating_sub(1);
+    }
+
+    /// Moves the window right by one column.
+    pub fn scroll_right(&mut self) {
+        self.offset.x = self.offset.x.saturating_add(1);
+        self.clamp_offset();
+    }
+
+    /// Moves the window up by its height, as of the last render.
+    pub fn scroll_page_up(&mut self) {
+        let page = self.page_size.map_or(1, |size| size.height.max(1));
+        self.offset.y = self.offset.y.saturating_sub(page);
+    }
+
+    /// Moves the window down by its height, as of the last render.
+    pub fn scroll_page_down(&mut self) {
+        let page = self.page_size.map_or(1, |size| size.height.max(1));
+        self.offset.y = self.offset.y.saturating_add(page);
+        self.clamp_offset();
+    }
+
+    /// Moves the window to the top of the content.
+    pub const fn scroll_to_top(&mut self) {
+        self.offset = Position::ORIGIN;
+    }
+
+    /// Moves the window to the bottom of the content.
+    ///
+    /// If the scroll view has not been rendered yet, the offset is clamped on the next render.
+    pub fn scroll_to_bottom(&mut self) {
+        self.offset = Position::new(0, u16::MAX);
+        self.clamp_offset();
+    }
+
+    /// Returns the state of the vertical scrollbar, as of the last render.
+    ///
+    /// This can be used to render a custom [`Scrollbar`] when the built-in scrollbars are hidden
+    /// with [`ScrollbarVisibility::Never`].
+    pub fn vertical_scrollbar_state(&self) -> ScrollbarState {
+        let max_offset = self.max_offset();
+        let page = self.page_size.unwrap_or_default();
+        ScrollbarState::new(usize::from(max_offset.y) + 1)
+            .position(usize::from(self.offset.y))
+            .viewport_content_length(usize::from(page.height))
+    }
+
+    /// Returns the state of the horizontal scrollbar, as of the last render.
+    ///
+    /// This can be used to render a custom [`Scrollbar`] when the built-in scrollbars are hidden
+    /// with [`ScrollbarVisibility::Never`].
+    pub fn horizontal_scrollbar_state(&self) -> ScrollbarState {
+        let max_offset = self.max_offset();
+        let page = self.page_size.unwrap_or_default();
+        ScrollbarState::new(usize::from(max_offset.x) + 1)
+            .position(usize::from(self.offset.x))
+            .viewport_content_length(usize::from(page.width))
+    }
+
+    /// The largest offset that keeps the window within the content.
+    const fn max_offset(&self) -> Position {
+        match (self.size, self.page_size) {
+            (Some(size), Some(page)) => Position::new(
+                size.width.saturating_sub(page.width),
+                size.height.saturating_sub(page.height),
+            ),
+            _ => Position::new(u16::MAX, u16::MAX),
+        }
+    }
+
+    fn clamp_offset(&mut self) {
+        let max_offset = self.max_offset();
+        self.offset.x = self.offset.x.min(max_offset.x);
+        self.offset.y = self.offset.y.min(max_offset.y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::{Style, Stylize};
+    use ratatui_core::text::Line;
+
+    use super::*;
+    use crate::paragraph::Paragraph;
+
+    /// A scroll view with 10 rows of "row N" followed by the column index, 12 columns wide.
+    fn scroll_view() -> ScrollView<'static> {
+        let mut scroll_view = ScrollView::new(Size::new(12, 10));
+        for row in 0..10 {
+            let line = Line::from(format!("{row}123456789ab"));
+            scroll_view.render_widget(line, Rect::new(0, row, 12, 1));
+        }
+        scroll_view
+    }
+
+    #[test]
+    fn new() {
+        let scroll_view = ScrollView::new(Size::new(10, 20));
+        assert_eq!(scroll_view.size(), Size::new(10, 20));
+        assert_eq!(scroll_view.area(), Rect::new(0, 0, 10, 20));
+        assert_eq!(scroll_view.buf(), &Buffer::empty(Rect::new(0, 0, 10, 20)));
+    }
+
+    #[test]
+    fn render_widget_clips_to_canvas() {
+        let mut scroll_view = ScrollView::new(Size::new(4, 1));
+        scroll_view.render_widget(Paragraph::new("abcdef"), Rect::new(2, 0, 10, 5));
+        assert_eq!(scroll_view.buf(), &Buffer::with_lines(["  ab"]));
+    }
+
+    #[test]
+    fn content_fits() {
+        let mut scroll_view = ScrollView::new(Size::new(3, 2));
+        scroll_view.render_widget(Paragraph::new("abc\ndef"), Rect::new(0, 0, 3, 2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        let mut state = ScrollViewState::with_offset(Position::new(2, 2));
+        StatefulWidget::render(&scroll_view, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["abc  ", "def  ", "     "]));
+        assert_eq!(state.offset(), Position::ORIGIN);
+    }
+
+    #[test]
+    fn render_with_scrollbars() {
+        let scroll_view = scroll_view();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+        let mut state = ScrollViewState::default();
+        StatefulWidget::render(&scroll_view, buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["01234▲", "11234█", "21234▼", "◄█══► "])
+        );
+        assert_eq!(state.page_size, Some(Size::new(5, 3)));
+    }
+
+    #[test]
+    fn render_scrolled() {
+        let scroll_view = scroll_view().scrollbars_visibility(ScrollbarVisibility::Never);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        let mut state = ScrollViewState::with_offset(Position::new(3, 5));
+        StatefulWidget::render(&scroll_view, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["3456", "3456"]));
+
+        // the offset is clamped to the end of the content
+        let mut state = ScrollViewState::with_offset(Position::new(100, 100));
+        StatefulWidget::render(&scroll_view, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["89ab", "89ab"]));
+        assert_eq!(state.offset(), Position::new(8, 8));
+    }
+
+    /// A scroll view with a single row of red wide graphemes.
+    fn wide_scroll_view() -> ScrollView<'static> {
+        let mut scroll_view =
+            ScrollView::new(Size::new(6, 1)).scrollbars_visibility(ScrollbarVisibility::Never);
+        scroll_view.render_widget(Line::from("日本語".red()), Rect::new(0, 0, 6, 1));
+        scroll_view
+    }
+
+    #[test]
+    fn render_wide_graphemes_at_odd_offset() {
+        let mut buf = Buffer::with_lines(["xxxxxx"]);
+        let mut state = ScrollViewState::with_offset(Position::new(1, 0));
+        wide_scroll_view().render(Rect::new(1, 0, 4, 1), &mut buf, &mut state);
+        // the halves of the graphemes cut by the edges are blank, the trailing cells are unstyled
+        let mut expected = Buffer::with_lines(["x 本 x"]);
+        expected.set_style(Rect::new(1, 0, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(4, 0, 1, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_wide_graphemes_in_odd_width_viewport() {
+        let mut buf = Buffer::with_lines(["xxxxx"]);
+        let mut state = ScrollViewState::default();
+        wide_scroll_view().render(Rect::new(1, 0, 3, 1), &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["x日 x"]);
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(3, 0, 1, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_in_offset_area() {
+        let scroll_view = scroll_view().scrollbars_visibility(ScrollbarVisibility::Never);
+        let mut buf = Buffer::with_lines(["xxxxxx"; 3]);
+        let mut state = ScrollViewState::with_offset(Position::new(1, 1));
+        scroll_view.render(Rect::new(1, 1, 3, 2), &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["xxxxxx", "x123xx", "x123xx"]));
+    }
+
+    #[test]
+    fn only_horizontal_scrollbar() {
+        let mut scroll_view = ScrollView::new(Size::new(8, 2));
+        scroll_view.render_widget(Paragraph::new("abcdefgh\nijklmnop"), Rect::new(0, 0, 8, 2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        let mut state = ScrollViewState::default();
+        StatefulWidget::render(&scroll_view, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["abcd", "ijkl", "◄█═►"]));
+    }
+
+    #[test]
+    fn horizontal_scrollbar_makes_vertical_scrollbar_necessary() {
+        let mut scroll_view = ScrollView::new(Size::new(8, 4));
+        scroll_view.render_widget(Paragraph::new("abcdefgh"), Rect::new(0, 0, 8, 1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 4));
+        let mut state = ScrollViewState::default();
+        StatefulWidget::render(&scroll_view, buf.area, &mut buf, &mut state);
+        // the canvas fits in the area, so no scrollbar is shown
+        assert_eq!(buf, Buffer::with_lines(["abcdefgh", "", "", ""]));
+
+        // the horizontal scrollbar hides the last row, so the vertical scrollbar is needed too
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 4));
+        StatefulWidget::render(&scroll_view, buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["abcdef▲", "      █", "      ▼", "◄███═► "])
+        );
+    }
+
+    #[test]
+    fn scrolling() {
+        let scroll_view = scroll_view();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+        let mut state = ScrollViewState::default();
+        StatefulWidget::render(&scroll_view, buf.area, &mut buf, &mut state);
+
+        state.scroll_down();
+        state.scroll_right();
+        assert_eq!(state.offset(), Position::new(1, 1));
+        state.scroll_up();
+        state.scroll_left();
+        state.scroll_left();
+        assert_eq!(state.offset(), Position::new(0, 0));
+
+        state.scroll_page_down();
+        assert_eq!(state.offset(), Position::new(0, 3));
+        state.scroll_page_down();
+        state.scroll_page_down();
+        assert_eq!(state.offset(), Position::new(0, 7));
+        state.scroll_page_up();
+        assert_eq!(state.offset(), Position::new(0, 4));
+
+        state.scroll_to_top();
+        assert_eq!(state.offset(), Position::ORIGIN);
+        state.scroll_to_bottom();
+        assert_eq!(state.offset(), Position::new(0, 7));
+    }
+
+    #[test]
+    fn scrollbar_states() {
+        let scroll_view = scroll_view();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+        let mut state = ScrollViewState::with_offset(Position::new(2, 3));
+        StatefulWidget::render(&scroll_view, buf.area, &mut buf, &mut state);
+        assert_eq!(
+            state.vertical_scrollbar_state(),
+            ScrollbarState::new(8)
+                .position(3)
+                .viewport_content_length(3)
+        );
+        assert_eq!(
+            state.horizontal_scrollbar_state(),
+            ScrollbarState::new(8)
+                .position(2)
+                .viewport_content_length(5)
+        );
+    }
+
+    #[test]
+    fn custom_scrollbar() {
+        let scroll_view = scroll_view()
+            .horizontal_scrollbar_visibility(ScrollbarVisibility::Never)
+            .vertical_scrollbar(
+                Scrollbar::new(ScrollbarOrientation::HorizontalTop)
+                    .begin_symbol(None)
+                    .end_symbol(None)
+                    .track_symbol(Some("|"))
+                    .thumb_symbol("#"),
+            );
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 5));
+        let mut state = ScrollViewState::default();
+        StatefulWidget::render(&scroll_view, buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["012#", "112#", "212#", "312|", "412|"])
+        );
+    }
+}
@@ -225,6 +225,12 @@ impl<'a> Scrollbar<'a> {
         self.symbols(symbols)
     }
 
+    /// Sets the position of the scrollbar without resetting the symbols.
+    pub(crate) const fn with_orientation(mut self, orientation: ScrollbarOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
     /// Sets the orientation and symbols for the scrollbar from a [`Set`].
     ///
     /// This has the same effect as calling [`Scrollbar::orientation`] and then
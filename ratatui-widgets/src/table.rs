@@ -7,7 +7,7 @@ use alloc::vec::Vec;
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::{Constraint, Flex, Layout, Rect};
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Style, Styled, Theme, roles};
 use ratatui_core::text::Text;
 use ratatui_core::widgets::{StatefulWidget, Widget};
 
@@ -747,7 +747,7 @@ impl StatefulWidget for &Table<'_> {
     type State = TableState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        buf.set_style(area, self.style);
+        buf.set_style(area, Theme::active_style(roles::TABLE).patch(self.style));
         self.block.as_ref().render(area, buf);
         let table_area = self.block.inner_if_some(area);
         if table_area.is_empty() {
@@ -812,7 +812,10 @@ impl Table<'_> {
     /// x-coordinate and width of each column in the table.
     fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[Rect]) {
         if let Some(ref header) = self.header {
-            buf.set_style(area, header.style);
+            buf.set_style(
+                area,
+                Theme::active_role_style(roles::TABLE_HEADER).patch(header.style),
+            );
             for (cell_area, cell) in column_widths.iter().zip(header.cells.iter()) {
                 let new_x = area.x + cell_area.x;
                 let area_to_render = Rect::new(new_x, area.y, cell_area.width, area.height);
@@ -891,15 +894,17 @@ impl Table<'_> {
             })
         });
 
+        let row_highlight_style =
+            Theme::active_role_style(roles::TABLE_HIGHLIGHT).patch(self.row_highlight_style);
         match (selected_row_area, selected_column_area) {
             (Some(row_area), Some(col_area)) => {
-                buf.set_style(row_area, self.row_highlight_style);
+                buf.set_style(row_area, row_highlight_style);
                 buf.set_style(col_area, self.column_highlight_style);
                 let cell_area = row_area.intersection(col_area);
                 buf.set_style(cell_area, self.cell_highlight_style);
             }
             (Some(row_area), None) => {
-                buf.set_style(row_area, self.row_highlight_style);
+                buf.set_style(row_area, row_highlight_style);
             }
             (None, Some(col_area)) => {
                 buf.set_style(col_area, self.column_highlight_style);
@@ -1,7 +1,7 @@
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
 use ratatui_core::style::{Style, Styled};
-use ratatui_core::text::Text;
+use ratatui_core::text::{Text, Truncation};
 use ratatui_core::widgets::Widget;
 
 /// A [`Cell`] contains the [`Text`] to be displayed in a [`Row`] of a [`Table`].
@@ -53,6 +53,8 @@ pub struct Cell<'a> {
     style: Style,
     /// The number of columns this cell will extend over
     pub(crate) column_span: u16,
+    /// How lines that don't fit in the cell are truncated
+    truncation: Option<Truncation>,
 }
 
 impl<'a> Cell<'a> {
@@ -83,6 +85,7 @@ impl<'a> Cell<'a> {
             content: content.into(),
             style: Style::default(),
             column_span: 1,
+            truncation: None,
         }
     }
 
@@ -173,12 +176,44 @@ impl<'a> Cell<'a> {
         self.style = style.into();
         self
     }
+
+    /// Set how lines that don't fit in the cell are truncated
+    ///
+    /// By default, lines that are wider than the column are cut off at the edge of the cell. With
+    /// a [`Truncation`], part of each line is replaced by a marker (`…` by default) instead. See
+    /// [`Line::truncate`] for more details.
+    ///
+    /// `truncation` accepts a [`Truncation`] or a [`TruncationMode`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::text::TruncationMode;
+    /// use ratatui::widgets::Cell;
+    ///
+    /// Cell::new("/home/user/projects/ratatui").truncation(TruncationMode::Start);
+    /// ```
+    ///
+    /// [`Line::truncate`]: ratatui_core::text::Line::truncate
+    /// [`TruncationMode`]: ratatui_core::text::TruncationMode
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn truncation<T: Into<Truncation>>(mut self, truncation: T) -> Self {
+        self.truncation = Some(truncation.into());
+        self
+    }
 }
 
 impl Cell<'_> {
     pub(crate) fn render(&self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, self.style);
-        Widget::render(&self.content, area, buf);
+        match self.truncation {
+            Some(truncation) => {
+                Widget::render(self.content.truncate(area.width, truncation), area, buf);
+            }
+            None => Widget::render(&self.content, area, buf),
+        }
     }
 }
 
@@ -191,6 +226,7 @@ where
             content: content.into(),
             style: Style::default(),
             column_span: 1,
+            truncation: None,
         }
     }
 }
@@ -210,6 +246,7 @@ impl Styled for Cell<'_> {
 #[cfg(test)]
 mod tests {
     use ratatui_core::style::{Color, Modifier, Stylize};
+    use ratatui_core::text::TruncationMode;
 
     use super::*;
 
@@ -232,6 +269,14 @@ mod tests {
         assert_eq!(cell.style, style);
     }
 
+    #[test]
+    fn truncation() {
+        let cell = Cell::new("abcdef").truncation(TruncationMode::Middle);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        cell.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["ab…f"]));
+    }
+
     #[test]
     fn stylize() {
         assert_eq!(
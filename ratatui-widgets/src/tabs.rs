@@ -4,9 +4,9 @@ use alloc::vec::Vec;
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Style, Styled, Theme, roles};
 use ratatui_core::symbols;
-use ratatui_core::text::{Line, Span};
+use ratatui_core::text::{Line, Span, Truncation};
 use ratatui_core::widgets::Widget;
 use unicode_width::UnicodeWidthStr;
 
@@ -65,6 +65,8 @@ pub struct Tabs<'a> {
     padding_left: Line<'a>,
     /// Tab Right Padding
     padding_right: Line<'a>,
+    /// How titles that don't fit in the remaining width are truncated
+    truncation: Option<Truncation>,
 }
 
 impl Default for Tabs<'_> {
@@ -143,6 +145,7 @@ impl<'a> Tabs<'a> {
             divider: Span::raw(symbols::line::VERTICAL),
             padding_left: Line::from(" "),
             padding_right: Line::from(" "),
+            truncation: None,
         }
     }
 
@@ -352,6 +355,30 @@ impl<'a> Tabs<'a> {
         self.padding_right = padding.into();
         self
     }
+
+    /// Sets how titles that don't fit in the remaining width are truncated.
+    ///
+    /// By default, a title that doesn't fit is cut off at the edge of the widget. With a
+    /// [`Truncation`], part of the title is replaced by a marker (`…` by default) instead. See
+    /// [`Line::truncate`] for more details.
+    ///
+    /// `truncation` accepts a [`Truncation`] or a [`TruncationMode`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::text::TruncationMode;
+    /// use ratatui::widgets::Tabs;
+    ///
+    /// let tabs = Tabs::new(vec!["Overview", "Settings"]).truncation(TruncationMode::End);
+    /// ```
+    ///
+    /// [`TruncationMode`]: ratatui_core::text::TruncationMode
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn truncation<T: Into<Truncation>>(mut self, truncation: T) -> Self {
+        self.truncation = Some(truncation.into());
+        self
+    }
 }
 
 impl Styled for Tabs<'_> {
@@ -374,7 +401,7 @@ impl Widget for Tabs<'_> {
 
 impl Widget for &Tabs<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        buf.set_style(area, self.style);
+        buf.set_style(area, Theme::active_style(roles::TABS).patch(self.style));
         self.block.as_ref().render(area, buf);
         let inner = self.block.inner_if_some(area);
         self.render_tabs(inner, buf);
@@ -387,6 +414,8 @@ impl Tabs<'_> {
             return;
         }
 
+        let highlight_style =
+            Theme::active_role_style(roles::TABS_HIGHLIGHT).patch(self.highlight_style);
         let mut x = tabs_area.left();
         let titles_length = self.titles.len();
         for (i, title) in self.titles.iter().enumerate() {
@@ -406,7 +435,13 @@ impl Tabs<'_> {
             }
 
             // Title
-            let pos = buf.set_line(x, tabs_area.top(), title, remaining_width);
+            let pos = match self.truncation {
+                Some(truncation) if title.width() > usize::from(remaining_width) => {
+                    let title = title.truncate(remaining_width, truncation);
+                    buf.set_line(x, tabs_area.top(), &title, remaining_width)
+                }
+                _ => buf.set_line(x, tabs_area.top(), title, remaining_width),
+            };
             if Some(i) == self.selected {
                 buf.set_style(
                     Rect {
@@ -415,7 +450,7 @@ impl Tabs<'_> {
                         width: pos.0.saturating_sub(x),
                         height: 1,
                     },
-                    self.highlight_style,
+                    highlight_style,
                 );
             }
             x = pos.0;
@@ -510,6 +545,7 @@ mod tests {
     use alloc::{format, vec};
 
     use ratatui_core::style::{Color, Stylize};
+    use ratatui_core::text::TruncationMode;
 
     use super::*;
 
@@ -533,6 +569,7 @@ mod tests {
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                truncation: None,
             }
         );
     }
@@ -550,6 +587,7 @@ mod tests {
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                truncation: None,
             }
         );
     }
@@ -640,6 +678,25 @@ mod tests {
         test_case(tabs, Rect::new(0, 0, 30, 1), &expected);
     }
 
+    #[test]
+    fn render_truncated() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "LongTab3"]).truncation(TruncationMode::End);
+        let mut expected = Buffer::with_lines([" Tab1 │ Tab2 │ Lon…"]);
+        // first tab selected
+        expected.set_style(Rect::new(1, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 19, 1), &expected);
+    }
+
+    #[test]
+    fn render_truncated_selected() {
+        let tabs = Tabs::new(vec!["Tab1", "LongTab2"])
+            .truncation(Truncation::start().marker(".."))
+            .select(1);
+        let mut expected = Buffer::with_lines([" Tab1 │ ..ab2"]);
+        expected.set_style(Rect::new(8, 0, 5, 1), DEFAULT_HIGHLIGHT_STYLE);
+        test_case(tabs, Rect::new(0, 0, 13, 1), &expected);
+    }
+
     #[test]
     fn render_with_block() {
         let tabs =
@@ -0,0 +1,52 @@
+//! Tests for the widgets with global tab stops.
+//!
+//! The tab stops are process-wide, so these tests live in their own test binary and run in a
+//! single test function to avoid affecting other tests.
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::text::{Line, TabStops};
+use ratatui_core::widgets::Widget;
+use ratatui_widgets::list::List;
+use ratatui_widgets::paragraph::Paragraph;
+use ratatui_widgets::table::{Row, Table};
+
+fn render<W: Widget>(widget: W, width: u16, height: u16) -> Buffer {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+    widget.render(buffer.area, &mut buffer);
+    buffer
+}
+
+#[test]
+fn widgets_use_global_tab_stops() {
+    assert_eq!(TabStops::global(), TabStops::default());
+    assert_eq!(
+        render(List::new(["a\tb"]), 10, 1),
+        Buffer::with_lines(["a       b "])
+    );
+
+    TabStops::every(4).set_global();
+    assert_eq!(TabStops::global(), TabStops::every(4));
+    assert_eq!(Line::from("a\tb").width(), 5);
+
+    // table
+    let table = Table::new([Row::new(["a\tb", "c"])], [6, 1]);
+    assert_eq!(render(table, 8, 1), Buffer::with_lines(["a   b  c"]));
+
+    // list
+    assert_eq!(
+        render(List::new(["a\tb"]), 10, 1),
+        Buffer::with_lines(["a   b     "])
+    );
+
+    // paragraphs use the global tab stops unless they have their own
+    assert_eq!(
+        render(Paragraph::new("a\tb"), 10, 1),
+        Buffer::with_lines(["a   b     "])
+    );
+    let paragraph = Paragraph::new("a\tb").tab_stops(TabStops::every(2));
+    assert_eq!(render(paragraph, 10, 1), Buffer::with_lines(["a b       "]));
+
+    TabStops::default().set_global();
+    assert_eq!(Line::from("a\tb").width(), 9);
+}
@@ -0,0 +1,117 @@
+//! Tests for the styles that the widgets take from the active theme.
+//!
+//! The active theme is process-wide, so these tests live in their own test binary and run in a
+//! single test function to avoid affecting other tests.
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Color, Style, Theme, roles};
+use ratatui_core::text::Line;
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use ratatui_widgets::block::Block;
+use ratatui_widgets::gauge::Gauge;
+use ratatui_widgets::list::{List, ListState};
+use ratatui_widgets::paragraph::Paragraph;
+use ratatui_widgets::table::{Row, Table, TableState};
+use ratatui_widgets::tabs::Tabs;
+
+fn render<W: Widget>(widget: W, width: u16, height: u16) -> Buffer {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+    widget.render(buffer.area, &mut buffer);
+    buffer
+}
+
+fn render_stateful<W: StatefulWidget>(
+    widget: W,
+    state: &mut W::State,
+    width: u16,
+    height: u16,
+) -> Buffer {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+    widget.render(buffer.area, &mut buffer, state);
+    buffer
+}
+
+#[test]
+fn widgets_use_active_theme() {
+    // without an active theme, the widgets use their own styles
+    assert_eq!(
+        render(Block::bordered(), 3, 3),
+        Buffer::with_lines(["┌─┐", "│ │", "└─┘"])
+    );
+
+    Theme::new()
+        .with_style(roles::BLOCK, Style::new().on_black())
+        .with_style(roles::BLOCK_BORDER, Style::new().blue())
+        .with_style(roles::BLOCK_TITLE, Style::new().bold())
+        .with_style(roles::LIST_HIGHLIGHT, Style::new().reversed())
+        .with_style(roles::TABLE_HEADER, Style::new().yellow())
+        .with_style(roles::TABLE_HIGHLIGHT, Style::new().italic())
+        .with_style(roles::TABS, Style::new().white())
+        .with_style(roles::TABS_HIGHLIGHT, Style::new().green())
+        .with_style(roles::PARAGRAPH, Style::new().cyan())
+        .with_style(roles::GAUGE_BAR, Style::new().red())
+        .set_global();
+
+    // block
+    let mut expected = Buffer::with_lines(["┌T┐", "│ │", "└─┘"]);
+    expected.set_style(expected.area, Style::new().blue().on_black());
+    expected.set_style(Rect::new(1, 1, 1, 1), Style::new().fg(Color::Reset));
+    expected.set_style(Rect::new(1, 0, 1, 1), Style::new().bold());
+    assert_eq!(render(Block::bordered().title("T"), 3, 3), expected);
+
+    // explicit styles take precedence over the theme
+    let block = Block::bordered()
+        .border_style(Style::new().red())
+        .style(Style::new().on_white());
+    let mut expected = Buffer::with_lines(["┌─┐", "│ │", "└─┘"]);
+    expected.set_style(expected.area, Style::new().red().on_white());
+    expected.set_style(Rect::new(1, 1, 1, 1), Style::new().fg(Color::Reset));
+    assert_eq!(render(block, 3, 3), expected);
+
+    // list
+    let mut state = ListState::default().with_selected(Some(1));
+    let mut expected = Buffer::with_lines(["a", "b"]);
+    expected.set_style(Rect::new(0, 1, 1, 1), Style::new().reversed());
+    assert_eq!(
+        render_stateful(List::new(["a", "b"]), &mut state, 1, 2),
+        expected
+    );
+
+    // table
+    let table = Table::new([Row::new(["a"]), Row::new(["b"])], [1]).header(Row::new(["h"]));
+    let mut state = TableState::default().with_selected(Some(0));
+    let mut expected = Buffer::with_lines(["h", "a", "b"]);
+    expected.set_style(Rect::new(0, 0, 1, 1), Style::new().yellow());
+    expected.set_style(Rect::new(0, 1, 1, 1), Style::new().italic());
+    assert_eq!(render_stateful(table, &mut state, 1, 3), expected);
+
+    // tabs
+    let tabs = Tabs::new(["a", "b"]).select(1).divider("|").padding("", "");
+    let mut expected = Buffer::with_lines(["a|b"]);
+    expected.set_style(expected.area, Style::new().white());
+    expected.set_style(Rect::new(2, 0, 1, 1), Style::new().green().reversed());
+    assert_eq!(render(tabs, 3, 1), expected);
+
+    // paragraph
+    let mut expected = Buffer::with_lines(["p"]);
+    expected.set_style(expected.area, Style::new().cyan());
+    assert_eq!(render(Paragraph::new(Line::from("p")), 1, 1), expected);
+
+    // gauge
+    let gauge = Gauge::default().ratio(1.0).label("");
+    let mut expected = Buffer::with_lines(["█ "]);
+    expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red().bg(Color::Reset));
+    expected.set_style(
+        Rect::new(1, 0, 1, 1),
+        Style::new().fg(Color::Reset).on_red(),
+    );
+    assert_eq!(render(gauge, 2, 1), expected);
+
+    // clearing the theme restores the default styles
+    Theme::clear_global();
+    assert_eq!(
+        render(Paragraph::new(Line::from("p")), 1, 1),
+        Buffer::with_lines(["p"])
+    );
+}
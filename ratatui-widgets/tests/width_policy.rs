@@ -0,0 +1,37 @@
+//! Tests for the widgets under a non-default global width policy.
+//!
+//! The policy is process-wide, so these tests live in their own test binary and run in a single
+//! test function to avoid affecting other tests.
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::text::{AmbiguousWidth, WidthPolicy};
+use ratatui_core::widgets::Widget;
+use ratatui_widgets::paragraph::{Paragraph, Wrap};
+
+fn render<W: Widget>(widget: W, width: u16, height: u16) -> Buffer {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+    widget.render(buffer.area, &mut buffer);
+    buffer
+}
+
+#[test]
+fn paragraph_uses_global_width_policy() {
+    WidthPolicy::new()
+        .ambiguous(AmbiguousWidth::Wide)
+        .set_global();
+
+    // wrapped
+    let paragraph = Paragraph::new("①①① ①①①").wrap(Wrap { trim: true });
+    assert_eq!(render(paragraph, 6, 2), Buffer::with_lines(["①①①", "①①①"]));
+
+    // truncated
+    let paragraph = Paragraph::new("①①①①");
+    assert_eq!(render(paragraph, 7, 1), Buffer::with_lines(["①①① "]));
+
+    // scrolled horizontally
+    let paragraph = Paragraph::new("a①①b").scroll((0, 3));
+    assert_eq!(render(paragraph, 4, 1), Buffer::with_lines(["①b "]));
+
+    WidthPolicy::default().set_global();
+}
@@ -50,9 +50,11 @@
 //! - [`Chart`]: displays multiple datasets as a lines or scatter graph.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`LayoutDebug`]: draws the segments and spacers of a layout on top of a frame for debugging.
 //! - [`LineGauge`]: display progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`ScrollView`]: displays a scrollable window into content larger than its area.
 //! - [`Scrollbar`]: displays a scrollbar.
 //! - [`Sparkline`]: display a single data set as a sparkline.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
@@ -673,10 +675,12 @@ pub use ratatui_widgets::canvas;
 pub use ratatui_widgets::chart::{Axis, Chart, Dataset, GraphType, LegendPosition};
 pub use ratatui_widgets::clear::Clear;
 pub use ratatui_widgets::gauge::{Gauge, LineGauge};
+pub use ratatui_widgets::layout_debug::LayoutDebug;
 pub use ratatui_widgets::list::{List, ListDirection, ListItem, ListState};
 pub use ratatui_widgets::logo::{RatatuiLogo, Size as RatatuiLogoSize};
 pub use ratatui_widgets::mascot::{MascotEyeColor, RatatuiMascot};
 pub use ratatui_widgets::paragraph::{Paragraph, Wrap};
+pub use ratatui_widgets::scroll_view::{ScrollView, ScrollViewState, ScrollbarVisibility};
 pub use ratatui_widgets::scrollbar::{
     ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState,
 };